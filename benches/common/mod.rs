@@ -0,0 +1,25 @@
+//! Helpers shared by the benchmarks.
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use rubiks_cube_representation::core::rubiks::moves::BasicMove;
+
+/// A 20-move scramble, in basic moves that parse for every N.
+pub const SCRAMBLE: &str = "R U2 F' L D B2 R' U F D' L2 B U' R2 F2 D L' B' U R";
+
+/// The moves of [`SCRAMBLE`] on an N×N×N cube.
+pub fn scramble<const N: usize>() -> Vec<BasicMove<N>> {
+    SCRAMBLE.split(' ').map(|mv| mv.parse().unwrap()).collect()
+}
+
+/// Runs `f` repeatedly for about `budget` and returns the mean time per call.
+pub fn time<T>(budget: Duration, mut f: impl FnMut() -> T) -> Duration {
+    let start = Instant::now();
+    let mut runs = 0;
+    while start.elapsed() < budget {
+        black_box(f());
+        runs += 1;
+    }
+    start.elapsed() / runs
+}
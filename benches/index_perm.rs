@@ -0,0 +1,55 @@
+//! Compares `IndexPerm` with `TilePerm` for composing move sequences and applying
+//! them to cube states.
+//!
+//! Run with `cargo bench --bench index_perm`. Both sides use precomputed move
+//! permutations, so the timings measure composition and application only.
+
+mod common;
+
+use std::time::Duration;
+
+use rubiks_cube_representation::core::rubiks::moves::BasicMove;
+use rubiks_cube_representation::core::rubiks::tiles::indexed::{IndexPerm, MoveTable};
+use rubiks_cube_representation::core::rubiks::tiles::TilePerm;
+use rubiks_cube_representation::{RubiksState, Western};
+
+use common::scramble;
+
+/// Runs `f` repeatedly for about half a second and returns the mean time per call.
+fn time<T>(f: impl FnMut() -> T) -> Duration {
+    common::time(Duration::from_millis(500), f)
+}
+
+fn report(name: &str, tile: Duration, index: Duration) {
+    let speedup = tile.as_secs_f64() / index.as_secs_f64();
+    println!("{name:<28} TilePerm {tile:>12.2?}   IndexPerm {index:>12.2?}   {speedup:>6.1}×");
+}
+
+fn bench<const N: usize>() {
+    let scramble = scramble::<N>();
+    let tile_moves: Vec<TilePerm<N>> = scramble.iter().map(TilePerm::from).collect();
+    let table = MoveTable::<N, BasicMove<N>>::basic();
+    let index_moves: Vec<&IndexPerm<N>> = scramble.iter().map(|mv| table.perm(mv).unwrap()).collect();
+
+    println!("N = {N}");
+    let tile = time(|| tile_moves.iter().fold(TilePerm::<N>::identity(), |acc, mv| acc * mv));
+    let index = time(|| index_moves.iter().fold(IndexPerm::<N>::identity(), |acc, mv| acc * mv));
+    report("compose 20 moves", tile, index);
+
+    let tile_perm = tile_moves.iter().fold(TilePerm::<N>::identity(), |acc, mv| acc * mv);
+    let index_perm = IndexPerm::from(&tile_perm);
+    let cube = RubiksState::<N>::solved_in(Western);
+    let tile = time(|| &cube * tile_perm.clone());
+    let index = time(|| &cube * &index_perm);
+    report("apply to state", tile, index);
+
+    let tile = time(|| tile_perm.inverse());
+    let index = time(|| index_perm.inverse());
+    report("invert", tile, index);
+    println!();
+}
+
+fn main() {
+    bench::<3>();
+    bench::<17>();
+}
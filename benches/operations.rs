@@ -0,0 +1,103 @@
+//! Baseline timings for the core operations across cube sizes.
+//!
+//! Run with `cargo bench --bench operations`. Every operation is timed for
+//! N = 2, 3, 5, 7 and 17 and the results are printed as one table, one row per
+//! operation and one column per size:
+//!
+//! - composing and inverting [`TilePerm`]s
+//! - applying a move and a permutation to a state, which goes through
+//!   `CubeOperation::on`
+//! - converting a move of each of the five families to a [`TilePerm`]; for basic moves
+//!   on cubes up to `MAX_TABLE_N` this only looks up a table evaluated at compile time,
+//!   and the row is marked as such
+//! - `is_solved_up_to_rotation_in` on a solved cube held in a different orientation
+//!
+//! Middle moves exist only for odd N, so their row is blank for even sizes. Applying
+//! a [`TilePerm`] consumes it, so that row includes cloning the permutation.
+
+mod common;
+
+use std::hint::black_box;
+use std::str::FromStr;
+use std::time::Duration;
+
+use rubiks_cube_representation::core::rubiks::moves::{BasicMove, MiddleMove, RangeMove, SliceMove, WideMove};
+use rubiks_cube_representation::core::rubiks::tiles::motion::MAX_TABLE_N;
+use rubiks_cube_representation::core::rubiks::tiles::TilePerm;
+use rubiks_cube_representation::{RubiksState, Western, X, Y};
+
+use common::scramble;
+
+/// The benchmarked operations, in table order.
+const ROWS: [&str; 11] = [
+    "compose",
+    "inverse",
+    "apply move to state",
+    "apply TilePerm to state",
+    "BasicMove to TilePerm *",
+    "WideMove to TilePerm",
+    "SliceMove to TilePerm",
+    "RangeMove to TilePerm",
+    "MiddleMove to TilePerm",
+    "solved up to rotation",
+    "unsolved up to rotation",
+];
+
+/// Runs `f` repeatedly for about a fifth of a second and returns the mean time per call.
+fn time<T>(f: impl FnMut() -> T) -> Duration {
+    common::time(Duration::from_millis(200), f)
+}
+
+/// Times converting the parsed move `text` to a [`TilePerm`].
+fn conversion<const N: usize, M: FromStr>(text: &str) -> Duration
+where
+    for<'a> &'a M: Into<TilePerm<N>>,
+{
+    let Ok(mv) = text.parse::<M>() else { panic!("invalid move `{text}` for N = {N}") };
+    time(|| -> TilePerm<N> { black_box(&mv).into() })
+}
+
+/// Times every row for one cube size, in the order of [`ROWS`].
+fn bench<const N: usize>() -> Vec<Option<Duration>> {
+    let perm = scramble::<N>().iter().map(TilePerm::from).fold(TilePerm::<N>::identity(), |acc, mv| acc * &mv);
+    let other = TilePerm::from(&BasicMove::<N>::F) * &TilePerm::from(&BasicMove::R3);
+    let cube = RubiksState::<N>::solved_in(Western);
+    let rotated = RubiksState::<N>::solved_in(Western) * &X * &Y;
+    let scrambled = &cube * perm.clone();
+
+    vec![
+        Some(time(|| &perm * &other)),
+        Some(time(|| perm.inverse())),
+        Some(time(|| &cube * &BasicMove::<N>::R)),
+        Some(time(|| &cube * perm.clone())),
+        Some(conversion::<N, BasicMove<N>>("R")),
+        Some(conversion::<N, WideMove<N>>("Rw")),
+        Some(conversion::<N, SliceMove<N>>("2R")),
+        Some(conversion::<N, RangeMove<N>>("1-2Rw")),
+        (N % 2 == 1).then(|| conversion::<N, MiddleMove<N>>("M")),
+        Some(time(|| rotated.is_solved_up_to_rotation_in(Western))),
+        Some(time(|| scrambled.is_solved_up_to_rotation_in(Western))),
+    ]
+}
+
+fn main() {
+    let sizes = [2, 3, 5, 7, 17];
+    let columns = [bench::<2>(), bench::<3>(), bench::<5>(), bench::<7>(), bench::<17>()];
+
+    print!("{:<26}", "");
+    for n in sizes {
+        print!("{:>12}", format!("N = {n}"));
+    }
+    println!();
+    for (row, name) in ROWS.iter().enumerate() {
+        print!("{name:<26}");
+        for column in &columns {
+            match column[row] {
+                Some(duration) => print!("{duration:>12.2?}"),
+                None => print!("{:>12}", "-"),
+            }
+        }
+        println!();
+    }
+    println!("* a static table lookup for N ≤ {MAX_TABLE_N}, computed above that");
+}
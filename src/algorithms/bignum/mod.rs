@@ -0,0 +1,198 @@
+//! Arbitrary-precision unsigned integers.
+//!
+//! Group orders outgrow every primitive integer type quickly: the 3×3×3 cube group
+//! already has 43,252,003,274,489,856,000 elements, and the 4×4×4 supercube group is
+//! larger than `u128::MAX`. [`BigUint`] is a small, dependency-free natural number type
+//! with just the operations the group algorithms need: construction from primitives,
+//! multiplication, comparison and decimal formatting.
+//!
+//! # Representation
+//!
+//! Values are stored as little-endian base-2³² limbs with no trailing zero limbs, so
+//! zero is the empty limb vector and every value has exactly one representation. The
+//! derived equality and hashing are therefore value equality and value hashing.
+//!
+//! # Example
+//!
+//! ```
+//! use rubiks_cube_representation::algorithms::bignum::BigUint;
+//!
+//! let factorial: BigUint = (1..=30u64).map(BigUint::from).product();
+//! assert_eq!(factorial.to_string(), "265252859812191058636308480000000");
+//! assert_eq!(factorial.to_u128(), Some(265252859812191058636308480000000));
+//! ```
+
+use std::cmp::Ordering;
+use std::fmt;
+use std::iter::Product;
+use std::ops::{Mul, MulAssign};
+
+#[cfg(test)]
+mod tests;
+
+/// An arbitrary-precision natural number.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct BigUint {
+    /// Little-endian base-2³² digits, without trailing zeros
+    limbs: Vec<u32>,
+}
+
+impl BigUint {
+    /// The number zero.
+    pub const ZERO: Self = BigUint { limbs: Vec::new() };
+
+    /// The number one.
+    pub fn one() -> Self {
+        BigUint { limbs: vec![1] }
+    }
+
+    /// Returns whether the value is zero.
+    pub fn is_zero(&self) -> bool {
+        self.limbs.is_empty()
+    }
+
+    /// Returns the value as a `u128`, or `None` if it does not fit.
+    pub fn to_u128(&self) -> Option<u128> {
+        if self.limbs.len() > 4 {
+            return None;
+        }
+        Some(self.limbs.iter().rev().fold(0, |acc, &limb| (acc << 32) | limb as u128))
+    }
+
+    /// Number of bits needed to write the value in binary (`0` for zero).
+    pub fn bits(&self) -> u64 {
+        match self.limbs.last() {
+            Some(&top) => 32 * self.limbs.len() as u64 - top.leading_zeros() as u64,
+            None => 0,
+        }
+    }
+
+    /// Divides in place by a small divisor and returns the remainder.
+    fn div_rem_small(&mut self, divisor: u32) -> u32 {
+        let mut rem = 0u64;
+        for limb in self.limbs.iter_mut().rev() {
+            let cur = (rem << 32) | *limb as u64;
+            *limb = (cur / divisor as u64) as u32;
+            rem = cur % divisor as u64;
+        }
+        self.normalise();
+        rem as u32
+    }
+
+    fn normalise(&mut self) {
+        while self.limbs.last() == Some(&0) {
+            self.limbs.pop();
+        }
+    }
+}
+
+impl From<u32> for BigUint {
+    fn from(value: u32) -> Self {
+        BigUint::from(value as u128)
+    }
+}
+
+impl From<u64> for BigUint {
+    fn from(value: u64) -> Self {
+        BigUint::from(value as u128)
+    }
+}
+
+impl From<usize> for BigUint {
+    fn from(value: usize) -> Self {
+        BigUint::from(value as u128)
+    }
+}
+
+impl From<u128> for BigUint {
+    fn from(mut value: u128) -> Self {
+        let mut limbs = Vec::new();
+        while value != 0 {
+            limbs.push(value as u32);
+            value >>= 32;
+        }
+        BigUint { limbs }
+    }
+}
+
+impl Mul<&BigUint> for &BigUint {
+    type Output = BigUint;
+
+    /// Schoolbook multiplication, O(len(a)·len(b)).
+    fn mul(self, rhs: &BigUint) -> BigUint {
+        if self.is_zero() || rhs.is_zero() {
+            return BigUint::ZERO;
+        }
+        let mut limbs = vec![0u32; self.limbs.len() + rhs.limbs.len()];
+        for (i, &a) in self.limbs.iter().enumerate() {
+            let mut carry = 0u64;
+            for (j, &b) in rhs.limbs.iter().enumerate() {
+                let cur = limbs[i + j] as u64 + a as u64 * b as u64 + carry;
+                limbs[i + j] = cur as u32;
+                carry = cur >> 32;
+            }
+            limbs[i + rhs.limbs.len()] = carry as u32;
+        }
+        let mut result = BigUint { limbs };
+        result.normalise();
+        result
+    }
+}
+
+impl Mul for BigUint {
+    type Output = BigUint;
+
+    fn mul(self, rhs: BigUint) -> BigUint {
+        &self * &rhs
+    }
+}
+
+impl MulAssign<&BigUint> for BigUint {
+    fn mul_assign(&mut self, rhs: &BigUint) {
+        *self = &*self * rhs;
+    }
+}
+
+impl Product for BigUint {
+    fn product<I: Iterator<Item = BigUint>>(iter: I) -> Self {
+        iter.fold(BigUint::one(), |acc, x| acc * x)
+    }
+}
+
+impl PartialEq<u128> for BigUint {
+    fn eq(&self, other: &u128) -> bool {
+        self.to_u128() == Some(*other)
+    }
+}
+
+impl Ord for BigUint {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.limbs.len().cmp(&other.limbs.len()).then_with(|| self.limbs.iter().rev().cmp(other.limbs.iter().rev()))
+    }
+}
+
+impl PartialOrd for BigUint {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl fmt::Display for BigUint {
+    /// Formats the value in decimal.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        const CHUNK: u32 = 1_000_000_000;
+        let mut rest = self.clone();
+        let mut chunks = Vec::new();
+        while !rest.is_zero() {
+            chunks.push(rest.div_rem_small(CHUNK));
+        }
+        let mut digits = match chunks.pop() {
+            Some(top) => top.to_string(),
+            None => "0".to_string(),
+        };
+        for chunk in chunks.iter().rev() {
+            digits.push_str(&format!("{chunk:09}"));
+        }
+        f.pad_integral(true, "", &digits)
+    }
+}
@@ -0,0 +1,40 @@
+use super::*;
+
+#[test]
+fn test_round_trip_through_u128() {
+    for value in [0, 1, u32::MAX as u128, u32::MAX as u128 + 1, u64::MAX as u128 * 3, u128::MAX] {
+        let big = BigUint::from(value);
+        assert_eq!(big.to_u128(), Some(value));
+        assert_eq!(big.to_string(), value.to_string());
+        assert_eq!(big.bits(), 128 - value.leading_zeros() as u64);
+    }
+    assert!(BigUint::from(0u32).is_zero());
+    assert_eq!(BigUint::ZERO.to_string(), "0");
+}
+
+#[test]
+fn test_multiplication_beyond_u128() {
+    let max = BigUint::from(u128::MAX);
+    let square = &max * &max;
+    assert_eq!(square.to_u128(), None);
+    assert_eq!(square.bits(), 256);
+    // (2^128 - 1)^2 = 2^256 - 2^129 + 1
+    assert_eq!(
+        square.to_string(),
+        "115792089237316195423570985008687907852589419931798687112530834793049593217025"
+    );
+    assert_eq!(&square * &BigUint::ZERO, BigUint::ZERO);
+    assert!(square > max);
+    assert!(BigUint::from(7u32) < BigUint::from(8u32));
+}
+
+#[test]
+fn test_product_and_padding() {
+    let order: BigUint = [8u64, 7, 6, 5, 4, 3, 2].into_iter().map(BigUint::from).product();
+    assert_eq!(order, 40320u128);
+    assert_eq!(format!("{order:>8}"), "   40320");
+    let mut x = BigUint::one();
+    x *= &BigUint::from(1_000_000_000u64);
+    x *= &BigUint::from(1_000_000_000u64);
+    assert_eq!(x.to_string(), "1000000000000000000");
+}
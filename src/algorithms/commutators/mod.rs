@@ -0,0 +1,246 @@
+//! Searching for commutators that perform a given 3-cycle.
+//!
+//! Blindfold methods and big-cube reduction both need an algorithm for an arbitrary
+//! 3-cycle of pieces, and almost all such algorithms are commutators `[A, B]` or
+//! conjugated commutators `[C: [A, B]]` (see [`algs`](crate::core::rubiks::algs)).
+//! A [`CommutatorSearch`] finds the shortest one over a chosen move set.
+//!
+//! # Targets
+//!
+//! A target is three stickers `[a, b, c]`. The commutator must send the sticker at `a`
+//! to `b`, `b` to `c` and `c` back to `a`, carry the other stickers of those three
+//! pieces along rigidly, and leave every other tile where it is. A target given as
+//! three pieces cycles their reference stickers (see [`pieces`](crate::core::rubiks::pieces)).
+//!
+//! # Algorithm
+//!
+//! 1. **Sequence table.** All move sequences up to `max_depth` are enumerated breadth
+//!    first, never turning the same layers twice in a row, and only the first (hence
+//!    shortest) sequence reaching each permutation is kept. Permutations are stored as
+//!    flat image arrays over tile indices, together with their inverses.
+//! 2. **Pure commutators.** For a total length `|A| + |B|`, every pair of table entries
+//!    of matching lengths is tried. Evaluating the commutator on the sticker `a` takes
+//!    four array lookups, which rejects almost every pair; the few survivors are checked
+//!    on every tile.
+//! 3. **Setups.** `[C: X]` performs the cycle `(a b c)` exactly when `X` performs
+//!    `(C(a) C(b) C(c))`, so conjugated commutators are found by running step 2 on the
+//!    moved target for each setup `C` in the table.
+//!
+//! Candidates are tried in order of structural length `2|C| + 2|A| + 2|B|`, so the
+//! result is as short as possible before any cancellation between the parts.
+//!
+//! # Performance
+//!
+//! The table grows exponentially with `max_depth`: with the 18 outer turns of a 3×3×3
+//! it has about 3,500 entries at depth 3 and 47,000 at depth 4. Pure commutators of
+//! total length 4 (8 moves) are found instantly, but an unreachable target makes the
+//! search try every combination, so keep the depths small.
+//!
+//! # Example
+//!
+//! ```
+//! use rubiks_cube_representation::algorithms::commutators::CommutatorSearch;
+//! use rubiks_cube_representation::core::cube::geometry::Face;
+//! use rubiks_cube_representation::core::rubiks::moves::BasicMove::{self, *};
+//! use rubiks_cube_representation::core::rubiks::tiles::{TilePerm, TilePos};
+//!
+//! let moves: Vec<BasicMove<3>> = vec![U, U2, U3, D, D2, D3, R, R2, R3, F, F2, F3];
+//! let search = CommutatorSearch::<3, _>::new(moves, 3, 1);
+//!
+//! // Cycle the UFR, UBR and DFR corners, starting from their stickers on U, U and F
+//! let ufr = TilePos { face: Face::Up, row: 2, col: 2 };
+//! let ubr = TilePos { face: Face::Up, row: 0, col: 2 };
+//! let dfr = TilePos { face: Face::Front, row: 2, col: 2 };
+//! let alg = search.find([ufr, ubr, dfr]).unwrap();
+//! assert_eq!(alg.move_count(), 8);
+//!
+//! let perm = TilePerm::<3>::from(&alg);
+//! assert_eq!((perm[ufr], perm[ubr], perm[dfr]), (ubr, dfr, ufr));
+//! assert_eq!(perm.support().len(), 9);
+//! ```
+
+use std::collections::HashSet;
+use std::ops::Range;
+
+use crate::algorithms::group::{compose, identity, invert, to_images, Images};
+use crate::core::rubiks::algs::{Alg, Turn};
+use crate::core::rubiks::pieces::Piece;
+use crate::core::rubiks::tiles::{TilePerm, TilePos};
+
+#[cfg(test)]
+mod tests;
+
+/// A move sequence in the table, with its permutation and inverse.
+#[derive(Clone, Debug)]
+struct Entry<M> {
+    moves: Vec<M>,
+    images: Images,
+    inverse: Images,
+}
+
+/// A target cycle in flat tile indices, with the tiles it is allowed to move.
+struct Target {
+    cycle: [usize; 3],
+    /// Whether each tile index belongs to one of the three pieces
+    moves: Vec<bool>,
+}
+
+/// A search for short commutators over a fixed move set.
+///
+/// See the [module documentation](self) for the method.
+#[derive(Clone, Debug)]
+pub struct CommutatorSearch<const N: usize, M> {
+    /// Table entries ordered by length, starting with the empty sequence
+    entries: Vec<Entry<M>>,
+    /// `levels[k]` is the range of entries of length `k`
+    levels: Vec<Range<usize>>,
+    max_setup: usize,
+}
+
+impl<const N: usize, M: Turn + Into<TilePerm<N>>> CommutatorSearch<N, M> {
+    /// Builds the sequence table for commutator parts of up to `max_depth` moves and
+    /// setups of up to `max_setup` moves.
+    ///
+    /// The move set should be closed under inverses, e.g. contain `R'` along with `R`;
+    /// otherwise inverse turns can only be spelt out as repeated turns.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_setup` is larger than `max_depth`, since setups are drawn from
+    /// the same table.
+    pub fn new(moves: impl IntoIterator<Item = M>, max_depth: usize, max_setup: usize) -> Self {
+        assert!(max_setup <= max_depth, "setups of {max_setup} moves need a table of that depth");
+        let generators: Vec<(M, Images)> = moves.into_iter().map(|mv| (mv, to_images::<N>(&mv.into()))).collect();
+        let id = identity(6 * N * N);
+        let mut seen = HashSet::from([id.clone()]);
+        let mut entries = vec![Entry { moves: Vec::new(), images: id.clone(), inverse: id }];
+        // The empty sequence is the only one of length 0
+        let mut levels = Vec::with_capacity(max_depth + 1);
+        levels.push(0..1);
+        for _ in 0..max_depth {
+            let previous = levels.last().expect("level 0 exists").clone();
+            let start = entries.len();
+            for i in previous {
+                for (mv, perm) in &generators {
+                    if entries[i].moves.last().is_some_and(|last: &M| last.same_layers(*mv)) {
+                        continue;
+                    }
+                    let images = compose(&entries[i].images, perm);
+                    if seen.insert(images.clone()) {
+                        let mut moves = entries[i].moves.clone();
+                        moves.push(*mv);
+                        let inverse = invert(&images);
+                        entries.push(Entry { moves, images, inverse });
+                    }
+                }
+            }
+            #[cfg(feature = "log")]
+            log::trace!(depth = levels.len(), sequences = entries.len() - start; "enumerated sequences");
+            levels.push(start..entries.len());
+        }
+        #[cfg(feature = "log")]
+        log::debug!(max_depth, max_setup, table_size = entries.len(); "built commutator table");
+        CommutatorSearch { entries, levels, max_setup }
+    }
+
+    /// The longest commutator part the table supports.
+    pub fn max_depth(&self) -> usize {
+        self.levels.len() - 1
+    }
+
+    /// The number of distinct sequences in the table, including the empty one.
+    pub fn table_size(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Finds a shortest commutator or conjugated commutator cycling the sticker at
+    /// `cycle[0]` to `cycle[1]`, `cycle[1]` to `cycle[2]` and `cycle[2]` to `cycle[0]`,
+    /// moving the rest of those three pieces with them and fixing every other tile.
+    ///
+    /// Returns `None` if no such algorithm exists within the depth limits, which
+    /// includes impossible targets such as stickers on pieces of different kinds.
+    pub fn find(&self, cycle: [TilePos; 3]) -> Option<Alg<M>> {
+        let mut moves = vec![false; 6 * N * N];
+        for tile in cycle {
+            for sticker in Piece::containing::<N>(tile).stickers {
+                moves[sticker.index::<N>()] = true;
+            }
+        }
+        let target = Target { cycle: cycle.map(|tile| tile.index::<N>()), moves };
+
+        let max_total = self.max_depth() * 2 + self.max_setup;
+        for total in 2..=max_total {
+            #[cfg(feature = "log")]
+            log::trace!(total; "searching commutators");
+            for setup in 0..=self.max_setup.min(total - 2) {
+                for c in &self.entries[self.levels[setup].clone()] {
+                    if let Some(commutator) = self.find_pure(&target.moved_by(&c.images), total - setup) {
+                        #[cfg(feature = "log")]
+                        log::debug!(cycle:?, setup, total; "found commutator");
+                        return Some(if setup == 0 { commutator } else { Alg::conjugate(sequence(&c.moves), commutator) });
+                    }
+                }
+            }
+        }
+        #[cfg(feature = "log")]
+        log::debug!(cycle:?, max_total; "found no commutator within the depth limits");
+        None
+    }
+
+    /// Finds a shortest commutator or conjugated commutator cycling three pieces,
+    /// sending the reference sticker of each to the reference sticker of the next.
+    ///
+    /// See [`CommutatorSearch::find`].
+    pub fn find_pieces(&self, pieces: [&Piece; 3]) -> Option<Alg<M>> {
+        self.find(pieces.map(|piece| piece.stickers[0]))
+    }
+
+    /// Searches pure commutators `[A, B]` with `|A| + |B| = total` performing the target.
+    fn find_pure(&self, target: &Target, total: usize) -> Option<Alg<M>> {
+        let [a, b, c] = target.cycle;
+        for len_a in 1..total {
+            let len_b = total - len_a;
+            if len_a > self.max_depth() || len_b > self.max_depth() {
+                continue;
+            }
+            for x in &self.entries[self.levels[len_a].clone()] {
+                for y in &self.entries[self.levels[len_b].clone()] {
+                    let apply = |tile: usize| {
+                        let tile = y.images[x.images[tile] as usize] as usize;
+                        y.inverse[x.inverse[tile] as usize] as usize
+                    };
+                    if apply(a) != b || apply(b) != c || apply(c) != a {
+                        continue;
+                    }
+                    let exact = (0..target.moves.len()).all(|tile| {
+                        if target.moves[tile] { apply(apply(apply(tile))) == tile } else { apply(tile) == tile }
+                    });
+                    if exact {
+                        return Some(Alg::commutator(sequence(&x.moves), sequence(&y.moves)));
+                    }
+                }
+            }
+        }
+        None
+    }
+}
+
+impl Target {
+    /// The target a commutator must perform so that conjugating it by `setup` performs
+    /// this one: every tile is replaced by its image under the setup.
+    fn moved_by(&self, setup: &Images) -> Target {
+        let mut moves = vec![false; self.moves.len()];
+        for (tile, &allowed) in self.moves.iter().enumerate() {
+            moves[setup[tile] as usize] = allowed;
+        }
+        Target { cycle: self.cycle.map(|tile| setup[tile] as usize), moves }
+    }
+}
+
+/// Wraps moves as an algorithm, without a sequence around a single move.
+fn sequence<M: Copy>(moves: &[M]) -> Alg<M> {
+    match moves {
+        [mv] => Alg::Move(*mv),
+        _ => Alg::moves(moves.iter().copied()),
+    }
+}
@@ -0,0 +1,92 @@
+use super::*;
+use crate::core::cube::geometry::Face;
+use crate::core::rubiks::moves::BasicMove::{self, *};
+use crate::core::rubiks::moves::SliceMove;
+use crate::core::rubiks::pieces::PieceKind;
+
+fn tile(face: Face, row: usize, col: usize) -> TilePos {
+    TilePos { face, row, col }
+}
+
+/// Checks that `alg` performs exactly the 3-cycle of the pieces carrying `cycle`.
+fn assert_performs<const N: usize, M: Turn + Into<TilePerm<N>>>(alg: &Alg<M>, cycle: [TilePos; 3]) {
+    let perm = TilePerm::<N>::from(alg);
+    let [a, b, c] = cycle;
+    assert_eq!((perm[a], perm[b], perm[c]), (b, c, a));
+    let pieces: Vec<Piece> = cycle.iter().map(|&t| Piece::containing::<N>(t)).collect();
+    let stickers: usize = pieces.iter().map(|p| p.stickers.len()).sum();
+    assert_eq!(perm.support().len(), stickers);
+    assert_eq!(perm.order(), 3);
+}
+
+fn outer_turns() -> Vec<BasicMove<3>> {
+    vec![U, U2, U3, D, D2, D3, L, L2, L3, R, R2, R3, F, F2, F3, B, B2, B3]
+}
+
+#[test]
+fn test_table() {
+    let search = CommutatorSearch::<3, _>::new(outer_turns(), 2, 0);
+    // 18 single turns and 243 distinct products of two turns of different faces
+    assert_eq!(search.table_size(), 1 + 18 + 243);
+    assert_eq!(search.max_depth(), 2);
+}
+
+#[test]
+fn test_pure_corner_commutator() {
+    let search = CommutatorSearch::<3, _>::new(outer_turns(), 3, 0);
+    let cycle = [tile(Face::Up, 2, 2), tile(Face::Up, 0, 2), tile(Face::Front, 2, 2)];
+    let alg = search.find(cycle).unwrap();
+    assert!(matches!(alg, Alg::Commutator(..)));
+    assert_eq!(alg.move_count(), 8);
+    assert_performs::<3, _>(&alg, cycle);
+}
+
+#[test]
+fn test_conjugated_commutator() {
+    // Three U-layer corners need a setup: no pure 8-move commutator cycles them
+    let search = CommutatorSearch::<3, _>::new(outer_turns(), 3, 1);
+    let cycle = [tile(Face::Up, 2, 2), tile(Face::Up, 0, 2), tile(Face::Up, 0, 0)];
+    let alg = search.find(cycle).unwrap();
+    assert_performs::<3, _>(&alg, cycle);
+    assert!(alg.move_count() <= 10);
+
+    // The same pieces cycled through different stickers, i.e. with a twist, need a
+    // different algorithm, which does not send the untwisted stickers where `alg` does
+    let twisted = [tile(Face::Up, 2, 2), tile(Face::Right, 0, 2), tile(Face::Up, 0, 0)];
+    let twisted_alg = search.find(twisted).unwrap();
+    assert_performs::<3, _>(&twisted_alg, twisted);
+    assert_ne!(twisted_alg, alg);
+    assert_ne!(TilePerm::<3>::from(&twisted_alg), TilePerm::from(&alg));
+    assert_ne!(TilePerm::<3>::from(&twisted_alg)[cycle[0]], cycle[1]);
+}
+
+#[test]
+fn test_pieces_and_impossible_targets() {
+    let search = CommutatorSearch::<3, _>::new(outer_turns(), 2, 1);
+    let corners: Vec<Piece> = Piece::all::<3>().into_iter().filter(|p| p.kind == PieceKind::Corner).collect();
+    // A corner, an edge and a centre can never be cycled
+    let edge = Piece::containing::<3>(tile(Face::Up, 0, 1));
+    let centre = Piece::containing::<3>(tile(Face::Up, 1, 1));
+    assert!(search.find_pieces([&corners[0], &edge, &centre]).is_none());
+    // Cycling a sticker to another sticker of the same piece is impossible
+    assert!(search.find([tile(Face::Up, 0, 0), tile(Face::Left, 0, 0), tile(Face::Up, 2, 2)]).is_none());
+}
+
+#[test]
+fn test_centre_commutator_on_big_cube() {
+    // Inner and outer slice turns of a 4×4×4 cycle three centres with an 8-move commutator
+    let mut moves = Vec::new();
+    for face in [Face::Up, Face::Right, Face::Front] {
+        for layer in 1..=2 {
+            for text in ["", "'"] {
+                moves.push(format!("{layer}{}{text}", face.letter()).parse::<SliceMove<4>>().unwrap());
+            }
+        }
+    }
+    let search = CommutatorSearch::<4, _>::new(moves, 3, 0);
+    let cycle = [tile(Face::Up, 1, 1), tile(Face::Front, 1, 1), tile(Face::Right, 1, 1)];
+    let alg = search.find(cycle).unwrap();
+    assert_eq!(alg.move_count(), 8);
+    assert_performs::<4, _>(&alg, cycle);
+}
+
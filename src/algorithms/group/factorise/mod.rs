@@ -0,0 +1,455 @@
+//! Factorising group elements into words in the generators.
+//!
+//! A [`PermGroup`] can decide whether a permutation is reachable, but not *how* to reach
+//! it. A [`Factoriser`] labels every transversal element of the stabiliser chain with a
+//! [`Word`] in the original generators, so sifting a permutation through the chain
+//! spells out a product of generators equal to it. This is a universal solver: it
+//! works for any generator set and any N and always terminates, although the words it
+//! produces are far from optimal.
+//!
+//! # Algorithm
+//!
+//! The word tables are filled in the style of Minkwitz (*An algorithm for solving the
+//! factorization problem in permutation groups*, 1998):
+//!
+//! 1. **Short words.** Breadth-first search over products of generators and their
+//!    inverses, up to a fixed budget of distinct elements. Each element is sifted through
+//!    the tables; an empty slot is filled with it, and an occupied slot with a longer word
+//!    is replaced by it. Sifting continues with the residue, whose word is the element's
+//!    word followed by the inverse of the slot's word.
+//! 2. **Closure.** Products of table entries with each other and with the generators are
+//!    sifted the same way, shortest first, until every slot is filled. The slots to fill
+//!    are known in advance from the basic orbits of the [`PermGroup`], and a table closed
+//!    under these products is a complete stabiliser chain, so this phase always finishes.
+//!
+//! Words are kept in normal form: adjacent powers of the same generator are merged and
+//! reduced modulo its order.
+//!
+//! # Cube States
+//!
+//! [`RubiksState::factorise_in`] answers the same question for a state entered sticker
+//! by sticker, such as a physical cube: is it reachable from the solved cube with the
+//! given generators, and how? Many permutations can produce the same colours, so it
+//! searches the stabiliser chain depth first for one that does, choosing at each level
+//! an entry that puts the right colour on the base point and backtracking on a clash.
+//!
+//! # Example
+//!
+//! ```
+//! use rubiks_cube_representation::algorithms::group::PermGroup;
+//! use rubiks_cube_representation::core::rubiks::moves::BasicMove::*;
+//! use rubiks_cube_representation::core::rubiks::tiles::TilePerm;
+//!
+//! let moves = [R, U];
+//! let group = PermGroup::<3>::new(moves);
+//! let factoriser = group.factoriser()?;
+//!
+//! let sune = [R, U, R3, U, R, U2, R3].iter().fold(TilePerm::<3>::ID, |acc, mv| acc * TilePerm::from(mv));
+//! let word = factoriser.factorise(&sune).unwrap();
+//! assert_eq!(factoriser.evaluate(&word), sune);
+//!
+//! // The word maps back onto the moves it was built from
+//! let solution: Vec<_> = word.letters().map(|i| moves[i]).collect();
+//! assert_eq!(solution.iter().fold(TilePerm::<3>::ID, |acc, mv| acc * TilePerm::from(mv)), sune);
+//!
+//! // F is not reachable with R and U alone
+//! assert!(factoriser.factorise(&TilePerm::from(&F)).is_none());
+//! # Ok::<(), rubiks_cube_representation::Error>(())
+//! ```
+
+use std::collections::{HashSet, VecDeque};
+use std::fmt;
+
+use super::{compose, identity, invert, to_images, Images, PermGroup};
+use crate::core::cube::schemes::ColourScheme;
+use crate::core::rubiks::tiles::{TilePerm, TilePos};
+use crate::core::rubiks::RubiksState;
+use crate::Error;
+
+#[cfg(test)]
+mod tests;
+
+/// Number of distinct short products enumerated before switching to closure.
+const SHORT_WORD_BUDGET: usize = 4096;
+
+/// A permutation together with a word evaluating to it.
+type Labelled = (Images, Word);
+
+/// One factor of a [`Word`]: a generator raised to a positive power.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct GeneratorPower {
+    /// Index into [`PermGroup::generators`]
+    pub generator: usize,
+    /// Exponent, between `1` and the order of the generator minus one
+    pub power: u32,
+}
+
+/// A product of generators, applied left to right.
+///
+/// Inverses are written as positive powers, so the inverse of a quarter turn appears as
+/// its cube. Adjacent factors never share a generator.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Word(pub Vec<GeneratorPower>);
+
+impl Word {
+    /// Number of factors.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns whether the word is empty, i.e. the identity.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Iterates over generator indices with each factor repeated `power` times.
+    ///
+    /// This is the word as a plain sequence of generator applications, ready to be
+    /// mapped onto the moves the group was built from.
+    pub fn letters(&self) -> impl Iterator<Item = usize> + '_ {
+        self.0.iter().flat_map(|factor| std::iter::repeat_n(factor.generator, factor.power as usize))
+    }
+}
+
+impl fmt::Display for Word {
+    /// Formats the word as e.g. `g0 g1^3`, or `1` for the empty word.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_empty() {
+            return write!(f, "1");
+        }
+        for (i, factor) in self.0.iter().enumerate() {
+            if i > 0 {
+                write!(f, " ")?;
+            }
+            match factor.power {
+                1 => write!(f, "g{}", factor.generator)?,
+                power => write!(f, "g{}^{power}", factor.generator)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A table entry: an element of the level's stabiliser and a word for it.
+#[derive(Clone, Debug)]
+struct Entry {
+    images: Images,
+    inverse: Images,
+    word: Word,
+}
+
+/// One level of the word-labelled stabiliser chain.
+#[derive(Clone, Debug)]
+struct WordLevel {
+    /// Flat index of the base point
+    point: usize,
+    /// Entry for each tile index in the basic orbit
+    entries: Vec<Option<Entry>>,
+}
+
+/// A stabiliser chain whose transversal elements are labelled with words.
+///
+/// Created by [`PermGroup::factoriser`].
+#[derive(Clone, Debug)]
+pub struct Factoriser<const N: usize> {
+    generators: Vec<TilePerm<N>>,
+    /// Order of each generator, used to keep words in normal form
+    orders: Vec<u32>,
+    levels: Vec<WordLevel>,
+}
+
+impl<const N: usize> PermGroup<N> {
+    /// Builds a [`Factoriser`] for writing group elements as words in the generators.
+    ///
+    /// See the [module documentation](self) for the algorithm.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OrderTooLarge`] if the order of a generator does not fit in the
+    /// `u32` powers of a [`Word`].
+    pub fn factoriser(&self) -> Result<Factoriser<N>, Error> {
+        Factoriser::new(self)
+    }
+}
+
+impl<const N: usize> Factoriser<N> {
+    fn new(group: &PermGroup<N>) -> Result<Self, Error> {
+        let orders = group
+            .generators
+            .iter()
+            .enumerate()
+            .map(|(generator, g)| {
+                g.order().to_u128().and_then(|order| u32::try_from(order).ok()).ok_or(Error::OrderTooLarge { generator })
+            })
+            .collect::<Result<Vec<u32>, Error>>()?;
+        let degree = 6 * N * N;
+        let levels = group
+            .levels
+            .iter()
+            .filter(|level| level.orbit.len() > 1)
+            .map(|level| {
+                let mut entries = vec![None; degree];
+                let id = identity(degree);
+                entries[level.point] = Some(Entry { images: id.clone(), inverse: id, word: Word::default() });
+                WordLevel { point: level.point, entries }
+            })
+            .collect();
+        let mut factoriser = Factoriser { generators: group.generators.clone(), orders, levels };
+        let target: usize = group.orbit_lengths().iter().sum();
+
+        // Generators and their inverses as single-factor words
+        let letters: Vec<Labelled> = factoriser
+            .orders
+            .iter()
+            .enumerate()
+            .filter(|&(_, &order)| order > 1)
+            .flat_map(|(generator, &order)| {
+                let images = to_images(&factoriser.generators[generator]);
+                let inverse = (order > 2).then(|| (invert(&images), Word(vec![GeneratorPower { generator, power: order - 1 }])));
+                std::iter::once((images, Word(vec![GeneratorPower { generator, power: 1 }]))).chain(inverse)
+            })
+            .collect();
+
+        factoriser.short_words(&letters, target);
+        #[cfg(feature = "log")]
+        log::trace!(filled = factoriser.filled(), target; "filled tables with short words");
+        factoriser.close(&letters, target);
+        #[cfg(feature = "log")]
+        log::debug!(
+            generators = factoriser.generators.len(),
+            levels = factoriser.levels.len(),
+            max_length = factoriser.max_length();
+            "built factoriser"
+        );
+        Ok(factoriser)
+    }
+
+    /// Writes `perm` as a word in the generators, or returns `None` if it is not in the
+    /// group.
+    ///
+    /// # Algorithm
+    ///
+    /// Sift `perm` through the tables, dividing at each level by the entry matching the
+    /// image of the base point. If the residue reaches the identity, `perm` is the
+    /// product of the entries used, deepest level first, so their words concatenate in
+    /// that order.
+    pub fn factorise(&self, perm: &TilePerm<N>) -> Option<Word> {
+        let mut residue = to_images(perm);
+        let mut used = Vec::new();
+        for level in &self.levels {
+            let entry = level.entries[residue[level.point] as usize].as_ref()?;
+            residue = compose(&residue, &entry.inverse);
+            used.push(&entry.word);
+        }
+        if residue.iter().enumerate().any(|(i, &image)| image as usize != i) {
+            return None;
+        }
+        let mut word = Word::default();
+        for factor in used.iter().rev().flat_map(|word| &word.0) {
+            self.push(&mut word, *factor);
+        }
+        Some(word)
+    }
+
+    /// Writes `state` as a word taking the solved cube in `scheme` to it, or returns
+    /// `None` if no element of the group does.
+    ///
+    /// See [`RubiksState::factorise_in`].
+    fn factorise_state<Scheme: ColourScheme>(&self, state: &RubiksState<N>, scheme: Scheme) -> Option<Word> {
+        let solved = RubiksState::<N>::solved_in(scheme);
+        let perm = self.search(0, identity(6 * N * N), &|tile, image| {
+            state[TilePos::from_index::<N>(image)] == solved[TilePos::from_index::<N>(tile)]
+        });
+        let word = perm.and_then(|perm| self.factorise(&super::to_tile_perm(&perm)));
+        #[cfg(feature = "log")]
+        log::debug!(reachable = word.is_some(), length = word.as_ref().map_or(0, Word::len); "factorised state");
+        word
+    }
+
+    /// Finds an element `u_m ⋯ u_k · prefix` of the group, with one entry `u_j` from each
+    /// level `j ≥ k`, under which every tile `t` and its image satisfy `fits(t, image)`.
+    ///
+    /// The image of the base point of level `j` is fixed once `u_j` is chosen, so each
+    /// choice is checked before descending; the remaining tiles are checked at the end.
+    fn search(&self, k: usize, prefix: Images, fits: &impl Fn(usize, usize) -> bool) -> Option<Images> {
+        let Some(level) = self.levels.get(k) else {
+            return prefix.iter().enumerate().all(|(tile, &image)| fits(tile, image as usize)).then_some(prefix);
+        };
+        level.entries.iter().flatten().find_map(|entry| {
+            let product = compose(&entry.images, &prefix);
+            if !fits(level.point, product[level.point] as usize) {
+                return None;
+            }
+            self.search(k + 1, product, fits)
+        })
+    }
+
+    /// Evaluates a word as the product of the generators it names.
+    pub fn evaluate(&self, word: &Word) -> TilePerm<N> {
+        word.letters().fold(TilePerm::identity(), |acc, generator| acc * &self.generators[generator])
+    }
+
+    /// The length of the longest word [`Factoriser::factorise`] can return, counted in
+    /// generator applications.
+    pub fn max_length(&self) -> usize {
+        self.levels
+            .iter()
+            .map(|level| level.entries.iter().flatten().map(|entry| entry.word.letters().count()).max().unwrap_or(0))
+            .sum()
+    }
+
+    /// Phase 1: breadth-first search over short products of generators.
+    fn short_words(&mut self, letters: &[Labelled], target: usize) {
+        let start = identity(6 * N * N);
+        let mut seen = HashSet::from([start.clone()]);
+        let mut queue = VecDeque::from([(start, Word::default())]);
+        while let Some((images, word)) = queue.pop_front() {
+            for (letter, letter_word) in letters {
+                if seen.len() >= SHORT_WORD_BUDGET {
+                    return;
+                }
+                let next = compose(&images, letter);
+                if seen.insert(next.clone()) {
+                    let next_word = self.concat(&word, letter_word);
+                    self.sift(next.clone(), next_word.clone());
+                    queue.push_back((next, next_word));
+                }
+            }
+            if self.filled() == target {
+                return;
+            }
+        }
+    }
+
+    /// Phase 2: sift products of entries until every slot is filled.
+    ///
+    /// A round that fills nothing leaves the table closed under the products, which
+    /// makes it complete, so that also ends the phase.
+    fn close(&mut self, letters: &[Labelled], target: usize) {
+        let mut before = 0;
+        while self.filled() < target && self.filled() > before {
+            before = self.filled();
+            #[cfg(feature = "log")]
+            log::trace!(filled = before, target; "sifting products of table entries");
+            let mut entries: Vec<Labelled> = self
+                .levels
+                .iter()
+                .flat_map(|level| level.entries.iter().flatten())
+                .filter(|entry| !entry.word.is_empty())
+                .map(|entry| (entry.images.clone(), entry.word.clone()))
+                .collect();
+            entries.sort_by_key(|(_, word)| word.len());
+            let factors: Vec<&Labelled> = entries.iter().chain(letters).collect();
+            let mut products: Vec<(&Labelled, &Labelled)> =
+                entries.iter().flat_map(|a| factors.iter().map(move |&b| (a, b))).collect();
+            products.sort_by_key(|(a, b)| a.1.len() + b.1.len());
+            for ((a, a_word), (b, b_word)) in products {
+                let word = self.concat(a_word, b_word);
+                self.sift(compose(a, b), word);
+                if self.filled() == target {
+                    return;
+                }
+            }
+        }
+    }
+
+    /// Sifts an element with its word, filling or shortening table entries on the way.
+    fn sift(&mut self, mut images: Images, mut word: Word) {
+        for k in 0..self.levels.len() {
+            let point = self.levels[k].point;
+            let image = images[point] as usize;
+            if image == point {
+                continue;
+            }
+            let Some(entry) = &self.levels[k].entries[image] else {
+                let inverse = invert(&images);
+                self.levels[k].entries[image] = Some(Entry { images, inverse, word });
+                return;
+            };
+            let residue = compose(&images, &entry.inverse);
+            let residue_word = self.concat(&word, &self.inverse_word(&entry.word));
+            if word.len() < entry.word.len() {
+                let inverse = invert(&images);
+                self.levels[k].entries[image] = Some(Entry { images, inverse, word });
+            }
+            images = residue;
+            word = residue_word;
+        }
+    }
+
+    fn filled(&self) -> usize {
+        self.levels.iter().map(|level| level.entries.iter().flatten().count()).sum()
+    }
+
+    /// Appends one factor, merging it with the last factor of the word if possible.
+    fn push(&self, word: &mut Word, factor: GeneratorPower) {
+        let order = self.orders[factor.generator];
+        match word.0.last_mut() {
+            Some(last) if last.generator == factor.generator => {
+                last.power = (last.power + factor.power) % order;
+                if last.power == 0 {
+                    word.0.pop();
+                }
+            }
+            _ => {
+                if !factor.power.is_multiple_of(order) {
+                    word.0.push(GeneratorPower { generator: factor.generator, power: factor.power % order });
+                }
+            }
+        }
+    }
+
+    fn concat(&self, a: &Word, b: &Word) -> Word {
+        let mut word = a.clone();
+        for factor in &b.0 {
+            self.push(&mut word, *factor);
+        }
+        word
+    }
+
+    fn inverse_word(&self, word: &Word) -> Word {
+        Word(
+            word.0
+                .iter()
+                .rev()
+                .map(|f| GeneratorPower { generator: f.generator, power: self.orders[f.generator] - f.power })
+                .collect(),
+        )
+    }
+}
+
+impl<const N: usize> RubiksState<N> {
+    /// Writes this state as a word in the generators of `factoriser`, taking the solved
+    /// cube in `scheme` to it, or returns `None` if the generators cannot reach it.
+    ///
+    /// Only the colours are compared, so any permutation producing this state will do;
+    /// see the [module documentation](crate::algorithms::group::factorise) for the
+    /// search. Whole-cube rotations are not undone first: a solved cube held differently
+    /// is reachable only if the generators include rotations.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rubiks_cube_representation::algorithms::group::PermGroup;
+    /// use rubiks_cube_representation::core::rubiks::moves::BasicMove::*;
+    /// use rubiks_cube_representation::{RubiksState, Western};
+    ///
+    /// let moves = [R, U];
+    /// let factoriser = PermGroup::<3>::new(moves).factoriser()?;
+    ///
+    /// // A cube scrambled with R and U, entered sticker by sticker
+    /// let mut cube = RubiksState::<3>::solved_in(Western) * &R * &U * &R3 * &U;
+    /// let word = cube.factorise_in(&factoriser, Western).unwrap();
+    /// let solved = word.letters().fold(RubiksState::<3>::solved_in(Western), |cube, i| cube * &moves[i]);
+    /// assert_eq!(solved, cube);
+    ///
+    /// // Swapping two stickers makes it unreachable
+    /// let (a, b) = (cube.up.vals[0][0], cube.up.vals[0][1]);
+    /// (cube.up.vals[0][0], cube.up.vals[0][1]) = (b, a);
+    /// assert!(cube.factorise_in(&factoriser, Western).is_none());
+    /// # Ok::<(), rubiks_cube_representation::Error>(())
+    /// ```
+    pub fn factorise_in<Scheme: ColourScheme>(&self, factoriser: &Factoriser<N>, scheme: Scheme) -> Option<Word> {
+        factoriser.factorise_state(self, scheme)
+    }
+}
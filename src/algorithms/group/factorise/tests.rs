@@ -0,0 +1,114 @@
+use super::*;
+use crate::algorithms::random::SplitMix64;
+use crate::core::cube::rotations::{X, Y};
+use crate::core::rubiks::moves::{BasicMove, SliceMove};
+use crate::core::rubiks::tiles::indexed::IndexPerm;
+use crate::Western;
+
+fn perm<const N: usize>(moves: &[BasicMove<N>]) -> TilePerm<N> {
+    moves.iter().fold(TilePerm::ID, |acc, mv| acc * TilePerm::from(mv))
+}
+
+#[test]
+fn test_word_normal_form() {
+    let group = PermGroup::<3>::new([BasicMove::<3>::R, BasicMove::U]);
+    let factoriser = group.factoriser().unwrap();
+    let r = |power| GeneratorPower { generator: 0, power };
+    let u = |power| GeneratorPower { generator: 1, power };
+
+    let mut word = Word(vec![r(1), u(2)]);
+    factoriser.push(&mut word, u(2));
+    assert_eq!(word, Word(vec![r(1)]));
+    factoriser.push(&mut word, r(3));
+    assert!(word.is_empty());
+
+    let word = Word(vec![r(1), u(3)]);
+    assert_eq!(factoriser.inverse_word(&word), Word(vec![u(1), r(3)]));
+    assert!(factoriser.concat(&word, &factoriser.inverse_word(&word)).is_empty());
+    assert_eq!(word.to_string(), "g0 g1^3");
+    assert_eq!(Word::default().to_string(), "1");
+    assert_eq!(word.letters().collect::<Vec<_>>(), [0, 1, 1, 1]);
+}
+
+#[test]
+fn test_factorise_full_cube() {
+    use BasicMove::*;
+    let moves = [U, D, L, R, F, B];
+    let group = PermGroup::<3>::new(moves);
+    let factoriser = group.factoriser().unwrap();
+    let mut rng = SplitMix64::new(99);
+    for _ in 0..10 {
+        let target = group.random_element(&mut rng);
+        let word = factoriser.factorise(&target).expect("random elements are in the group");
+        assert_eq!(factoriser.evaluate(&word), target);
+        assert!(word.letters().count() <= factoriser.max_length());
+        let solution: Vec<BasicMove<3>> = word.letters().map(|i| moves[i]).collect();
+        assert_eq!(perm(&solution), target);
+    }
+    assert!(factoriser.factorise(&TilePerm::ID).unwrap().is_empty());
+}
+
+#[test]
+fn test_restricted_generators() {
+    use BasicMove::*;
+    let group = PermGroup::<3>::new([R, U]);
+    let factoriser = group.factoriser().unwrap();
+    let t_perm = perm::<3>(&[R, U, R3, U3, R3, F, R2, U3, R3, U3, R, U, R3, F3]);
+    // The T-permutation swaps two corners and two edges in ⟨R, U, F⟩ but is not in ⟨R, U⟩
+    assert!(factoriser.factorise(&t_perm).is_none());
+    assert!(!group.contains(&t_perm));
+
+    let sexy = perm::<3>(&[R, U, R3, U3]);
+    let word = factoriser.factorise(&sexy).unwrap();
+    assert_eq!(factoriser.evaluate(&word), sexy);
+}
+
+#[test]
+fn test_factorise_rotations_and_big_cubes() {
+    let rotations = PermGroup::<2>::new([X, Y]);
+    let factoriser = rotations.factoriser().unwrap();
+    let z = TilePerm::<2>::from(&crate::Z);
+    let word = factoriser.factorise(&z).unwrap();
+    assert_eq!(factoriser.evaluate(&word), z);
+    assert!(word.letters().count() <= 5);
+
+    // Inner slices of the 4×4×4 together with an outer face
+    let group = PermGroup::<4>::new([TilePerm::from(&SliceMove::<4>::Rs(2)), TilePerm::from(&BasicMove::<4>::U)]);
+    let factoriser = group.factoriser().unwrap();
+    let mut rng = SplitMix64::new(5);
+    let target = group.random_element(&mut rng);
+    let word = factoriser.factorise(&target).unwrap();
+    assert_eq!(factoriser.evaluate(&word), target);
+}
+
+#[test]
+fn test_factorise_state() {
+    use BasicMove::*;
+    let moves = [R, U];
+    let factoriser = PermGroup::<3>::new(moves).factoriser().unwrap();
+    let solved = RubiksState::<3>::solved_in(Western);
+
+    let scrambled = [R, U2, R3, U3, R, U, R2, U].iter().fold(solved.clone(), |cube, mv| cube * mv);
+    let word = scrambled.factorise_in(&factoriser, Western).expect("⟨R, U⟩ scrambles are reachable");
+    assert_eq!(word.letters().fold(solved.clone(), |cube, i| cube * &moves[i]), scrambled);
+    assert!(solved.factorise_in(&factoriser, Western).unwrap().is_empty());
+
+    // A state that needs F, a whole-cube rotation or a different colour scheme is not
+    assert!((&scrambled * &F).factorise_in(&factoriser, Western).is_none());
+    assert!((&solved * &Y).factorise_in(&factoriser, Western).is_none());
+    assert!(scrambled.factorise_in(&factoriser, crate::Japanese).is_none());
+}
+
+#[test]
+fn test_generator_order_too_large() {
+    // One cycle for each prime up to 29: order 6,469,693,230, beyond u32
+    let mut images: Vec<u16> = (0..6 * 5 * 5).collect();
+    let mut start = 0;
+    for prime in [2, 3, 5, 7, 11, 13, 17, 19, 23, 29] {
+        images[start..start + prime].rotate_left(1);
+        start += prime;
+    }
+    let huge = TilePerm::<5>::from(IndexPerm::<5>::from_images(images).unwrap());
+    let group = PermGroup::<5>::new([TilePerm::ID, huge]);
+    assert_eq!(group.factoriser().err(), Some(Error::OrderTooLarge { generator: 1 }));
+}
@@ -0,0 +1,100 @@
+//! Orbits of tiles under a set of generators.
+//!
+//! The orbit of a tile is the set of positions it can be moved to by some product of the
+//! generators: which stickers can ever reach which positions. Orbits partition the
+//! 6·N² tiles, and depend only on the generators, not on the full stabiliser chain, so
+//! they are available both as a free function, [`tile_orbits`], and as methods on
+//! [`PermGroup`].
+//!
+//! Each orbit is returned as a [`TileSet<N>`], which implements [`Restriction<N>`], so
+//! it plugs straight into [`TilePerm::agree_on`] and the rest of the restriction
+//! framework.
+//!
+//! # Algorithm
+//!
+//! Flood fill: starting from the smallest tile not yet assigned, repeatedly apply every
+//! generator to the tiles found so far until no new tile appears. Each tile is visited
+//! once per generator, so this takes O(g·N²) time for `g` generators.
+//!
+//! # Example
+//!
+//! ```
+//! use rubiks_cube_representation::algorithms::group::orbits::tile_orbits;
+//! use rubiks_cube_representation::core::rubiks::moves::BasicMove;
+//!
+//! // Under the half-turn group, stickers never leave their pair of opposite faces
+//! let orbits = tile_orbits::<3, _, _>(
+//!     [BasicMove::<3>::U2, BasicMove::D2, BasicMove::L2, BasicMove::R2, BasicMove::F2, BasicMove::B2],
+//! );
+//! for orbit in &orbits {
+//!     let first = orbit.iter().next().unwrap().face;
+//!     assert!(orbit.iter().all(|tile| tile.face == first || tile.face == first.opposite()));
+//! }
+//! ```
+//!
+//! [`Restriction<N>`]: crate::core::rubiks::tiles::restrictions::Restriction
+//! [`TilePerm::agree_on`]: crate::core::rubiks::tiles::TilePerm::agree_on
+
+use super::PermGroup;
+use crate::core::rubiks::tiles::restrictions::TileSet;
+use crate::core::rubiks::tiles::{TilePerm, TilePos};
+
+#[cfg(test)]
+mod tests;
+
+/// Returns the orbits of all tiles under the group generated by `generators`.
+///
+/// Every tile lies in exactly one orbit, including tiles fixed by every generator,
+/// which form orbits of size one. Orbits are sorted by their smallest tile.
+pub fn tile_orbits<const N: usize, I, T>(generators: I) -> Vec<TileSet<N>>
+where
+    I: IntoIterator<Item = T>,
+    T: Into<TilePerm<N>>,
+{
+    let generators: Vec<TilePerm<N>> = generators.into_iter().map(Into::into).collect();
+    orbits_of(&generators)
+}
+
+impl<const N: usize> PermGroup<N> {
+    /// Returns the orbits of all tiles under the group.
+    ///
+    /// See [`tile_orbits`].
+    pub fn orbits(&self) -> Vec<TileSet<N>> {
+        orbits_of(&self.generators)
+    }
+
+    /// Returns the orbit of a single tile: every position the group can move it to.
+    pub fn orbit(&self, tile: TilePos) -> TileSet<N> {
+        orbit_from(&self.generators, tile, &mut vec![false; 6 * N * N])
+    }
+}
+
+fn orbits_of<const N: usize>(generators: &[TilePerm<N>]) -> Vec<TileSet<N>> {
+    let mut visited = vec![false; 6 * N * N];
+    let mut orbits = Vec::new();
+    for tile in TilePos::all::<N>() {
+        if !visited[tile.index::<N>()] {
+            orbits.push(orbit_from(generators, tile, &mut visited));
+        }
+    }
+    orbits
+}
+
+/// Flood-fills the orbit of `start`, marking its tiles in `visited`.
+fn orbit_from<const N: usize>(generators: &[TilePerm<N>], start: TilePos, visited: &mut [bool]) -> TileSet<N> {
+    visited[start.index::<N>()] = true;
+    let mut orbit = vec![start];
+    let mut next = 0;
+    while next < orbit.len() {
+        let tile = orbit[next];
+        next += 1;
+        for generator in generators {
+            let image = generator[tile];
+            if !visited[image.index::<N>()] {
+                visited[image.index::<N>()] = true;
+                orbit.push(image);
+            }
+        }
+    }
+    orbit.into_iter().collect()
+}
@@ -0,0 +1,87 @@
+use super::*;
+use crate::core::cube::rotations::Y;
+use crate::core::rubiks::moves::{BasicMove, SliceMove};
+use crate::core::rubiks::pieces::{Piece, PieceKind};
+use crate::core::rubiks::tiles::restrictions::Restriction;
+use crate::Face;
+
+fn face_turns<const N: usize>() -> Vec<TilePerm<N>> {
+    use BasicMove::*;
+    [U, D, L, R, F, B].iter().map(TilePerm::from).collect()
+}
+
+fn assert_partition<const N: usize>(orbits: &[TileSet<N>], generators: &[TilePerm<N>]) {
+    let total: usize = orbits.iter().map(TileSet::len).sum();
+    assert_eq!(total, 6 * N * N);
+    for orbit in orbits {
+        for generator in generators {
+            assert!(orbit.iter().all(|&tile| orbit.contains(&generator[tile])));
+        }
+    }
+}
+
+#[test]
+fn test_full_cube_orbits() {
+    let generators = face_turns::<3>();
+    let orbits = tile_orbits::<3, _, _>(generators.clone());
+    assert_partition(&orbits, &generators);
+    // Corner stickers, edge stickers and six fixed centres
+    let mut sizes: Vec<usize> = orbits.iter().map(TileSet::len).collect();
+    sizes.sort();
+    assert_eq!(sizes, [1, 1, 1, 1, 1, 1, 24, 24]);
+    assert!(orbits.windows(2).all(|w| w[0].iter().next() < w[1].iter().next()));
+}
+
+#[test]
+fn test_five_by_five_orbits_follow_piece_kinds() {
+    let mut generators = face_turns::<5>();
+    for layer in [2, 3] {
+        generators.extend(
+            [SliceMove::<5>::Us(layer), SliceMove::Rs(layer), SliceMove::Fs(layer)].iter().map(TilePerm::from),
+        );
+    }
+    let group = PermGroup::<5>::new(generators.clone());
+    let orbits = group.orbits();
+    assert_partition(&orbits, &generators);
+    // One orbit per piece kind: corners, midges, centres, x-centres and t-centres, except
+    // that wings cannot be flipped in place, so their stickers split into two orbits
+    assert_eq!(orbits.len(), 7);
+    for orbit in &orbits {
+        let kind = Piece::containing::<5>(*orbit.iter().next().unwrap()).kind;
+        assert!(orbit.iter().all(|&tile| Piece::containing::<5>(tile).kind == kind));
+    }
+    let wing = TilePos { face: Face::Up, row: 0, col: 1 };
+    assert_eq!(group.orbit(wing).len(), 24);
+    let mirror_wing = TilePos { face: Face::Up, row: 0, col: 3 };
+    assert!(!group.orbit(wing).contains(&mirror_wing));
+    assert_eq!(Piece::containing::<5>(wing).kind, PieceKind::Wing);
+}
+
+#[test]
+fn test_half_turn_orbits() {
+    use BasicMove::*;
+    let generators: Vec<TilePerm<3>> = [R2, U2, F2].iter().map(TilePerm::from).collect();
+    let orbits = tile_orbits::<3, _, _>(generators.clone());
+    assert_partition(&orbits, &generators);
+    // The DBL corner, the DL, DB and BL edges and all six centres never move
+    let fixed = orbits.iter().filter(|orbit| orbit.len() == 1).count();
+    assert_eq!(fixed, 3 + 3 * 2 + 6);
+    for orbit in &orbits {
+        let first = orbit.iter().next().unwrap().face;
+        assert!(orbit.iter().all(|tile| tile.face == first || tile.face == first.opposite()));
+    }
+}
+
+#[test]
+fn test_orbits_are_restrictions() {
+    let group = PermGroup::<3>::new([Y]);
+    let centre = TilePos { face: Face::Front, row: 1, col: 1 };
+    let orbit = group.orbit(centre);
+    assert_eq!(orbit.len(), 4);
+    assert!(!orbit.contains(&TilePos { face: Face::Up, row: 1, col: 1 }));
+    // Y² and Y³ = Y' agree on nothing in the orbit, Y⁴ agrees with the identity everywhere
+    let y = TilePerm::<3>::from(&Y);
+    assert!(!y.agree_on(&(&y * &y), orbit.clone()));
+    assert!((&(&y * &y) * &(&y * &y)).agree_on(&TilePerm::ID, orbit.clone()));
+    assert_eq!(<TileSet<3> as Restriction<3>>::restricted_positions(&orbit).count(), 4);
+}
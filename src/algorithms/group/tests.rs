@@ -0,0 +1,78 @@
+use super::*;
+use crate::core::cube::rotations::{X, Y};
+use crate::core::rubiks::moves::{BasicMove, MiddleMove};
+
+fn face_turns<const N: usize>() -> [BasicMove<N>; 6] {
+    use BasicMove::*;
+    [U, D, L, R, F, B]
+}
+
+#[test]
+fn test_rubiks_cube_group_order() {
+    let group = PermGroup::<3>::new(face_turns::<3>());
+    assert_eq!(group.order().to_string(), "43252003274489856000");
+    assert_eq!(group.order(), 43_252_003_274_489_856_000u128);
+    // Centres are fixed, so no base point is a centre
+    assert!(group.base().iter().all(|tile| (tile.row, tile.col) != (1, 1)));
+}
+
+#[test]
+fn test_small_groups() {
+    use BasicMove::*;
+    assert_eq!(PermGroup::<3>::new(Vec::<TilePerm<3>>::new()).order(), 1u128);
+    assert_eq!(PermGroup::<3>::new([U]).order(), 4u128);
+    assert_eq!(PermGroup::<3>::new([U2, R2]).order(), 12u128);
+    // Whole-cube rotations form the rotation group of the cube
+    assert_eq!(PermGroup::<3>::new([X, Y]).order(), 24u128);
+    // ⟨M, U⟩ on 3×3: the "LSE"-style group including centre movement
+    let mu = PermGroup::<3>::new([TilePerm::from(&MiddleMove::<3>::M), TilePerm::from(&U)]);
+    // 6!·2⁵ edge states, 4 centre and 4 corner positions, halved by the shared parity
+    assert_eq!(mu.order(), 184_320u128);
+}
+
+#[test]
+fn test_two_by_two_group() {
+    // With all six faces the 2×2×2 group also contains whole-cube rotations
+    let group = PermGroup::<2>::new(face_turns::<2>());
+    assert_eq!(group.order(), 3_674_160u128 * 24);
+}
+
+#[test]
+fn test_order_matches_orbit_lengths() {
+    use BasicMove::*;
+    let group = PermGroup::<3>::new([R, U]);
+    let lengths = group.orbit_lengths();
+    assert_eq!(lengths.len(), group.base().len());
+    let product: u128 = lengths.iter().map(|&l| l as u128).product();
+    assert_eq!(group.order(), product);
+    // |⟨R, U⟩| on the 3×3×3
+    assert_eq!(group.order(), 73_483_200u128);
+}
+
+#[test]
+fn test_membership() {
+    use BasicMove::*;
+    let group = PermGroup::<3>::new([R, U]);
+    let sune = [R, U, R3, U, R, U2, R3].iter().fold(TilePerm::ID, |acc, mv| acc * TilePerm::from(mv));
+    assert!(group.contains(&sune));
+    assert!(group.contains(&TilePerm::ID));
+    assert!(!group.contains(&TilePerm::from(&F)));
+    assert!(!group.contains(&TilePerm::from(&X)));
+
+    for generator in group.strong_generators() {
+        assert!(group.contains(&generator));
+    }
+}
+
+#[test]
+fn test_random_elements() {
+    use BasicMove::*;
+    let group = PermGroup::<3>::new([R, U]);
+    let full = PermGroup::<3>::new(face_turns::<3>());
+    let mut rng = SplitMix64::new(2024);
+    let samples: Vec<TilePerm<3>> = (0..20).map(|_| group.random_element(&mut rng)).collect();
+    assert!(samples.iter().all(|p| group.contains(p) && full.contains(p)));
+    // Twenty samples from 73 million elements are distinct
+    assert!(samples.iter().enumerate().all(|(i, p)| samples[..i].iter().all(|q| q != p)));
+}
+
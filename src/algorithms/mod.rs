@@ -1,4 +1,16 @@
+//! Algorithms built on the core cube representation.
+//!
+//! - [`commutators`]: Searching for commutators that perform a given 3-cycle
+//! - [`group`]: Schreier–Sims stabiliser chains for groups generated by cube operations
+//! - [`bignum`]: Arbitrary-precision natural numbers for group orders
+//! - [`random`]: A seedable pseudo-random number generator
+
 #[cfg(test)]
 mod tests;
 
-// Solving algorithms will go here in the future
\ No newline at end of file
+pub mod bignum;
+pub mod commutators;
+pub mod group;
+pub mod random;
+
+// Solving algorithms will go here in the future
@@ -0,0 +1,75 @@
+//! A small, seedable pseudo-random number generator.
+//!
+//! The crate has no dependencies, so algorithms that need randomness (such as uniform
+//! sampling from a permutation group) take a [`SplitMix64`] instead of a `rand` RNG.
+//! SplitMix64 is fast, passes BigCrush, and is fully determined by its 64-bit seed,
+//! which makes sampled results reproducible in tests.
+//!
+//! It is **not** cryptographically secure.
+//!
+//! # Example
+//!
+//! ```
+//! use rubiks_cube_representation::algorithms::random::SplitMix64;
+//!
+//! let mut a = SplitMix64::new(42);
+//! let mut b = SplitMix64::new(42);
+//! assert_eq!(a.next_u64(), b.next_u64());
+//! assert!(a.below(6) < 6);
+//! ```
+
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+
+#[cfg(test)]
+mod tests;
+
+/// The SplitMix64 generator of Steele, Lea and Flood.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SplitMix64 {
+    state: u64,
+}
+
+impl SplitMix64 {
+    /// Creates a generator from a fixed seed.
+    pub fn new(seed: u64) -> Self {
+        SplitMix64 { state: seed }
+    }
+
+    /// Creates a generator seeded from the per-process random keys of the standard
+    /// library's [`RandomState`].
+    pub fn from_entropy() -> Self {
+        let mut hasher = RandomState::new().build_hasher();
+        hasher.write_u64(0x5EED);
+        SplitMix64::new(hasher.finish())
+    }
+
+    /// Returns the next 64 random bits.
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Returns a uniformly distributed value in `0..bound`.
+    ///
+    /// Uses rejection sampling, so there is no modulo bias.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bound == 0`.
+    pub fn below(&mut self, bound: usize) -> usize {
+        assert!(bound > 0, "cannot sample from an empty range");
+        let bound = bound as u64;
+        // Largest multiple of `bound` that fits, minus one
+        let zone = u64::MAX - (u64::MAX - bound + 1) % bound;
+        loop {
+            let x = self.next_u64();
+            if x <= zone {
+                return (x % bound) as usize;
+            }
+        }
+    }
+}
@@ -0,0 +1,20 @@
+use super::*;
+
+#[test]
+fn test_reference_output() {
+    // First outputs for seed 1234567 from the reference implementation
+    let mut rng = SplitMix64::new(1234567);
+    assert_eq!(rng.next_u64(), 6457827717110365317);
+    assert_eq!(rng.next_u64(), 3203168211198807973);
+}
+
+#[test]
+fn test_below_is_in_range_and_covers_it() {
+    let mut rng = SplitMix64::new(7);
+    let mut seen = [0usize; 6];
+    for _ in 0..600 {
+        seen[rng.below(6)] += 1;
+    }
+    assert!(seen.iter().all(|&count| count > 50), "{seen:?}");
+    assert_eq!(rng.below(1), 0);
+}
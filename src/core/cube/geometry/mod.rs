@@ -6,6 +6,7 @@
 //! - **Diagonals**: The four main diagonals connecting opposite vertices
 //! - **Faces**: The six faces of the cube with standard orientation
 //! - **Face Adjacencies**: Mapping between faces and their neighboring faces with directional edges
+//! - **3D Coordinates**: Positions of corners, faces and tiles in a centred [`Point3`] frame
 //!
 //! # Core Design
 //!
@@ -27,11 +28,13 @@
 //! - [`Face::adjacencies`]: Returns all four neighboring faces with their edge relationships
 //! - [`Face::adjacent`]: Gets the specific neighboring face along a given edge
 //! - [`From<CubeCorner> for CubeDiag`]: Converts corners to their containing diagonals
+//! - [`Face::normal`], [`Face::row_direction`], [`Face::col_direction`]: A face's axes in 3D
+//! - [`TilePos::centre`] and [`TilePos::at_point`]: Converts between tiles and 3D points
 //!
 //! These functions work together to enable the rotation system's core algorithm for converting
 //! between different representation formats.
 
-use std::ops::Mul;
+use std::{array::from_fn, ops::Mul};
 
 use crate::core::{rubiks::tiles::TilePos, Angle};
 
@@ -114,7 +117,7 @@ impl CubeDiag {
 ///
 /// The naming convention follows standard Rubik's cube notation, representing
 /// the faces as they appear when viewing the cube in standard orientation.
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum Face {
     /// The top face of the cube
     Up=0,
@@ -136,7 +139,24 @@ pub enum Face {
 pub const FACES: [Face;6] = [Face::Up, Face::Down, Face::Left, Face::Right, Face::Front, Face::Back];
 
 impl Face {
-    pub fn opposite(self) -> Self {
+    /// Returns the single-letter name of this face used in move notation (`U`, `D`, ...).
+    pub fn letter(self) -> char {
+        match self {
+            Face::Up => 'U',
+            Face::Down => 'D',
+            Face::Left => 'L',
+            Face::Right => 'R',
+            Face::Front => 'F',
+            Face::Back => 'B',
+        }
+    }
+
+    /// Returns the face named by a move notation letter, the inverse of [`Face::letter`].
+    pub fn from_letter(letter: char) -> Option<Self> {
+        FACES.into_iter().find(|face| face.letter() == letter)
+    }
+
+    pub const fn opposite(self) -> Self {
         use Face::*;
         match self {
             Up => Down,
@@ -297,7 +317,7 @@ impl Face {
     ///
     /// A [`CubeCorner`] representing the 3D coordinates of the upper-left corner
     /// of this face's internal representation.
-    pub fn principal_corner(&self) -> CubeCorner {
+    pub const fn principal_corner(&self) -> CubeCorner {
         match self {
             Face::Up => CubeCorner { up: true, left: true, front: false },      // ULB matches (contains U)
             Face::Down => CubeCorner { up: false, left: true, front: true },    // DFL (URB doesn't contain D)
@@ -585,7 +605,7 @@ impl Face {
     /// let north_neighbor = up_adjacencies.north;
     /// let east_neighbor = up_adjacencies.east;
     /// ```
-    pub fn adjacencies(self) -> Adjacencies {
+    pub const fn adjacencies(self) -> Adjacencies {
         use Face::*;
         use FaceSide::*;
         match self {
@@ -660,7 +680,7 @@ impl Face {
     /// let north_adjacent = Face::Up.adjacent(FaceSide::North);
     /// // north_adjacent contains the face north of Up and the corresponding edge
     /// ```
-    pub fn adjacent(self, side: FaceSide) -> AdjacentFace {
+    pub const fn adjacent(self, side: FaceSide) -> AdjacentFace {
         let adjacencies = self.adjacencies();
         match side {
             FaceSide::North => adjacencies.north,
@@ -734,5 +754,137 @@ impl Mul<Angle> for FaceSide {
     }
 }
 
+/// A point or direction in the cube's 3D frame.
+///
+/// The frame is right-handed and centred on the middle of the cube: `x` points towards
+/// the Right face, `y` towards the Up face and `z` towards the Front face. One unit is
+/// the width of one tile, so an N×N×N cube occupies `[-N/2, N/2]` on every axis.
+pub type Point3 = [f64; 3];
+
+impl CubeCorner {
+    /// Returns the unit direction signs of this corner as `[x, y, z]`, each `±1`.
+    pub const fn signs(self) -> [i32; 3] {
+        const fn sign(positive: bool) -> i32 {
+            if positive { 1 } else { -1 }
+        }
+        [sign(!self.left), sign(self.up), sign(self.front)]
+    }
+
+    /// Returns the position of this corner on an N×N×N cube.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rubiks_cube_representation::CubeCorner;
+    ///
+    /// let urf = CubeCorner { up: true, left: false, front: true };
+    /// assert_eq!(urf.position::<3>(), [1.5, 1.5, 1.5]);
+    /// ```
+    pub fn position<const N: usize>(self) -> Point3 {
+        self.signs().map(|s| s as f64 * N as f64 / 2.0)
+    }
+}
+
+impl Face {
+    /// Returns the outward unit normal of this face.
+    pub const fn normal(self) -> [i32; 3] {
+        match self {
+            Face::Up => [0, 1, 0],
+            Face::Down => [0, -1, 0],
+            Face::Left => [-1, 0, 0],
+            Face::Right => [1, 0, 0],
+            Face::Front => [0, 0, 1],
+            Face::Back => [0, 0, -1],
+        }
+    }
+
+    /// Returns the face whose outward normal is the given unit vector, if any.
+    pub fn from_normal(normal: [i32; 3]) -> Option<Face> {
+        FACES.into_iter().find(|face| face.normal() == normal)
+    }
+
+    /// Returns the unit direction in which row indices increase on this face.
+    ///
+    /// This is the direction of the face's South side, pointing away from its
+    /// [`principal_corner`](Face::principal_corner).
+    pub const fn row_direction(self) -> [i32; 3] {
+        self.adjacent(FaceSide::South).face.normal()
+    }
+
+    /// Returns the unit direction in which column indices increase on this face.
+    ///
+    /// This is the direction of the face's East side, pointing away from its
+    /// [`principal_corner`](Face::principal_corner).
+    pub const fn col_direction(self) -> [i32; 3] {
+        self.adjacent(FaceSide::East).face.normal()
+    }
+
+    /// Maps in-face coordinates to a point on the face of an N×N×N cube.
+    ///
+    /// `u` and `v` are measured in tiles from the face's principal corner along its rows
+    /// and columns respectively, so `(0, 0)` is the principal corner and `(N, N)` is the
+    /// opposite corner of the face.
+    pub fn point<const N: usize>(self, u: f64, v: f64) -> Point3 {
+        let origin = self.principal_corner().position::<N>();
+        let (row, col) = (self.row_direction(), self.col_direction());
+        from_fn(|i| origin[i] + u * row[i] as f64 + v * col[i] as f64)
+    }
+}
+
+impl TilePos {
+    /// Returns the centre of this tile on an N×N×N cube.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rubiks_cube_representation::Face;
+    /// use rubiks_cube_representation::core::rubiks::tiles::TilePos;
+    ///
+    /// let centre = TilePos { face: Face::Up, row: 1, col: 1 };
+    /// assert_eq!(centre.centre::<3>(), [0.0, 1.5, 0.0]);
+    /// ```
+    pub fn centre<const N: usize>(self) -> Point3 {
+        self.face.point::<N>(self.row as f64 + 0.5, self.col as f64 + 0.5)
+    }
+
+    /// Returns the integer coordinates `[x, y, z]` of the cubie carrying this tile.
+    ///
+    /// Cubie coordinates run from `0` to `N-1` along the `x` (Left to Right), `y`
+    /// (Down to Up) and `z` (Back to Front) axes.
+    pub fn cubie<const N: usize>(self) -> [usize; 3] {
+        let centre = self.centre::<N>();
+        let normal = self.face.normal();
+        from_fn(|i| {
+            let inside = centre[i] - 0.5 * normal[i] as f64;
+            (inside + N as f64 / 2.0 - 0.5).round() as usize
+        })
+    }
+
+    /// Returns the tile containing a point on the surface of an N×N×N cube.
+    ///
+    /// The face is chosen by the coordinate of largest magnitude, which must lie within
+    /// `tolerance` of the surface. Points on the boundary between two tiles belong to the
+    /// tile with the larger index. Returns `None` for points off the surface.
+    pub fn at_point<const N: usize>(point: Point3, tolerance: f64) -> Option<TilePos> {
+        let half = N as f64 / 2.0;
+        let axis = (0..3).max_by(|&a, &b| point[a].abs().total_cmp(&point[b].abs()))?;
+        if N == 0 || (point[axis].abs() - half).abs() > tolerance {
+            return None;
+        }
+        let mut normal = [0; 3];
+        normal[axis] = if point[axis] > 0.0 { 1 } else { -1 };
+        let face = Face::from_normal(normal)?;
+
+        let origin = face.principal_corner().position::<N>();
+        let offset: Point3 = from_fn(|i| point[i] - origin[i]);
+        let along = |direction: [i32; 3]| -> f64 { (0..3).map(|i| offset[i] * direction[i] as f64).sum() };
+        let (u, v) = (along(face.row_direction()), along(face.col_direction()));
+        let index = |t: f64| {
+            (t >= -tolerance && t <= N as f64 + tolerance).then(|| (t.max(0.0).floor() as usize).min(N - 1))
+        };
+        Some(TilePos { face, row: index(u)?, col: index(v)? })
+    }
+}
+
 #[cfg(test)]
 mod tests;
\ No newline at end of file
@@ -1,5 +1,5 @@
 use crate::{FACES, CubeDiag};
-use super::{FaceSide, FACE_SIDES};
+use super::{CubeCorner, Face, FaceSide, FACE_SIDES};
 
 #[test]
 fn test_principal_corner_consistency() {
@@ -48,4 +48,49 @@ fn test_principal_corner_adjacency() {
         assert!(principal_corner.touching(west_adjacent.face),
             "Face {:?}: principal corner doesn't touch west adjacent face {:?}", face, west_adjacent.face);
     }
-}
\ No newline at end of file
+}
+#[test]
+fn test_face_directions_follow_principal_corner() {
+    for &face in &FACES {
+        let (normal, row, col) = (face.normal(), face.row_direction(), face.col_direction());
+        let dot = |a: [i32; 3], b: [i32; 3]| (0..3).map(|i| a[i] * b[i]).sum::<i32>();
+        assert_eq!(dot(normal, row), 0, "{face:?}: rows leave the face");
+        assert_eq!(dot(normal, col), 0, "{face:?}: columns leave the face");
+        assert_eq!(dot(row, col), 0, "{face:?}: rows and columns are not perpendicular");
+        assert_eq!(Face::from_normal(normal), Some(face));
+        assert_eq!(face.opposite().normal(), normal.map(|c| -c));
+
+        // Rows and columns run away from the principal corner
+        let signs = face.principal_corner().signs();
+        assert_eq!(dot(signs, row), -1, "{face:?}: rows start at the wrong edge");
+        assert_eq!(dot(signs, col), -1, "{face:?}: columns start at the wrong edge");
+        assert_eq!(face.point::<3>(0.0, 0.0), face.principal_corner().position::<3>());
+    }
+    let ulb = CubeCorner { up: true, left: true, front: false };
+    assert_eq!(ulb.position::<2>(), [-1.0, 1.0, -1.0]);
+}
+
+#[test]
+fn test_tile_points_round_trip() {
+    use crate::core::rubiks::tiles::TilePos;
+    for &face in &FACES {
+        for row in 0..4 {
+            for col in 0..4 {
+                let tile = TilePos { face, row, col };
+                let centre = tile.centre::<4>();
+                assert_eq!(TilePos::at_point::<4>(centre, 1e-9), Some(tile));
+
+                // The tile's cubie sits just inside the tile centre
+                let cubie = tile.cubie::<4>();
+                let normal = face.normal();
+                for i in 0..3 {
+                    let expected = centre[i] - 0.5 * normal[i] as f64 + 1.5;
+                    assert_eq!(cubie[i] as f64, expected, "{tile:?}");
+                }
+            }
+        }
+    }
+    assert_eq!(TilePos::at_point::<3>([0.0, 0.0, 0.0], 1e-9), None);
+    assert_eq!(TilePos::at_point::<3>([2.0, 1.5, 0.0], 1e-9), None);
+    assert_eq!(TilePos::at_point::<3>([-1.5, 1.0, 0.5], 1e-9).map(|t| t.face), Some(Face::Left));
+}
@@ -8,6 +8,7 @@
 //! - [`geometry`]: Fundamental geometric primitives (corners, diagonals, faces)
 //! - [`rotations`]: The octahedral group implementation using diagonal permutations
 //! - [`schemes`]: Color scheme abstraction and rotation interface
+//! - [`symmetries`]: The 48-element symmetry group including mirror images
 //!
 //! # Mathematical Approach
 //!
@@ -22,6 +23,7 @@
 pub mod geometry;
 pub mod rotations;
 pub mod schemes;
+pub mod symmetries;
 
 #[cfg(test)]
 mod tests;
\ No newline at end of file
@@ -14,6 +14,8 @@
 //!
 //! - [`CubeRotation`]: A rotation represented as a permutation of main diagonals
 //! - [`FacePerm`]: A permutation of the six faces, converted from diagonal permutations
+//! - [`RotationAxis`]: A face, edge or corner axis of rotational symmetry
+//! - [`ConjugacyClass`]: The five conjugacy classes of the rotation group
 //!
 //! # Standard Rotations
 //!
@@ -24,6 +26,16 @@
 //!
 //! These generate the full group of 24 rotations through composition.
 //!
+//! # Group Structure
+//!
+//! [`CubeRotation::all`] enumerates the 24 rotations. Each one other than the identity
+//! turns about a [`RotationAxis`] through a pair of opposite faces, edges or corners;
+//! [`CubeRotation::axis_angle`] and [`CubeRotation::from_axis_angle`] convert between
+//! the two descriptions, and [`CubeRotation::conjugacy_class`] groups rotations by the
+//! kind of axis and angle. [`CubeRotation::canonical_expression`] names every rotation
+//! as a product of at most two powers of `X`, `Y` and `Z`, which is also how rotations
+//! are [displayed](std::fmt::Display).
+//!
 //! # Key Algorithm
 //!
 //! The conversion from [`CubeRotation`] to [`FacePerm`] uses the geometric relationships
@@ -36,10 +48,10 @@
 //! The multiplication operator follows cubing notation where `a * b` means "apply rotation a,
 //! then apply rotation b". This is the reverse of standard mathematical function composition.
 
-use std::{array::from_fn, ops::{Index, Mul}};
+use std::{array::from_fn, fmt, ops::{Index, Mul}};
 use crate::core::rubiks::tiles::TilePerm;
 
-use super::geometry::{CubeDiag, Face};
+use super::geometry::{CubeCorner, CubeDiag, Face};
 
 /// A cube rotation represented as a permutation of the four main diagonals.
 ///
@@ -155,6 +167,433 @@ impl CubeRotation {
         }
         CubeRotation(result)
     }
+
+    /// Returns the 3×3 rotation matrix of this rotation in the cube's 3D frame.
+    ///
+    /// The frame is the one described by [`Point3`](super::geometry::Point3): `x` towards
+    /// Right, `y` towards Up and `z` towards Front. The matrix acts on column vectors, so
+    /// `matrix[i][j]` is the `i`-th coordinate of the image of the `j`-th axis.
+    ///
+    /// This is a `const fn`, so rotations can be turned into matrices and tile
+    /// permutations at compile time.
+    ///
+    /// # Algorithm
+    ///
+    /// Take each diagonal as the vector from the centre to its Up end, so URF is
+    /// `(1, 1, 1)`. A rotation carries each of these vectors to `±` the vector of the
+    /// diagonal it maps to, and the signs are fixed up to a common factor by the single
+    /// linear relation `URF - ULF - URB + ULB = 0`, which the images must satisfy too.
+    /// The axes are `x = (URF - ULF)/2`, `y = (URF + ULB)/2` and `z = (URF - URB)/2`, so
+    /// their images are the columns of the matrix; the common sign is the one with
+    /// determinant `+1`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rubiks_cube_representation::{CubeRotation, X};
+    ///
+    /// assert_eq!(CubeRotation::ID.matrix(), [[1, 0, 0], [0, 1, 0], [0, 0, 1]]);
+    /// // x follows R: the Front face moves to Up
+    /// assert_eq!(X.matrix()[1][2], 1);
+    /// ```
+    pub const fn matrix(self) -> [[i32; 3]; 3] {
+        const VECTORS: [[i32; 3]; 4] = [[1, 1, 1], [-1, 1, 1], [1, 1, -1], [-1, 1, -1]];
+        const RELATION: [i32; 4] = [1, -1, -1, 1];
+        let mut images = [[0; 3]; 4];
+        let mut i = 0;
+        while i < 4 {
+            let to = self.0[i] as usize;
+            let sign = RELATION[i] * RELATION[to];
+            images[i] = [sign * VECTORS[to][0], sign * VECTORS[to][1], sign * VECTORS[to][2]];
+            i += 1;
+        }
+        // Columns as (first, second, sign): image of x, y and z is (first + sign·second)/2
+        let axes = [(0, 1, -1), (0, 3, 1), (0, 2, -1)];
+        let mut matrix = [[0; 3]; 3];
+        let mut j = 0;
+        while j < 3 {
+            let (first, second, sign) = axes[j];
+            let mut i = 0;
+            while i < 3 {
+                matrix[i][j] = (images[first][i] + sign * images[second][i]) / 2;
+                i += 1;
+            }
+            j += 1;
+        }
+        let m = matrix;
+        let determinant = m[0][0] * (m[1][1] * m[2][2] - m[1][2] * m[2][1])
+            - m[0][1] * (m[1][0] * m[2][2] - m[1][2] * m[2][0])
+            + m[0][2] * (m[1][0] * m[2][1] - m[1][1] * m[2][0]);
+        if determinant < 0 {
+            let mut i = 0;
+            while i < 3 {
+                matrix[i] = [-matrix[i][0], -matrix[i][1], -matrix[i][2]];
+                i += 1;
+            }
+        }
+        matrix
+    }
+
+    /// The position of this rotation in [`CubeRotation::all`]: the lexicographic rank
+    /// of its permutation of the diagonals.
+    pub(crate) const fn rank(self) -> usize {
+        let mut rank = 0;
+        let mut i = 0;
+        while i < 4 {
+            let mut smaller = 0;
+            let mut j = i + 1;
+            while j < 4 {
+                if (self.0[j] as usize) < (self.0[i] as usize) {
+                    smaller += 1;
+                }
+                j += 1;
+            }
+            rank = rank * (4 - i) + smaller;
+            i += 1;
+        }
+        rank
+    }
+
+    /// The rotation at position `rank` in [`CubeRotation::all`], the inverse of
+    /// [`CubeRotation::rank`].
+    pub(crate) const fn from_rank(mut rank: usize) -> Self {
+        let mut remaining = DIAGS;
+        let mut diags = [URF; 4];
+        let mut radix = 6;
+        let mut i = 0;
+        while i < 4 {
+            // Take the (rank / radix)-th unused diagonal and close the gap it leaves
+            let mut k = rank / radix;
+            rank %= radix;
+            diags[i] = remaining[k];
+            while k + 1 < 4 - i {
+                remaining[k] = remaining[k + 1];
+                k += 1;
+            }
+            if i < 3 {
+                radix /= 3 - i;
+            }
+            i += 1;
+        }
+        CubeRotation(diags)
+    }
+}
+
+/// The four main diagonals in index order.
+const DIAGS: [CubeDiag; 4] = [URF, ULF, URB, ULB];
+
+/// Generators of the canonical expressions, in order of preference.
+const XYZ: [CubeRotation; 9] = [X, X2, X3, Y, Y2, Y3, Z, Z2, Z3];
+
+impl CubeRotation {
+    /// Returns all 24 rotations of the cube.
+    ///
+    /// Every permutation of the four main diagonals is a rotation, so these are the 24
+    /// elements of S₄ in lexicographic order of their diagonal images, starting with
+    /// [`CubeRotation::ID`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rubiks_cube_representation::CubeRotation;
+    ///
+    /// let all = CubeRotation::all();
+    /// assert_eq!(all[0], CubeRotation::ID);
+    /// assert!(all.iter().all(|&a| all.iter().all(|&b| all.contains(&(a * b)))));
+    /// ```
+    pub fn all() -> [CubeRotation; 24] {
+        let mut all = [Self::ID; 24];
+        let mut count = 0;
+        for a in DIAGS {
+            for b in DIAGS.into_iter().filter(|&b| b != a) {
+                for c in DIAGS.into_iter().filter(|&c| c != a && c != b) {
+                    let d = DIAGS.into_iter().find(|&d| d != a && d != b && d != c).expect("four distinct diagonals");
+                    all[count] = CubeRotation([a, b, c, d]);
+                    count += 1;
+                }
+            }
+        }
+        all
+    }
+
+    /// Returns the order of the rotation: the smallest `k ≥ 1` with `rotationᵏ = ID`.
+    ///
+    /// This is `1` for the identity, `4` for quarter turns, `2` for half turns and `3`
+    /// for turns about a corner.
+    pub fn order(self) -> u8 {
+        let mut power = self;
+        let mut order = 1;
+        while power != Self::ID {
+            power = power * self;
+            order += 1;
+        }
+        order
+    }
+
+    /// Returns the conjugacy class of the rotation in the octahedral group.
+    ///
+    /// Two rotations are conjugate exactly when they turn by the same angle about the
+    /// same kind of axis, so the class is read off the axis-angle decomposition.
+    pub fn conjugacy_class(self) -> ConjugacyClass {
+        match self.axis_angle() {
+            None => ConjugacyClass::Identity,
+            Some((RotationAxis::Face(_), 180)) => ConjugacyClass::FaceHalf,
+            Some((RotationAxis::Face(_), _)) => ConjugacyClass::FaceQuarter,
+            Some((RotationAxis::Edge(..), _)) => ConjugacyClass::EdgeHalf,
+            Some((RotationAxis::Corner(_), _)) => ConjugacyClass::CornerThird,
+        }
+    }
+
+    /// Returns `by⁻¹ * self * by`: the rotation `self` performed after reorienting the
+    /// cube by `by⁻¹`.
+    pub fn conjugate(self, by: CubeRotation) -> Self {
+        by.inverse() * self * by
+    }
+
+    /// Decomposes the rotation into an axis and a clockwise angle in degrees.
+    ///
+    /// Returns `None` for the identity. Otherwise the angle is measured clockwise when
+    /// looking at the named face, edge or corner from outside the cube, and the axis is
+    /// chosen to make the decomposition unique:
+    ///
+    /// - Quarter turns are `90°` about the face they turn clockwise, so [`X`] is
+    ///   `(Face(Right), 90)` and [`X3`] is `(Face(Left), 90)`
+    /// - Turns about a corner are `120°` about the corner they turn clockwise
+    /// - Half turns are `180°` about the axis direction whose first non-zero coordinate
+    ///   (in the order x, y, z) is positive, so face half turns are named by Right, Up
+    ///   or Front
+    ///
+    /// # Algorithm
+    ///
+    /// The trace of the [`matrix`](CubeRotation::matrix) gives the angle: `1 + 2·cos θ`.
+    /// For turns other than half turns the antisymmetric part of the matrix is
+    /// `sin θ` times the cross-product matrix of the axis, which gives its direction and
+    /// sense. For half turns `M + I = 2·aaᵀ`, so any non-zero column is along the axis.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rubiks_cube_representation::{Face, X, Y};
+    /// use rubiks_cube_representation::core::cube::rotations::Z2;
+    /// use rubiks_cube_representation::core::cube::rotations::RotationAxis;
+    ///
+    /// assert_eq!(X.axis_angle(), Some((RotationAxis::Face(Face::Right), 90)));
+    /// assert_eq!(Z2.axis_angle(), Some((RotationAxis::Face(Face::Front), 180)));
+    /// assert_eq!((X * Y).axis_angle().unwrap().1, 120);
+    /// ```
+    pub fn axis_angle(self) -> Option<(RotationAxis, u16)> {
+        let m = self.matrix();
+        let antisymmetric = [m[2][1] - m[1][2], m[0][2] - m[2][0], m[1][0] - m[0][1]];
+        match m[0][0] + m[1][1] + m[2][2] {
+            3 => None,
+            // 90° anticlockwise about a: the antisymmetric part is 2a, so turn clockwise about -a
+            1 => Some((RotationAxis::from_direction(antisymmetric.map(|c| -c / 2))?, 90)),
+            // 120° about a corner: the antisymmetric part is ±a
+            0 => Some((RotationAxis::from_direction(antisymmetric.map(|c| -c))?, 120)),
+            _ => {
+                let column = (0..3)
+                    .map(|j| from_fn::<i32, 3, _>(|i| m[i][j] + i32::from(i == j)))
+                    .find(|column| column.iter().any(|&c| c != 0))?;
+                let sign = column.iter().find(|&&c| c != 0).map_or(1, |c| c.signum());
+                Some((RotationAxis::from_direction(column.map(|c| sign * c.signum()))?, 180))
+            }
+        }
+    }
+
+    /// Constructs the rotation turning `degrees` clockwise about `axis`.
+    ///
+    /// Negative angles turn anticlockwise. Returns `None` if the angle is not a
+    /// symmetry of that kind of axis (a multiple of 90° for faces, 180° for edges and
+    /// 120° for corners), or if an [`RotationAxis::Edge`] names two faces that do not
+    /// share an edge.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rubiks_cube_representation::{CubeCorner, CubeRotation, Face, X, Y3};
+    /// use rubiks_cube_representation::core::cube::rotations::RotationAxis;
+    ///
+    /// assert_eq!(CubeRotation::from_axis_angle(RotationAxis::Face(Face::Right), 90), Some(X));
+    /// assert_eq!(CubeRotation::from_axis_angle(RotationAxis::Face(Face::Up), -90), Some(Y3));
+    /// let urf = RotationAxis::Corner(CubeCorner { up: true, left: false, front: true });
+    /// assert_eq!(CubeRotation::from_axis_angle(urf, 90), None);
+    /// ```
+    pub fn from_axis_angle(axis: RotationAxis, degrees: i32) -> Option<Self> {
+        let direction = axis.direction();
+        if direction == [0; 3] {
+            return None;
+        }
+        let d = direction;
+        // Clockwise about d is anticlockwise (right-handed) about -d
+        let cross = [[0, d[2], -d[1]], [-d[2], 0, d[0]], [d[1], -d[0], 0]];
+        let outer: [[i32; 3]; 3] = from_fn(|i| from_fn(|j| d[i] * d[j]));
+        let identity: [[i32; 3]; 3] = from_fn(|i| from_fn(|j| i32::from(i == j)));
+        let combine = |a: i32, b: i32, c: i32, divisor: i32| -> [[i32; 3]; 3] {
+            from_fn(|i| from_fn(|j| (a * identity[i][j] + b * cross[i][j] + c * outer[i][j]) / divisor))
+        };
+        let matrix = match (axis, degrees.rem_euclid(360)) {
+            (_, 0) => identity,
+            // Rodrigues: R = cos θ·I + sin θ·[k]ₓ + (1 - cos θ)·kkᵀ
+            (RotationAxis::Face(_), 90) => combine(0, 1, 1, 1),
+            (RotationAxis::Face(_), 180) => combine(-1, 0, 2, 1),
+            (RotationAxis::Face(_), 270) => combine(0, -1, 1, 1),
+            (RotationAxis::Edge(..), 180) => combine(-2, 0, 2, 2),
+            (RotationAxis::Corner(_), 120) => combine(-1, 1, 1, 2),
+            (RotationAxis::Corner(_), 240) => combine(-1, -1, 1, 2),
+            _ => return None,
+        };
+        Self::all().into_iter().find(|rotation| rotation.matrix() == matrix)
+    }
+
+    /// Returns the shortest expression of the rotation as a product of [`X`], [`Y`],
+    /// [`Z`] and their powers.
+    ///
+    /// Every rotation needs at most two factors. Among expressions of equal length the
+    /// first in the order `X, X2, X3, Y, Y2, Y3, Z, Z2, Z3` is chosen, so rotations
+    /// prefer `x` before `y` before `z`. The identity gives an empty expression.
+    ///
+    /// The [`Display`](fmt::Display) implementation prints this expression in cubing
+    /// notation, e.g. `x y2`.
+    pub fn canonical_expression(self) -> Vec<CubeRotation> {
+        if self == Self::ID {
+            return Vec::new();
+        }
+        if XYZ.contains(&self) {
+            return vec![self];
+        }
+        XYZ.into_iter()
+            .flat_map(|a| XYZ.into_iter().map(move |b| vec![a, b]))
+            .find(|pair| pair[0] * pair[1] == self)
+            .expect("every cube rotation is a product of at most two of x, y and z powers")
+    }
+}
+
+impl fmt::Display for CubeRotation {
+    /// Formats the rotation as its [canonical expression](CubeRotation::canonical_expression)
+    /// in cubing notation, e.g. `x y2` or `z'`. The identity is written `1`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let expression = self.canonical_expression();
+        if expression.is_empty() {
+            return write!(f, "1");
+        }
+        for (i, factor) in expression.iter().enumerate() {
+            if i > 0 {
+                write!(f, " ")?;
+            }
+            let index = XYZ.iter().position(|r| r == factor).expect("factors are powers of x, y or z");
+            write!(f, "{}{}", ["x", "y", "z"][index / 3], ["", "2", "'"][index % 3])?;
+        }
+        Ok(())
+    }
+}
+
+/// An axis of rotational symmetry of the cube.
+///
+/// Each axis passes through the centre of the cube and is named by the face, edge or
+/// corner it leaves through; angles about it are measured clockwise when looking at that
+/// face, edge or corner from outside.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum RotationAxis {
+    /// Four-fold axis through the centres of a face and its opposite face
+    Face(Face),
+    /// Two-fold axis through the midpoints of two opposite edges, named by the two faces
+    /// meeting at one of them
+    Edge(Face, Face),
+    /// Three-fold axis through two opposite corners
+    Corner(CubeCorner),
+}
+
+impl RotationAxis {
+    /// Returns the integer direction of the axis, pointing out through the named face,
+    /// edge or corner.
+    ///
+    /// This is the face normal, the sum of two face normals, or the corner's signs. An
+    /// [`RotationAxis::Edge`] naming two faces that do not meet gives a direction that is
+    /// not an edge direction, such as `[0, 0, 0]` for opposite faces.
+    pub fn direction(self) -> [i32; 3] {
+        match self {
+            RotationAxis::Face(face) => face.normal(),
+            RotationAxis::Edge(a, b) => {
+                let (a, b) = (a.normal(), b.normal());
+                from_fn(|i| a[i] + b[i])
+            }
+            RotationAxis::Corner(corner) => corner.signs(),
+        }
+    }
+
+    /// Returns the number of distinct rotations about the axis, including the identity:
+    /// `4` for faces, `2` for edges and `3` for corners.
+    pub fn fold(self) -> u8 {
+        match self {
+            RotationAxis::Face(_) => 4,
+            RotationAxis::Edge(..) => 2,
+            RotationAxis::Corner(_) => 3,
+        }
+    }
+
+    /// Returns the axis leaving the cube in `direction`, if it is a face, edge or corner
+    /// direction. Edges name their two faces in [`FACES`](super::geometry::FACES) order.
+    fn from_direction(direction: [i32; 3]) -> Option<Self> {
+        match direction.iter().filter(|&&c| c != 0).count() {
+            1 => Face::from_normal(direction).map(RotationAxis::Face),
+            2 => {
+                let mut faces = (0..3).filter(|&i| direction[i] != 0).map(|i| {
+                    let mut normal = [0; 3];
+                    normal[i] = direction[i];
+                    Face::from_normal(normal)
+                });
+                let (a, b) = (faces.next()??, faces.next()??);
+                Some(if a < b { RotationAxis::Edge(a, b) } else { RotationAxis::Edge(b, a) })
+            }
+            3 => Some(RotationAxis::Corner(CubeCorner {
+                up: direction[1] > 0,
+                left: direction[0] < 0,
+                front: direction[2] > 0,
+            })),
+            _ => None,
+        }
+    }
+}
+
+/// The five conjugacy classes of the cube's rotation group.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum ConjugacyClass {
+    /// The identity
+    Identity,
+    /// Quarter turns about a face axis (6 rotations)
+    FaceQuarter,
+    /// Half turns about a face axis (3 rotations)
+    FaceHalf,
+    /// Half turns about an edge axis (6 rotations)
+    EdgeHalf,
+    /// Third turns about a corner axis (8 rotations)
+    CornerThird,
+}
+
+impl ConjugacyClass {
+    /// All five classes.
+    pub const ALL: [ConjugacyClass; 5] = [
+        ConjugacyClass::Identity,
+        ConjugacyClass::FaceQuarter,
+        ConjugacyClass::FaceHalf,
+        ConjugacyClass::EdgeHalf,
+        ConjugacyClass::CornerThird,
+    ];
+
+    /// Returns the rotations in the class, in the order of [`CubeRotation::all`].
+    pub fn members(self) -> Vec<CubeRotation> {
+        CubeRotation::all().into_iter().filter(|r| r.conjugacy_class() == self).collect()
+    }
+
+    /// Returns the number of rotations in the class.
+    pub fn size(self) -> usize {
+        match self {
+            ConjugacyClass::Identity => 1,
+            ConjugacyClass::FaceQuarter | ConjugacyClass::EdgeHalf => 6,
+            ConjugacyClass::FaceHalf => 3,
+            ConjugacyClass::CornerThird => 8,
+        }
+    }
 }
 
 /// A permutation of the six cube faces.
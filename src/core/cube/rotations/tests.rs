@@ -185,4 +185,113 @@ fn test_identity_face_perm() {
     assert_eq!(id_face_perm[Right], Right);
     assert_eq!(id_face_perm[Front], Front);
     assert_eq!(id_face_perm[Back], Back);
-}
\ No newline at end of file
+}
+#[test]
+fn test_all_rotations() {
+    let all = CubeRotation::all();
+    for (i, a) in all.iter().enumerate() {
+        assert!(all[..i].iter().all(|b| b != a), "duplicate rotation {a:?}");
+    }
+    for r in [X, X2, X3, Y, Y2, Y3, Z, Z2, Z3] {
+        assert!(all.contains(&r));
+    }
+    // Orders: 1 identity, 9 of order 2, 8 of order 3, 6 of order 4
+    let count = |order| all.iter().filter(|r| r.order() == order).count();
+    assert_eq!([count(1), count(2), count(3), count(4)], [1, 9, 8, 6]);
+}
+
+#[test]
+fn test_matrix_and_rank() {
+    for (rank, r) in CubeRotation::all().into_iter().enumerate() {
+        // Columns are the normals of the faces Right, Up and Front are carried to
+        let perm = FacePerm::from(r);
+        let columns = [Face::Right, Face::Up, Face::Front].map(|face| perm[face].normal());
+        assert_eq!(r.matrix(), from_fn(|i| from_fn(|j| columns[j][i])), "{r:?}");
+        assert_eq!((r.rank(), CubeRotation::from_rank(rank)), (rank, r));
+    }
+    const X_MATRIX: [[i32; 3]; 3] = X.matrix();
+    assert_eq!(X_MATRIX, [[1, 0, 0], [0, 0, 1], [0, -1, 0]]);
+}
+
+#[test]
+fn test_conjugacy_classes() {
+    let mut total = 0;
+    for class in ConjugacyClass::ALL {
+        let members = class.members();
+        assert_eq!(members.len(), class.size());
+        total += members.len();
+        // Classes are closed under conjugation and every member has the same order
+        for &r in &members {
+            assert_eq!(r.order(), members[0].order());
+            for g in CubeRotation::all() {
+                assert_eq!(r.conjugate(g).conjugacy_class(), class);
+            }
+        }
+    }
+    assert_eq!(total, 24);
+    assert_eq!(X.conjugacy_class(), ConjugacyClass::FaceQuarter);
+    assert_eq!((X * Y).conjugacy_class(), ConjugacyClass::CornerThird);
+    assert_eq!((X * Y2).conjugacy_class(), ConjugacyClass::EdgeHalf);
+    // Conjugating X by Y gives a quarter turn about the Front or Back axis
+    assert!([Z, Z3].contains(&X.conjugate(Y)));
+}
+
+#[test]
+fn test_axis_angle_round_trip() {
+    use Face::*;
+    assert_eq!(CubeRotation::ID.axis_angle(), None);
+    assert_eq!(X.axis_angle(), Some((RotationAxis::Face(Right), 90)));
+    assert_eq!(X3.axis_angle(), Some((RotationAxis::Face(Left), 90)));
+    assert_eq!(Y.axis_angle(), Some((RotationAxis::Face(Up), 90)));
+    assert_eq!(Z.axis_angle(), Some((RotationAxis::Face(Front), 90)));
+    assert_eq!(X2.axis_angle(), Some((RotationAxis::Face(Right), 180)));
+    assert_eq!(Y2.axis_angle(), Some((RotationAxis::Face(Up), 180)));
+
+    for r in CubeRotation::all().into_iter().skip(1) {
+        let (axis, degrees) = r.axis_angle().unwrap();
+        assert_eq!(degrees % (360 / u16::from(axis.fold())), 0, "{r:?}");
+        assert_eq!(CubeRotation::from_axis_angle(axis, degrees.into()), Some(r));
+        // Turning the other way about the same axis gives the inverse
+        assert_eq!(CubeRotation::from_axis_angle(axis, -i32::from(degrees)), Some(r.inverse()));
+        // The axis direction is fixed by the rotation
+        let m = r.matrix();
+        let d = axis.direction();
+        assert_eq!(from_fn::<i32, 3, _>(|i| (0..3).map(|j| m[i][j] * d[j]).sum()), d);
+    }
+}
+
+#[test]
+fn test_from_axis_angle() {
+    use Face::*;
+    let urf = CubeCorner { up: true, left: false, front: true };
+    // Clockwise about URF sends U to R to F
+    let r = CubeRotation::from_axis_angle(RotationAxis::Corner(urf), 120).unwrap();
+    let perm = FacePerm::from(r);
+    assert_eq!((perm[Up], perm[Right], perm[Front]), (Right, Front, Up));
+    assert_eq!(CubeRotation::from_axis_angle(RotationAxis::Face(Down), 90), Some(Y3));
+    assert_eq!(CubeRotation::from_axis_angle(RotationAxis::Face(Back), 450), Some(Z3));
+    assert_eq!(CubeRotation::from_axis_angle(RotationAxis::Edge(Up, Front), 360), Some(CubeRotation::ID));
+
+    let uf = CubeRotation::from_axis_angle(RotationAxis::Edge(Front, Up), 180).unwrap();
+    assert_eq!(FacePerm::from(uf)[Up], Front);
+    assert_eq!(uf.axis_angle(), Some((RotationAxis::Edge(Up, Front), 180)));
+
+    assert_eq!(CubeRotation::from_axis_angle(RotationAxis::Edge(Up, Down), 180), None);
+    assert_eq!(CubeRotation::from_axis_angle(RotationAxis::Edge(Up, Front), 90), None);
+    assert_eq!(CubeRotation::from_axis_angle(RotationAxis::Face(Up), 45), None);
+}
+
+#[test]
+fn test_canonical_expression() {
+    assert!(CubeRotation::ID.canonical_expression().is_empty());
+    assert_eq!(CubeRotation::ID.to_string(), "1");
+    assert_eq!(X3.canonical_expression(), [X3]);
+    assert_eq!(X3.to_string(), "x'");
+    assert_eq!((X * Y).to_string(), "x y");
+    assert_eq!((Z2 * Y).to_string(), "x2 y'");
+    for r in CubeRotation::all() {
+        let expression = r.canonical_expression();
+        assert!(expression.len() <= 2);
+        assert_eq!(expression.into_iter().fold(CubeRotation::ID, |acc, f| acc * f), r);
+    }
+}
@@ -24,6 +24,7 @@
 
 use std::collections::HashMap;
 use crate::core::Colour;
+use crate::error::Error;
 use super::geometry::{Face, FACES};
 use super::rotations::{CubeRotation, FacePerm};
 
@@ -48,8 +49,46 @@ pub struct ColourPerm {
     pub back: Colour,
 }
 
+impl ColourPerm {
+    /// Creates a color permutation from a face-to-color mapping, checking that every
+    /// face has a color and no color is used twice.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::MissingFace`] for the first face without a color, or
+    /// [`Error::DuplicateColour`] as for [`ColourPerm::check`].
+    pub fn try_from_map(value: &HashMap<Face,Colour>) -> Result<Self, Error> {
+        if let Some(&face) = FACES.iter().find(|face| !value.contains_key(face)) {
+            return Err(Error::MissingFace(face));
+        }
+        let perm = ColourPerm::from(value);
+        perm.check()?;
+        Ok(perm)
+    }
+
+    /// Checks that the six faces show six different colors.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::DuplicateColour`] with the first color, in face order, that
+    /// appears on an earlier face too.
+    pub fn check(&self) -> Result<(), Error> {
+        for (i, &face) in FACES.iter().enumerate() {
+            let colour = self.from_face(face);
+            if FACES[..i].iter().any(|&earlier| self.from_face(earlier) == colour) {
+                return Err(Error::DuplicateColour(colour));
+            }
+        }
+        Ok(())
+    }
+}
+
 impl From<&HashMap<Face,Colour>> for ColourPerm {
     /// Creates a color permutation from a face-to-color mapping.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a face is missing from the map; see [`ColourPerm::try_from_map`].
     fn from(value: &HashMap<Face,Colour>) -> Self {
         use Face::*;
         Self {
@@ -145,26 +184,29 @@ pub trait ColourScheme {
         let inv_rotation = rotation.inverse();
         let face_perm: FacePerm = inv_rotation.into();
         use Face::*;
-        ColourPerm {
+        let rotated = ColourPerm {
             up: self.from_face(face_perm[Up]),
             down: self.from_face(face_perm[Down]),
             left: self.from_face(face_perm[Left]),
             right: self.from_face(face_perm[Right]),
             front: self.from_face(face_perm[Front]),
             back: self.from_face(face_perm[Back])
-        }
+        };
+        #[cfg(feature = "log")]
+        log::trace!(rotation:%, up:? = rotated.up, front:? = rotated.front; "rotated colour scheme");
+        rotated
     }
 
     /// Finds which face has the specified color in this scheme.
     ///
-    /// Returns an error if the color is not present in the scheme.
-    fn get_face(&self, colour: Colour) -> Result<Face,String> {
+    /// Returns [`Error::UnknownColour`] if the color is not present in the scheme.
+    fn get_face(&self, colour: Colour) -> Result<Face,Error> {
         for face in FACES {
             if self.from_face(face) == colour {
                 return Ok(face);
             }
         }
-        Err(format!("Colour {colour:?} not in scheme."))
+        Err(Error::UnknownColour(colour))
     }
 }
 
@@ -0,0 +1,165 @@
+//! Canonical representatives of symmetry classes.
+//!
+//! Two positions that differ only by a whole-cube symmetry are solved by "the same"
+//! algorithm, seen through that symmetry. Pattern databases and algorithm searches can
+//! therefore store one position per *symmetry class*, shrinking their tables by a
+//! factor of up to 48 (or 96 when a permutation is also identified with its inverse).
+//!
+//! For a group `S` of symmetries, the class of a tile permutation `P` is the set of its
+//! conjugates `s⁻¹ * P * s` for `s ∈ S`, and the class of a state is the set of its
+//! [transformed](RubiksState::transformed_in) images. The *canonical representative*
+//! is the least member of the class under the derived [`Ord`], so two positions are
+//! symmetric exactly when their representatives are equal.
+//!
+//! # Core Types
+//!
+//! - [`SymmetryGroup`]: Whether to use the 24 rotations or all 48 symmetries
+//! - [`Canonical<T>`]: A canonical representative together with the symmetry (and
+//!   possibly inversion) that produces it
+//!
+//! # Examples
+//!
+//! ```
+//! use rubiks_cube_representation::core::cube::symmetries::canonical::SymmetryGroup;
+//! use rubiks_cube_representation::core::rubiks::moves::BasicMove;
+//! use rubiks_cube_representation::core::rubiks::tiles::TilePerm;
+//!
+//! // Every single quarter turn is in the same class as every other under rotations
+//! let r = TilePerm::<3>::from(&BasicMove::<3>::R).canonical(SymmetryGroup::Rotations);
+//! let f = TilePerm::<3>::from(&BasicMove::<3>::F).canonical(SymmetryGroup::Rotations);
+//! assert_eq!(r.representative, f.representative);
+//!
+//! // but R and R' are only identified once mirrors are allowed
+//! let r3 = TilePerm::<3>::from(&BasicMove::<3>::R3);
+//! assert_ne!(r3.canonical(SymmetryGroup::Rotations).representative, r.representative);
+//! assert_eq!(
+//!     r3.canonical(SymmetryGroup::Full).representative,
+//!     TilePerm::<3>::from(&BasicMove::<3>::R).canonical(SymmetryGroup::Full).representative,
+//! );
+//! ```
+
+use crate::core::cube::schemes::ColourScheme;
+use crate::core::rubiks::tiles::TilePerm;
+use crate::core::rubiks::RubiksState;
+
+use super::{CubeSymmetry, Symmetric};
+
+#[cfg(test)]
+mod tests;
+
+/// A group of whole-cube symmetries to canonicalise under.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum SymmetryGroup {
+    /// The 24 rotations a physical cube can be turned through
+    Rotations,
+    /// All 48 symmetries, including mirror images
+    Full,
+}
+
+impl SymmetryGroup {
+    /// Lists the symmetries in the group, starting with the identity.
+    pub fn elements(self) -> Vec<CubeSymmetry> {
+        CubeSymmetry::all()
+            .into_iter()
+            .filter(|symmetry| self == SymmetryGroup::Full || !symmetry.is_reflection())
+            .collect()
+    }
+
+    /// Returns the number of symmetries in the group: 24 or 48.
+    pub fn order(self) -> usize {
+        match self {
+            SymmetryGroup::Rotations => 24,
+            SymmetryGroup::Full => 48,
+        }
+    }
+}
+
+/// The canonical representative of a symmetry class.
+///
+/// The representative is obtained from the original position by inverting it if
+/// `inverted` is set, and then transforming by `symmetry`. When several symmetries
+/// give the representative, the first in [`SymmetryGroup::elements`] is reported, so a
+/// position that is already canonical reports the identity.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Canonical<T> {
+    /// The least member of the symmetry class
+    pub representative: T,
+    /// The symmetry carrying the (possibly inverted) original to the representative
+    pub symmetry: CubeSymmetry,
+    /// Whether the original was inverted before transforming
+    pub inverted: bool,
+}
+
+/// Returns the least of `candidates`, keeping the first of any equal ones.
+fn least<T: Ord>(candidates: impl IntoIterator<Item = Canonical<T>>) -> Canonical<T> {
+    candidates
+        .into_iter()
+        .reduce(|best, next| if next.representative < best.representative { next } else { best })
+        .expect("symmetry groups contain the identity")
+}
+
+impl<const N: usize> TilePerm<N> {
+    /// Lists the conjugates `s⁻¹ * self * s` for every symmetry `s` in `group`.
+    ///
+    /// The list may contain repeats when the permutation is itself symmetric.
+    pub fn conjugates(&self, group: SymmetryGroup) -> Vec<(CubeSymmetry, TilePerm<N>)> {
+        group.elements().into_iter().map(|symmetry| (symmetry, self.transformed(symmetry))).collect()
+    }
+
+    /// Returns the symmetries in `group` that commute with this permutation.
+    ///
+    /// These form a subgroup, and the symmetry class has `group.order()` divided by
+    /// its size members.
+    pub fn self_symmetries(&self, group: SymmetryGroup) -> Vec<CubeSymmetry> {
+        self.conjugates(group).into_iter().filter(|(_, conjugate)| conjugate == self).map(|(s, _)| s).collect()
+    }
+
+    /// Returns the canonical representative of this permutation's class under `group`.
+    pub fn canonical(&self, group: SymmetryGroup) -> Canonical<TilePerm<N>> {
+        least(self.conjugates(group).into_iter().map(|(symmetry, representative)| Canonical {
+            representative,
+            symmetry,
+            inverted: false,
+        }))
+    }
+
+    /// Returns the canonical representative of this permutation's class under `group`,
+    /// also identifying the permutation with its inverse.
+    ///
+    /// An algorithm for `P` reversed is an algorithm for `P⁻¹`, so searches that can
+    /// run in either direction may use this coarser class.
+    pub fn canonical_with_inverse(&self, group: SymmetryGroup) -> Canonical<TilePerm<N>> {
+        let forward = self.canonical(group);
+        let backward = Canonical { inverted: true, ..self.inverse().canonical(group) };
+        least([forward, backward])
+    }
+}
+
+impl<const N: usize> RubiksState<N> {
+    /// Lists the images of this state under every symmetry in `group`, using `scheme`
+    /// to recolour. See [`RubiksState::transformed_in`].
+    pub fn conjugates_in<Scheme: ColourScheme + Copy>(
+        &self,
+        group: SymmetryGroup,
+        scheme: Scheme,
+    ) -> Vec<(CubeSymmetry, RubiksState<N>)> {
+        group.elements().into_iter().map(|symmetry| (symmetry, self.transformed_in(symmetry, scheme))).collect()
+    }
+
+    /// Returns the canonical representative of this state's class under `group`.
+    ///
+    /// States have no inverse without a reference position, so `inverted` is always
+    /// `false`; canonicalise the [`TilePerm`] instead when inverses should be
+    /// identified.
+    pub fn canonical_in<Scheme: ColourScheme + Copy>(
+        &self,
+        group: SymmetryGroup,
+        scheme: Scheme,
+    ) -> Canonical<RubiksState<N>> {
+        least(self.conjugates_in(group, scheme).into_iter().map(|(symmetry, representative)| Canonical {
+            representative,
+            symmetry,
+            inverted: false,
+        }))
+    }
+}
@@ -0,0 +1,86 @@
+use super::*;
+use crate::core::cube::schemes::Western;
+use crate::core::rubiks::moves::BasicMove::{self, *};
+
+fn perm(moves: &[BasicMove<3>]) -> TilePerm<3> {
+    moves.iter().fold(TilePerm::ID, |acc, mv| acc * TilePerm::from(mv))
+}
+
+fn state(moves: &[BasicMove<3>]) -> RubiksState<3> {
+    moves.iter().fold(RubiksState::solved_in(Western), |state, mv| &state * mv)
+}
+
+#[test]
+fn test_groups() {
+    for group in [SymmetryGroup::Rotations, SymmetryGroup::Full] {
+        let elements = group.elements();
+        assert_eq!(elements.len(), group.order());
+        assert_eq!(elements[0], CubeSymmetry::ID);
+    }
+    assert!(SymmetryGroup::Rotations.elements().iter().all(|s| !s.is_reflection()));
+}
+
+#[test]
+fn test_canonical_perms() {
+    let sune = perm(&[R, U, R3, U, R, U2, R3]);
+    for group in [SymmetryGroup::Rotations, SymmetryGroup::Full] {
+        let canonical = sune.canonical(group);
+        assert!(!canonical.inverted);
+        assert_eq!(sune.transformed(canonical.symmetry), canonical.representative);
+        // Every conjugate has the same representative
+        for (_, conjugate) in sune.conjugates(group) {
+            assert_eq!(conjugate.canonical(group).representative, canonical.representative);
+        }
+        assert!(sune.conjugates(group).iter().all(|(_, c)| canonical.representative <= *c));
+    }
+    // Sune and the left-handed Sune only meet under the full group
+    let left = perm(&[L3, U3, L, U3, L3, U2, L]);
+    assert_ne!(left.canonical(SymmetryGroup::Rotations).representative, sune.canonical(SymmetryGroup::Rotations).representative);
+    assert_eq!(left.canonical(SymmetryGroup::Full).representative, sune.canonical(SymmetryGroup::Full).representative);
+}
+
+#[test]
+fn test_canonical_with_inverse() {
+    // Anti-Sune is the inverse of Sune, and a rotation of neither
+    let sune = perm(&[R, U, R3, U, R, U2, R3]);
+    let anti = perm(&[R, U2, R3, U3, R, U3, R3]);
+    assert_eq!(anti, sune.inverse());
+    let group = SymmetryGroup::Rotations;
+    assert_ne!(sune.canonical(group).representative, anti.canonical(group).representative);
+    let a = sune.canonical_with_inverse(group);
+    let b = anti.canonical_with_inverse(group);
+    assert_eq!(a.representative, b.representative);
+    assert_ne!(a.inverted, b.inverted);
+    let source = if b.inverted { anti.inverse() } else { anti.clone() };
+    assert_eq!(source.transformed(b.symmetry), b.representative);
+}
+
+#[test]
+fn test_self_symmetries() {
+    // U2 D2 commutes with every rotation that fixes or swaps the U and D faces
+    let ud = perm(&[U2, D2]);
+    assert_eq!(ud.self_symmetries(SymmetryGroup::Rotations).len(), 8);
+    assert_eq!(ud.self_symmetries(SymmetryGroup::Full).len(), 16);
+    assert_eq!(TilePerm::<3>::ID.self_symmetries(SymmetryGroup::Full).len(), 48);
+    // A single quarter turn is fixed by the four rotations about its axis
+    assert_eq!(perm(&[R]).self_symmetries(SymmetryGroup::Full).len(), 4);
+    assert_eq!(perm(&[R]).canonical(SymmetryGroup::Rotations).representative, perm(&[B]).canonical(SymmetryGroup::Rotations).representative);
+}
+
+#[test]
+fn test_canonical_states() {
+    let group = SymmetryGroup::Full;
+    let sexy = state(&[R, U, R3, U3]);
+    let canonical = sexy.canonical_in(group, Western);
+    assert_eq!(sexy.transformed_in(canonical.symmetry, Western), canonical.representative);
+    // The mirrored and rotated algorithms reach symmetric states
+    assert_eq!(state(&[L3, U3, L, U]).canonical_in(group, Western).representative, canonical.representative);
+    assert_eq!(state(&[F, U, F3, U3]).canonical_in(group, Western).representative, canonical.representative);
+    assert_ne!(state(&[R, U3, R3, U]).canonical_in(SymmetryGroup::Rotations, Western).representative,
+        sexy.canonical_in(SymmetryGroup::Rotations, Western).representative);
+
+    let solved = RubiksState::<3>::solved_in(Western);
+    let canonical = solved.canonical_in(group, Western);
+    assert_eq!(canonical.representative, solved);
+    assert_eq!(canonical.symmetry, CubeSymmetry::ID);
+}
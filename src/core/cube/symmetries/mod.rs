@@ -0,0 +1,309 @@
+//! The full symmetry group of the cube, including mirror images.
+//!
+//! The 24 rotations of [`CubeRotation`] are the symmetries a physical cube can be turned
+//! through. Allowing reflections doubles this to the 48-element group Oₕ: every symmetry
+//! is a rotation, optionally followed by the *central inversion* that sends each point
+//! `p` to `-p`. The inversion commutes with every rotation, so Oₕ = O × {1, inversion}.
+//!
+//! # Core Types
+//!
+//! - [`CubeSymmetry`]: A rotation together with a flag saying whether it is reflected
+//! - [`MirrorPlane`]: The M, E and S planes used to mirror algorithms
+//! - [`Symmetric`]: Things that can be carried through a symmetry, such as moves,
+//!   move sequences and tile permutations
+//!
+//! # Mirroring Moves
+//!
+//! Conjugating a move by a symmetry `s` gives the move that does the same thing "as seen
+//! through `s`": it turns the face `s` sends the original face to, and a reflection
+//! reverses the direction of every turn. Mirroring across the M plane therefore swaps
+//! `R` and `L'`, while `U` becomes `U'`:
+//!
+//! ```
+//! use rubiks_cube_representation::core::cube::symmetries::{MirrorPlane, Symmetric};
+//! use rubiks_cube_representation::core::rubiks::moves::BasicMove::{self, *};
+//! use rubiks_cube_representation::core::rubiks::tiles::TilePerm;
+//!
+//! // The left-handed Sune
+//! let sune: Vec<BasicMove<3>> = vec![R, U, R3, U, R, U2, R3];
+//! let left = sune.mirrored(MirrorPlane::M);
+//! assert_eq!(format!("{left:?}"), "[L3, U3, L, U3, L3, U2, L]");
+//!
+//! // Both are products of conjugate permutations
+//! let perm = |moves: &[BasicMove<3>]| moves.iter().fold(TilePerm::<3>::ID, |acc, m| acc * TilePerm::from(m));
+//! assert_eq!(perm(&left).order(), perm(&sune).order());
+//! ```
+//!
+//! # Mirroring States
+//!
+//! A symmetry acts on a [`RubiksState<N>`] by moving the stickers and then recolouring
+//! them, so that the state reached by a move sequence is carried to the state reached
+//! by the transformed sequence. Recolouring needs to know which colour belongs to which
+//! face, so [`RubiksState::transformed_in`] takes a [`ColourScheme`].
+//!
+//! The [`canonical`] submodule picks one representative from each symmetry class of
+//! states and permutations.
+
+pub mod canonical;
+
+use std::{array::from_fn, ops::Mul};
+
+use crate::core::rubiks::moves::{BasicMove, BasicMoveInternal, MiddleMove, MiddleMoveInternal, RangeMove,
+    RangeMoveInternal, SliceMove, SliceMoveInternal, WideMove, WideMoveInternal};
+use crate::core::rubiks::tiles::{TilePerm, TilePos};
+use crate::core::rubiks::RubiksState;
+use crate::core::Angle;
+
+use super::geometry::Face;
+use super::rotations::{CubeRotation, FacePerm, X2, Y2, Z2};
+use super::schemes::ColourScheme;
+
+#[cfg(test)]
+mod tests;
+
+/// A symmetry of the cube: a rotation, optionally followed by the central inversion.
+///
+/// Reflections are exactly the symmetries with `reflected` set. The reflection in the
+/// M plane, for example, is the half turn [`X2`] followed by the inversion.
+///
+/// # Composition
+///
+/// As for [`CubeRotation`], `a * b` means "apply `a`, then apply `b`".
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CubeSymmetry {
+    /// The rotation part of the symmetry
+    pub rotation: CubeRotation,
+    /// Whether the rotation is followed by the central inversion `p ↦ -p`
+    pub reflected: bool,
+}
+
+/// The three planes through the centre of the cube parallel to a pair of faces.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum MirrorPlane {
+    /// The plane between L and R: mirroring swaps left and right
+    M,
+    /// The plane between U and D: mirroring swaps up and down
+    E,
+    /// The plane between F and B: mirroring swaps front and back
+    S,
+}
+
+impl CubeSymmetry {
+    /// The identity symmetry.
+    pub const ID: Self = CubeSymmetry { rotation: CubeRotation::ID, reflected: false };
+
+    /// The central inversion, sending every point `p` to `-p` and every face to its
+    /// opposite.
+    pub const INVERSION: Self = CubeSymmetry { rotation: CubeRotation::ID, reflected: true };
+
+    /// Returns the reflection in a mirror plane.
+    ///
+    /// The reflection in a plane is the half turn about the plane's normal followed by
+    /// the central inversion.
+    pub fn mirror(plane: MirrorPlane) -> Self {
+        let rotation = match plane {
+            MirrorPlane::M => X2,
+            MirrorPlane::E => Y2,
+            MirrorPlane::S => Z2,
+        };
+        CubeSymmetry { rotation, reflected: true }
+    }
+
+    /// Returns all 48 symmetries: the 24 rotations of [`CubeRotation::all`], followed by
+    /// each of them composed with the inversion.
+    pub fn all() -> [CubeSymmetry; 48] {
+        let rotations = CubeRotation::all();
+        from_fn(|i| CubeSymmetry { rotation: rotations[i % 24], reflected: i >= 24 })
+    }
+
+    /// Returns whether the symmetry reverses handedness.
+    pub fn is_reflection(self) -> bool {
+        self.reflected
+    }
+
+    /// Computes the inverse symmetry.
+    pub fn inverse(self) -> Self {
+        CubeSymmetry { rotation: self.rotation.inverse(), reflected: self.reflected }
+    }
+
+    /// Returns the 3×3 matrix of this symmetry in the cube's 3D frame.
+    ///
+    /// This is the [rotation matrix](CubeRotation::matrix), negated for reflections.
+    pub fn matrix(self) -> [[i32; 3]; 3] {
+        let sign = if self.reflected { -1 } else { 1 };
+        self.rotation.matrix().map(|row| row.map(|c| sign * c))
+    }
+}
+
+impl From<CubeRotation> for CubeSymmetry {
+    fn from(rotation: CubeRotation) -> Self {
+        CubeSymmetry { rotation, reflected: false }
+    }
+}
+
+impl Mul for CubeSymmetry {
+    type Output = CubeSymmetry;
+
+    /// Composes two symmetries: `a * b` applies `a`, then `b`.
+    ///
+    /// The inversion commutes with every rotation, so the rotation parts compose and
+    /// the reflection flags cancel in pairs.
+    fn mul(self, rhs: Self) -> Self::Output {
+        CubeSymmetry { rotation: self.rotation * rhs.rotation, reflected: self.reflected != rhs.reflected }
+    }
+}
+
+impl From<CubeSymmetry> for FacePerm {
+    /// Returns where each face is sent: the rotation's face permutation, followed by
+    /// the opposite face for reflections.
+    fn from(value: CubeSymmetry) -> Self {
+        let FacePerm(faces) = FacePerm::from(value.rotation);
+        FacePerm(faces.map(|face| if value.reflected { face.opposite() } else { face }))
+    }
+}
+
+impl<const N: usize> From<&CubeSymmetry> for TilePerm<N> {
+    /// Converts a symmetry to the permutation of tile positions it induces.
+    ///
+    /// # Algorithm
+    ///
+    /// The rotation part uses the existing [`CubeRotation`] conversion. The inversion
+    /// sends the centre of each tile to its negation, which is the centre of a tile on
+    /// the opposite face, found with [`TilePos::at_point`].
+    fn from(value: &CubeSymmetry) -> Self {
+        let rotation = TilePerm::from(&value.rotation);
+        if !value.reflected {
+            return rotation;
+        }
+        let inversion = TilePerm::from_fn(|tile| {
+            let centre = tile.centre::<N>().map(|c| -c);
+            TilePos::at_point::<N>(centre, 1e-9).expect("the inversion maps tile centres to tile centres")
+        });
+        rotation * inversion
+    }
+}
+
+impl<const N: usize> From<CubeSymmetry> for TilePerm<N> {
+    fn from(value: CubeSymmetry) -> Self {
+        Self::from(&value)
+    }
+}
+
+/// Objects that can be carried through a symmetry of the cube.
+///
+/// For an operation `op` and symmetry `s`, the transformed operation has tile
+/// permutation `s⁻¹ * op * s`: reorient the cube by `s⁻¹`, perform `op`, and reorient
+/// back. Sequences transform element by element.
+pub trait Symmetric: Sized {
+    /// Returns the image of `self` under conjugation by `symmetry`.
+    fn transformed(&self, symmetry: CubeSymmetry) -> Self;
+
+    /// Returns the mirror image of `self` across a plane.
+    fn mirrored(&self, plane: MirrorPlane) -> Self {
+        self.transformed(CubeSymmetry::mirror(plane))
+    }
+}
+
+/// Returns the face and turn amount of a face turn seen through `symmetry`.
+fn transform_turn(face: Face, amount: Angle, symmetry: CubeSymmetry) -> (Face, Angle) {
+    let face = FacePerm::from(symmetry)[face];
+    let amount = if symmetry.reflected { Angle::Zero - amount } else { amount };
+    (face, amount)
+}
+
+impl<const N: usize> Symmetric for BasicMove<N> {
+    fn transformed(&self, symmetry: CubeSymmetry) -> Self {
+        let BasicMoveInternal { face, amount } = BasicMoveInternal::from(*self);
+        let (face, amount) = transform_turn(face, amount, symmetry);
+        BasicMove::from_parts(face, amount)
+    }
+}
+
+impl<const N: usize> Symmetric for WideMove<N> {
+    fn transformed(&self, symmetry: CubeSymmetry) -> Self {
+        let WideMoveInternal { face, amount, depth } = WideMoveInternal::from(*self);
+        let (face, amount) = transform_turn(face, amount, symmetry);
+        WideMove::from_parts(face, amount, depth)
+    }
+}
+
+impl<const N: usize> Symmetric for SliceMove<N> {
+    fn transformed(&self, symmetry: CubeSymmetry) -> Self {
+        let SliceMoveInternal { face, amount, layer } = SliceMoveInternal::from(*self);
+        let (face, amount) = transform_turn(face, amount, symmetry);
+        SliceMove::from_parts(face, amount, layer)
+    }
+}
+
+impl<const N: usize> Symmetric for RangeMove<N> {
+    fn transformed(&self, symmetry: CubeSymmetry) -> Self {
+        let RangeMoveInternal { face, amount, start_layer, end_layer } = RangeMoveInternal::from(*self);
+        let (face, amount) = transform_turn(face, amount, symmetry);
+        RangeMove::from_parts(face, amount, start_layer, end_layer)
+    }
+}
+
+impl<const N: usize> Symmetric for MiddleMove<N> {
+    fn transformed(&self, symmetry: CubeSymmetry) -> Self {
+        let MiddleMoveInternal { face, amount } = MiddleMoveInternal::from(*self);
+        let (face, amount) = transform_turn(face, amount, symmetry);
+        MiddleMove::from_parts(face, amount)
+    }
+}
+
+impl Symmetric for CubeRotation {
+    /// Conjugates the rotation. The inversion is central, so only the rotation part of
+    /// the symmetry matters.
+    fn transformed(&self, symmetry: CubeSymmetry) -> Self {
+        self.conjugate(symmetry.rotation)
+    }
+}
+
+impl Symmetric for CubeSymmetry {
+    fn transformed(&self, symmetry: CubeSymmetry) -> Self {
+        symmetry.inverse() * *self * symmetry
+    }
+}
+
+impl<const N: usize> Symmetric for TilePerm<N> {
+    fn transformed(&self, symmetry: CubeSymmetry) -> Self {
+        let s = TilePerm::from(&symmetry);
+        &(&s.inverse() * self) * &s
+    }
+}
+
+impl<T: Symmetric> Symmetric for Vec<T> {
+    fn transformed(&self, symmetry: CubeSymmetry) -> Self {
+        self.iter().map(|item| item.transformed(symmetry)).collect()
+    }
+}
+
+impl<const N: usize> RubiksState<N> {
+    /// Returns the image of this state under a symmetry, using `scheme` to recolour.
+    ///
+    /// If this state is the solved state in `scheme` after a move sequence, the result
+    /// is the solved state after the [transformed](Symmetric::transformed) sequence.
+    ///
+    /// # Algorithm
+    ///
+    /// Writing the state as `solved * P`, the result is `solved * s⁻¹ * P * s`. Since
+    /// `solved * s⁻¹` is the solved state with each face `f` showing the colour of face
+    /// `s(f)`, and recolouring commutes with moving stickers, this is the state
+    /// `self * s` with every colour of face `f` replaced by the colour of `s(f)`.
+    /// Colours not in the scheme are left unchanged.
+    pub fn transformed_in<Scheme: ColourScheme>(&self, symmetry: CubeSymmetry, scheme: Scheme) -> Self {
+        let moved = self * &symmetry;
+        let faces = FacePerm::from(symmetry);
+        let recolour = |colour| match scheme.get_face(colour) {
+            Ok(face) => scheme.from_face(faces[face]),
+            Err(_) => colour,
+        };
+        RubiksState::from_fn(|tile| recolour(moved[tile]))
+    }
+
+    /// Returns the mirror image of this state across a plane, using `scheme` to
+    /// recolour. See [`RubiksState::transformed_in`].
+    pub fn mirrored_in<Scheme: ColourScheme>(&self, plane: MirrorPlane, scheme: Scheme) -> Self {
+        self.transformed_in(CubeSymmetry::mirror(plane), scheme)
+    }
+}
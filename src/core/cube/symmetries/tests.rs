@@ -0,0 +1,115 @@
+use super::*;
+use crate::core::cube::rotations::{X, Y, Z};
+use crate::core::cube::schemes::Western;
+use crate::core::rubiks::moves::{BasicMove, MiddleMove, RangeMove, SliceMove, WideMove};
+
+fn perm<const N: usize, T>(moves: &[T]) -> TilePerm<N>
+where
+    for<'a> &'a T: Into<TilePerm<N>>,
+{
+    moves.iter().fold(TilePerm::ID, |acc, mv| acc * mv.into())
+}
+
+/// Checks that transforming a move agrees with conjugating its tile permutation.
+fn assert_conjugates<const N: usize, T>(moves: &[T])
+where
+    T: Symmetric + std::fmt::Debug,
+    for<'a> &'a T: Into<TilePerm<N>>,
+{
+    for symmetry in CubeSymmetry::all() {
+        for mv in moves {
+            let transformed = mv.transformed(symmetry);
+            let expected: TilePerm<N> = (&transformed).into();
+            let conjugated: TilePerm<N> = mv.into();
+            assert_eq!(conjugated.transformed(symmetry), expected, "{mv:?} under {symmetry:?}");
+        }
+    }
+}
+
+#[test]
+fn test_group_structure() {
+    let all = CubeSymmetry::all();
+    for (i, a) in all.iter().enumerate() {
+        assert!(all[..i].iter().all(|b| b != a));
+        assert_eq!(*a * a.inverse(), CubeSymmetry::ID);
+    }
+    assert_eq!(all.iter().filter(|s| s.is_reflection()).count(), 24);
+    // Mirrors are involutions and their matrices negate one axis
+    assert_eq!(CubeSymmetry::mirror(MirrorPlane::M).matrix(), [[-1, 0, 0], [0, 1, 0], [0, 0, 1]]);
+    assert_eq!(CubeSymmetry::mirror(MirrorPlane::E).matrix(), [[1, 0, 0], [0, -1, 0], [0, 0, 1]]);
+    assert_eq!(CubeSymmetry::mirror(MirrorPlane::S).matrix(), [[1, 0, 0], [0, 1, 0], [0, 0, -1]]);
+    for plane in [MirrorPlane::M, MirrorPlane::E, MirrorPlane::S] {
+        let mirror = CubeSymmetry::mirror(plane);
+        assert_eq!(mirror * mirror, CubeSymmetry::ID);
+    }
+    // The composite of two mirrors is a half turn
+    let ms = CubeSymmetry::mirror(MirrorPlane::M) * CubeSymmetry::mirror(MirrorPlane::S);
+    assert_eq!(ms, CubeSymmetry::from(crate::core::cube::rotations::Y2));
+}
+
+#[test]
+fn test_tile_perms_are_homomorphic() {
+    let all = CubeSymmetry::all();
+    for a in all.iter().step_by(5) {
+        for b in all.iter().step_by(7) {
+            assert_eq!(TilePerm::<3>::from(&(*a * *b)), TilePerm::from(a) * TilePerm::from(b));
+        }
+    }
+    let m = TilePerm::<2>::from(&CubeSymmetry::mirror(MirrorPlane::M));
+    assert_eq!(m[TilePos { face: Face::Right, row: 0, col: 0 }].face, Face::Left);
+    assert_eq!(m[TilePos { face: Face::Up, row: 0, col: 0 }], TilePos { face: Face::Up, row: 0, col: 1 });
+    assert_eq!(TilePerm::<3>::from(&CubeSymmetry::INVERSION).order(), 2);
+}
+
+#[test]
+fn test_moves_transform_like_their_tile_perms() {
+    use BasicMove::*;
+    assert_conjugates::<3, _>(&[U, D2, L3, R, F2, B3]);
+    assert_conjugates::<4, _>(&[WideMove::Uw(2), WideMove::Rw3(3), WideMove::Bw2(2)]);
+    assert_conjugates::<5, _>(&[SliceMove::Fs(2), SliceMove::Ls3(4), SliceMove::Ds2(3)]);
+    assert_conjugates::<5, _>(&[RangeMove::Ur(1, 2), RangeMove::Rr3(2, 4)]);
+    assert_conjugates::<3, _>(&[MiddleMove::M, MiddleMove::E3, MiddleMove::S2]);
+}
+
+#[test]
+fn test_mirroring_algorithms() {
+    use BasicMove::*;
+    // M mirror of R U R' U' is L' U' L U
+    let sexy: Vec<BasicMove<3>> = vec![R, U, R3, U3];
+    assert_eq!(format!("{:?}", sexy.mirrored(MirrorPlane::M)), "[L3, U3, L, U]");
+    // The E mirror turns U into D'
+    assert_eq!(format!("{:?}", sexy.mirrored(MirrorPlane::E)), "[R3, D3, R, D]");
+    assert_eq!(format!("{:?}", MiddleMove::<3>::M.mirrored(MirrorPlane::M)), "M");
+    assert_eq!(format!("{:?}", MiddleMove::<3>::M.mirrored(MirrorPlane::E)), "M3");
+    // x carries the U layer to the B layer
+    assert_eq!(format!("{:?}", BasicMove::<3>::U.transformed(CubeSymmetry::from(X))), "B");
+
+    let mirrored = sexy.mirrored(MirrorPlane::S);
+    assert_eq!(perm::<3, _>(&mirrored), perm::<3, _>(&sexy).mirrored(MirrorPlane::S));
+}
+
+#[test]
+fn test_rotations_and_symmetries_transform() {
+    let s = CubeSymmetry::mirror(MirrorPlane::M);
+    assert!([Y, Y * Y * Y].contains(&Y.transformed(s)));
+    // Mirroring in M reverses turns about the x axis but keeps X as a rotation about it
+    assert_eq!(X.transformed(s), X);
+    let tile = TilePerm::<3>::from(&Y.transformed(s));
+    assert_eq!(tile, TilePerm::<3>::from(&Y).transformed(s));
+    assert_eq!(CubeSymmetry::from(Z).transformed(CubeSymmetry::ID), CubeSymmetry::from(Z));
+}
+
+#[test]
+fn test_mirrored_states() {
+    use BasicMove::*;
+    let solved = RubiksState::<3>::solved_in(Western);
+    let sexy: Vec<BasicMove<3>> = vec![R, U, R3, U3];
+    let state = sexy.iter().fold(solved.clone(), |state, mv| &state * mv);
+    for plane in [MirrorPlane::M, MirrorPlane::E, MirrorPlane::S] {
+        let expected = sexy.mirrored(plane).iter().fold(solved.clone(), |state, mv| &state * mv);
+        assert_eq!(state.mirrored_in(plane, Western), expected, "{plane:?}");
+    }
+    for symmetry in CubeSymmetry::all() {
+        assert_eq!(solved.transformed_in(symmetry, Western), solved);
+    }
+}
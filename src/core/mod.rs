@@ -59,8 +59,9 @@ mod tests;
 /// - **Copy/Clone**: Efficient passing and storage
 /// - **Debug**: Development and testing support
 /// - **PartialEq/Eq**: Color comparison operations
+/// - **PartialOrd/Ord**: Colours order as declared, so states can be compared
 /// - **Hash**: Use in hash-based collections and algorithms
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum Colour {
     /// Pure white color, typically used for the top face in Western schemes
     White,
@@ -257,7 +258,6 @@ impl Angle {
     ///
     /// This is the core transformation used throughout the tile permutation system
     /// to calculate where tiles move when faces are rotated. It's used by:
-    /// - `rotate_face_only` to rotate tiles on a single face
     /// - Cube rotation tile permutation calculations
     /// - Face orientation calculations in tile permutations
     ///
@@ -0,0 +1,417 @@
+//! Structured algorithms built from conjugates and commutators.
+//!
+//! Algorithm designers rarely think of a long algorithm as a flat list of moves.
+//! Most are assembled from two building blocks:
+//!
+//! - the **conjugate** `[A: B] = A B A'`, which performs `B` "somewhere else" by setting
+//!   up with `A` and undoing the setup afterwards;
+//! - the **commutator** `[A, B] = A B A' B'`, which only disturbs pieces touched by both
+//!   `A` and `B`, making it the standard way to build 3-cycles.
+//!
+//! [`Alg<M>`] keeps this structure, so an algorithm can be printed in bracket notation,
+//! [expanded](Alg::expand) into its moves, [simplified](Alg::simplified) by cancelling
+//! adjacent turns, inverted structurally and converted to a [`TilePerm<N>`]. It also
+//! parses from nested bracket notation with [`str::parse`].
+//!
+//! # Move Types
+//!
+//! An `Alg<M>` is built over a single move type `M`, usually one of the five families
+//! in [`moves`](super::moves). Simplification needs to know how turns combine, which the
+//! [`Turn`] trait describes; parsing uses the move type's [`FromStr`] notation.
+//!
+//! # Examples
+//!
+//! ```
+//! use rubiks_cube_representation::core::rubiks::algs::Alg;
+//! use rubiks_cube_representation::core::rubiks::moves::BasicMove;
+//! use rubiks_cube_representation::core::rubiks::tiles::TilePerm;
+//!
+//! // A corner 3-cycle: a commutator conjugated by a setup move
+//! let alg: Alg<BasicMove<3>> = "[D': [R U R', D]]".parse().unwrap();
+//! assert_eq!(alg.to_string(), "[D': [R U R', D]]");
+//! assert_eq!(alg.move_count(), 10);
+//!
+//! let text = |moves: Vec<BasicMove<3>>| moves.iter().map(ToString::to_string).collect::<Vec<_>>().join(" ");
+//! assert_eq!(text(alg.expand()), "D' R U R' D R U' R' D' D");
+//! assert_eq!(text(alg.simplified()), "D' R U R' D R U' R'");
+//!
+//! // Three corners of three stickers each are cycled
+//! let perm = TilePerm::<3>::from(&alg);
+//! assert_eq!(perm.order(), 3);
+//! assert_eq!(perm.support().len(), 9);
+//! ```
+
+use std::fmt;
+use std::str::FromStr;
+
+use crate::core::rubiks::moves::{BasicMove, BasicMoveInternal, MiddleMove, MiddleMoveInternal, RangeMove,
+    RangeMoveInternal, SliceMove, SliceMoveInternal, WideMove, WideMoveInternal};
+use crate::core::rubiks::tiles::{NonTilePermOperation, TilePerm};
+use crate::core::Angle;
+use crate::error::Error;
+
+#[cfg(test)]
+mod tests;
+
+/// A move that turns a fixed set of layers by some amount.
+///
+/// This is what [`simplify`] needs to know about a move type: two consecutive turns of
+/// the same layers combine into one, and cancel when their amounts sum to zero.
+pub trait Turn: Copy {
+    /// The amount this move turns its layers by.
+    fn amount(self) -> Angle;
+
+    /// The move turning the same layers by `amount`, or `None` for [`Angle::Zero`].
+    fn with_amount(self, amount: Angle) -> Option<Self>;
+
+    /// Whether `self` and `other` turn the same layers in the same sense.
+    fn same_layers(self, other: Self) -> bool;
+
+    /// The move undoing this one.
+    fn inverse(self) -> Self {
+        self.with_amount(Angle::Zero - self.amount()).expect("moves never turn by zero")
+    }
+}
+
+impl<const N: usize> Turn for BasicMove<N> {
+    fn amount(self) -> Angle {
+        BasicMoveInternal::from(self).amount
+    }
+
+    fn with_amount(self, amount: Angle) -> Option<Self> {
+        let face = BasicMoveInternal::from(self).face;
+        (amount != Angle::Zero).then(|| BasicMove::from_parts(face, amount))
+    }
+
+    fn same_layers(self, other: Self) -> bool {
+        BasicMoveInternal::from(self).face == BasicMoveInternal::from(other).face
+    }
+}
+
+impl<const N: usize> Turn for WideMove<N> {
+    fn amount(self) -> Angle {
+        WideMoveInternal::from(self).amount
+    }
+
+    fn with_amount(self, amount: Angle) -> Option<Self> {
+        let WideMoveInternal { face, depth, .. } = WideMoveInternal::from(self);
+        (amount != Angle::Zero).then(|| WideMove::from_parts(face, amount, depth))
+    }
+
+    fn same_layers(self, other: Self) -> bool {
+        let (a, b) = (WideMoveInternal::from(self), WideMoveInternal::from(other));
+        (a.face, a.depth) == (b.face, b.depth)
+    }
+}
+
+impl<const N: usize> Turn for SliceMove<N> {
+    fn amount(self) -> Angle {
+        SliceMoveInternal::from(self).amount
+    }
+
+    fn with_amount(self, amount: Angle) -> Option<Self> {
+        let SliceMoveInternal { face, layer, .. } = SliceMoveInternal::from(self);
+        (amount != Angle::Zero).then(|| SliceMove::from_parts(face, amount, layer))
+    }
+
+    fn same_layers(self, other: Self) -> bool {
+        let (a, b) = (SliceMoveInternal::from(self), SliceMoveInternal::from(other));
+        (a.face, a.layer) == (b.face, b.layer)
+    }
+}
+
+impl<const N: usize> Turn for RangeMove<N> {
+    fn amount(self) -> Angle {
+        RangeMoveInternal::from(self).amount
+    }
+
+    fn with_amount(self, amount: Angle) -> Option<Self> {
+        let RangeMoveInternal { face, start_layer, end_layer, .. } = RangeMoveInternal::from(self);
+        (amount != Angle::Zero).then(|| RangeMove::from_parts(face, amount, start_layer, end_layer))
+    }
+
+    fn same_layers(self, other: Self) -> bool {
+        let (a, b) = (RangeMoveInternal::from(self), RangeMoveInternal::from(other));
+        (a.face, a.start_layer, a.end_layer) == (b.face, b.start_layer, b.end_layer)
+    }
+}
+
+impl<const N: usize> Turn for MiddleMove<N> {
+    fn amount(self) -> Angle {
+        MiddleMoveInternal::from(self).amount
+    }
+
+    fn with_amount(self, amount: Angle) -> Option<Self> {
+        let face = MiddleMoveInternal::from(self).face;
+        (amount != Angle::Zero).then(|| MiddleMove::from_parts(face, amount))
+    }
+
+    fn same_layers(self, other: Self) -> bool {
+        MiddleMoveInternal::from(self).face == MiddleMoveInternal::from(other).face
+    }
+}
+
+/// Cancels and merges adjacent turns of the same layers.
+///
+/// # Algorithm
+///
+/// The moves are pushed onto a stack. A move turning the same layers as the top of the
+/// stack is merged into it, popping it if the turns cancel, so cancellations cascade:
+/// `R U U' R'` simplifies to nothing. Turns of different layers are never reordered,
+/// so `R L R'` is left alone even though `R` and `L` commute.
+pub fn simplify<M: Turn>(moves: impl IntoIterator<Item = M>) -> Vec<M> {
+    let mut stack: Vec<M> = Vec::new();
+    for mv in moves {
+        match stack.last() {
+            Some(&top) if top.same_layers(mv) => {
+                stack.pop();
+                stack.extend(top.with_amount(top.amount() + mv.amount()));
+            }
+            _ => stack.push(mv),
+        }
+    }
+    stack
+}
+
+/// An algorithm over moves of type `M`, keeping its conjugate and commutator structure.
+///
+/// # Notation
+///
+/// Algorithms print and parse as moves separated by spaces, with `[A: B]` for the
+/// conjugate `A B A'`, `[A, B]` for the commutator `A B A' B'` and parentheses for
+/// grouping. Brackets nest arbitrarily: `[R U: [R', D]]`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Alg<M> {
+    /// A single move
+    Move(M),
+    /// Algorithms performed one after another
+    Sequence(Vec<Alg<M>>),
+    /// The conjugate `[A: B] = A B A'`
+    Conjugate(Box<Alg<M>>, Box<Alg<M>>),
+    /// The commutator `[A, B] = A B A' B'`
+    Commutator(Box<Alg<M>>, Box<Alg<M>>),
+}
+
+impl<M> Alg<M> {
+    /// The empty algorithm.
+    pub const EMPTY: Self = Alg::Sequence(Vec::new());
+
+    /// The algorithm performing `moves` in order.
+    pub fn moves(moves: impl IntoIterator<Item = M>) -> Self {
+        Alg::Sequence(moves.into_iter().map(Alg::Move).collect())
+    }
+
+    /// The conjugate `[setup: body]`, performing `setup`, `body` and then undoing `setup`.
+    pub fn conjugate(setup: Alg<M>, body: Alg<M>) -> Self {
+        Alg::Conjugate(Box::new(setup), Box::new(body))
+    }
+
+    /// The commutator `[a, b]`, performing `a`, `b`, `a'` and then `b'`.
+    pub fn commutator(a: Alg<M>, b: Alg<M>) -> Self {
+        Alg::Commutator(Box::new(a), Box::new(b))
+    }
+
+    /// The number of moves in the expanded algorithm, before simplification.
+    pub fn move_count(&self) -> usize {
+        match self {
+            Alg::Move(_) => 1,
+            Alg::Sequence(items) => items.iter().map(Alg::move_count).sum(),
+            Alg::Conjugate(a, b) => 2 * a.move_count() + b.move_count(),
+            Alg::Commutator(a, b) => 2 * (a.move_count() + b.move_count()),
+        }
+    }
+}
+
+impl<M: Turn> Alg<M> {
+    /// The inverse algorithm, keeping the bracket structure.
+    ///
+    /// Uses `[A: B]' = [A: B']` and `[A, B]' = [B, A]`.
+    pub fn inverse(&self) -> Self {
+        match self {
+            Alg::Move(mv) => Alg::Move(mv.inverse()),
+            Alg::Sequence(items) => Alg::Sequence(items.iter().rev().map(Alg::inverse).collect()),
+            Alg::Conjugate(a, b) => Alg::Conjugate(a.clone(), Box::new(b.inverse())),
+            Alg::Commutator(a, b) => Alg::Commutator(b.clone(), a.clone()),
+        }
+    }
+
+    /// Writes out every move of the algorithm in order.
+    pub fn expand(&self) -> Vec<M> {
+        let mut moves = Vec::with_capacity(self.move_count());
+        self.expand_into(&mut moves);
+        moves
+    }
+
+    fn expand_into(&self, moves: &mut Vec<M>) {
+        match self {
+            Alg::Move(mv) => moves.push(*mv),
+            Alg::Sequence(items) => items.iter().for_each(|item| item.expand_into(moves)),
+            Alg::Conjugate(a, b) => {
+                a.expand_into(moves);
+                b.expand_into(moves);
+                a.inverse().expand_into(moves);
+            }
+            Alg::Commutator(a, b) => {
+                a.expand_into(moves);
+                b.expand_into(moves);
+                a.inverse().expand_into(moves);
+                b.inverse().expand_into(moves);
+            }
+        }
+    }
+
+    /// Expands the algorithm and [simplifies](simplify) the result.
+    pub fn simplified(&self) -> Vec<M> {
+        simplify(self.expand())
+    }
+}
+
+impl<M> From<Vec<M>> for Alg<M> {
+    fn from(moves: Vec<M>) -> Self {
+        Alg::moves(moves)
+    }
+}
+
+impl<M: fmt::Display> fmt::Display for Alg<M> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Alg::Move(mv) => write!(f, "{mv}"),
+            Alg::Sequence(items) => {
+                for (i, item) in items.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(" ")?;
+                    }
+                    match item {
+                        Alg::Sequence(_) => write!(f, "({item})")?,
+                        _ => write!(f, "{item}")?,
+                    }
+                }
+                Ok(())
+            }
+            Alg::Conjugate(a, b) => write!(f, "[{a}: {b}]"),
+            Alg::Commutator(a, b) => write!(f, "[{a}, {b}]"),
+        }
+    }
+}
+
+impl<const N: usize, M: Turn + Into<TilePerm<N>>> From<&Alg<M>> for TilePerm<N> {
+    /// Composes the algorithm's permutation, reusing the permutation of each bracket's
+    /// operands instead of expanding them.
+    fn from(alg: &Alg<M>) -> Self {
+        match alg {
+            Alg::Move(mv) => (*mv).into(),
+            Alg::Sequence(items) => items.iter().fold(TilePerm::identity(), |acc, item| acc * &TilePerm::from(item)),
+            Alg::Conjugate(a, b) => {
+                let a = TilePerm::from(&**a);
+                &(&a * &TilePerm::from(&**b)) * &a.inverse()
+            }
+            Alg::Commutator(a, b) => {
+                let (a, b) = (TilePerm::from(&**a), TilePerm::from(&**b));
+                &(&(&a * &b) * &a.inverse()) * &b.inverse()
+            }
+        }
+    }
+}
+
+impl<const N: usize, M: Turn + Into<TilePerm<N>>> From<Alg<M>> for TilePerm<N> {
+    fn from(alg: Alg<M>) -> Self {
+        Self::from(&alg)
+    }
+}
+
+impl<const N: usize, M: Turn + Into<TilePerm<N>>> NonTilePermOperation<N> for Alg<M> {}
+
+impl<M: FromStr<Err = Error>> FromStr for Alg<M> {
+    type Err = Error;
+
+    /// Parses bracket notation such as `[R U: [R', D]]`. Syntax errors, including those
+    /// within a move, report their position in the whole text.
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let mut parser = Parser { text, pos: 0 };
+        let alg = parser.sequence()?;
+        match parser.peek() {
+            None => Ok(alg),
+            Some(_) => Err(parser.unexpected()),
+        }
+    }
+}
+
+/// Characters that end a move token.
+const DELIMITERS: [char; 6] = ['[', ']', '(', ')', ':', ','];
+
+/// A recursive descent parser over the grammar
+///
+/// ```text
+/// sequence := item*
+/// item     := move | "(" sequence ")" | "[" sequence (":" | ",") sequence "]"
+/// ```
+struct Parser<'a> {
+    text: &'a str,
+    pos: usize,
+}
+
+impl Parser<'_> {
+    /// Skips whitespace and returns the next character without consuming it.
+    fn peek(&mut self) -> Option<char> {
+        let rest = &self.text[self.pos..];
+        self.pos += rest.len() - rest.trim_start().len();
+        self.text[self.pos..].chars().next()
+    }
+
+    /// The error for the next character, or for the end of the text.
+    fn unexpected(&mut self) -> Error {
+        let token = self.peek().map(String::from).unwrap_or_default();
+        Error::Parse { position: self.pos, token }
+    }
+
+    fn expect(&mut self, expected: char) -> Result<(), Error> {
+        match self.peek() {
+            Some(c) if c == expected => {
+                self.pos += c.len_utf8();
+                Ok(())
+            }
+            _ => Err(self.unexpected()),
+        }
+    }
+
+    /// Parses items up to the next closing bracket, separator or the end. A sequence of
+    /// one item is returned as that item.
+    fn sequence<M: FromStr<Err = Error>>(&mut self) -> Result<Alg<M>, Error> {
+        let mut items = Vec::new();
+        loop {
+            match self.peek() {
+                None | Some(']' | ')' | ':' | ',') => break,
+                Some('(') => {
+                    self.expect('(')?;
+                    let group = self.sequence()?;
+                    self.expect(')')?;
+                    items.push(group);
+                }
+                Some('[') => {
+                    self.expect('[')?;
+                    let a = self.sequence()?;
+                    let bracket = match self.peek() {
+                        Some(':') => Alg::conjugate,
+                        Some(',') => Alg::commutator,
+                        _ => return Err(self.unexpected()),
+                    };
+                    self.pos += 1;
+                    let b = self.sequence()?;
+                    self.expect(']')?;
+                    items.push(bracket(a, b));
+                }
+                Some(_) => {
+                    let rest = &self.text[self.pos..];
+                    let len = rest.find(|c: char| c.is_whitespace() || DELIMITERS.contains(&c)).unwrap_or(rest.len());
+                    let mv = rest[..len].parse().map_err(|error| match error {
+                        Error::Parse { position, token } => Error::Parse { position: self.pos + position, token },
+                        error => error,
+                    })?;
+                    items.push(Alg::Move(mv));
+                    self.pos += len;
+                }
+            }
+        }
+        Ok(if items.len() == 1 { items.pop().expect("one item") } else { Alg::Sequence(items) })
+    }
+}
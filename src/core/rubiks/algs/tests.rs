@@ -0,0 +1,104 @@
+use super::*;
+use crate::core::cube::schemes::Western;
+use crate::core::rubiks::moves::BasicMove::{self, *};
+use crate::core::rubiks::moves::{MiddleMove, RangeMove, SliceMove, WideMove};
+use crate::core::rubiks::RubiksState;
+
+fn parse(text: &str) -> Alg<BasicMove<3>> {
+    text.parse().unwrap_or_else(|err| panic!("{text}: {err}"))
+}
+
+fn perm(moves: &[BasicMove<3>]) -> TilePerm<3> {
+    moves.iter().fold(TilePerm::ID, |acc, mv| acc * TilePerm::from(mv))
+}
+
+#[test]
+fn test_builders() {
+    let a = Alg::<BasicMove<3>>::moves([R, U, R3]);
+    let conjugate = Alg::conjugate(a.clone(), Alg::Move(D));
+    assert_eq!(conjugate.expand(), vec![R, U, R3, D, R, U3, R3]);
+    assert_eq!(conjugate.to_string(), "[R U R': D]");
+    let commutator = Alg::commutator(a, Alg::Move(D));
+    assert_eq!(commutator.expand(), vec![R, U, R3, D, R, U3, R3, D3]);
+    assert_eq!(commutator.move_count(), 8);
+    assert_eq!(Alg::<BasicMove<3>>::EMPTY.expand(), vec![]);
+    assert_eq!(Alg::<BasicMove<3>>::from(vec![F, B]).to_string(), "F B");
+}
+
+#[test]
+fn test_parse_round_trips() {
+    for text in ["[R U: [R', D]]", "R U R' U'", "[R, U]", "[[R, U]: [F2, D' L]]", "(R U) (R' U')", "R2"] {
+        assert_eq!(parse(text).to_string(), text);
+    }
+    assert_eq!(parse("  [ R U :[R',D] ] "), parse("[R U: [R', D]]"));
+    assert_eq!(parse(""), Alg::EMPTY);
+    assert_eq!(parse("R"), Alg::Move(R));
+}
+
+#[test]
+fn test_parse_errors() {
+    for text in ["[R U]", "[R: U", "R ]", "(R U", "[R: U, D]", "RU", "R: U", "[R, Rw]"] {
+        assert!(text.parse::<Alg<BasicMove<3>>>().is_err(), "{text} should not parse");
+    }
+    let error = |position, token: &str| Err(Error::Parse { position, token: token.to_string() });
+    assert_eq!("[R U]".parse::<Alg<BasicMove<3>>>(), error(4, "]"));
+    assert_eq!("[R: U".parse::<Alg<BasicMove<3>>>(), error(5, ""));
+    assert_eq!("R ]".parse::<Alg<BasicMove<3>>>(), error(2, "]"));
+    assert_eq!("R U X2".parse::<Alg<BasicMove<3>>>(), error(4, "X2"));
+    assert_eq!("2R 4U".parse::<Alg<SliceMove<3>>>(), Err(Error::InvalidLayer { layer: 4, n: 3 }));
+}
+
+#[test]
+fn test_inverse() {
+    for text in ["[R U: [R', D]]", "[R, U] F", "[[R, U]: [F2, D' L]]"] {
+        let alg = parse(text);
+        let inverse = alg.inverse();
+        assert_eq!(simplify(alg.expand().into_iter().chain(inverse.expand())), vec![]);
+        assert_eq!(TilePerm::<3>::from(&inverse), TilePerm::from(&alg).inverse());
+    }
+    assert_eq!(parse("[R, U]").inverse().to_string(), "[U, R]");
+    assert_eq!(parse("[R: U]").inverse().to_string(), "[R: U']");
+}
+
+#[test]
+fn test_simplify() {
+    assert_eq!(simplify::<BasicMove<3>>([R, U, U3, R3]), vec![]);
+    assert_eq!(simplify::<BasicMove<3>>([R, R, R]), vec![R3]);
+    assert_eq!(simplify::<BasicMove<3>>([R2, R3, L, R]), vec![R, L, R]);
+    assert_eq!(parse("[R U: [R', D]]").simplified(), vec![R, U, R3, D, R, D3, U3, R3]);
+    // The setup cancels against the commutator
+    assert_eq!(parse("[R: [R', D]]").simplified(), vec![D, R, D3, R3]);
+
+    let wide: Vec<WideMove<4>> = vec![WideMove::Rw(2), WideMove::Rw(3), WideMove::Rw3(2)];
+    assert_eq!(simplify(wide), vec![WideMove::Rw(2), WideMove::Rw(3), WideMove::Rw3(2)]);
+    assert_eq!(simplify([MiddleMove::<3>::M, MiddleMove::M]), vec![MiddleMove::M2]);
+    assert_eq!(simplify([SliceMove::<5>::Rs(2), SliceMove::Rs3(2)]), vec![]);
+    assert_eq!(simplify([RangeMove::<5>::Rr2(2, 3), RangeMove::Rr2(2, 3)]), vec![]);
+}
+
+#[test]
+fn test_tile_perms() {
+    for text in ["[R U: [R', D]]", "[R U R', D2]", "[F: R U R' U'] (L2 B)"] {
+        let alg = parse(text);
+        assert_eq!(TilePerm::<3>::from(&alg), perm(&alg.expand()), "{text}");
+        assert_eq!(TilePerm::<3>::from(&alg), perm(&alg.simplified()), "{text}");
+    }
+    // Algorithms apply to states like any other operation
+    let alg = parse("[R, U]");
+    let solved = RubiksState::<3>::solved_in(Western);
+    let expected = [R, U, R3, U3].iter().fold(solved.clone(), |state, mv| &state * mv);
+    assert_eq!(&solved * &alg, expected);
+    assert_eq!(&TilePerm::<3>::ID * &alg, perm(&[R, U, R3, U3]));
+}
+
+#[test]
+fn test_other_move_types() {
+    let alg: Alg<WideMove<4>> = "[Rw: [Uw, 3Rw']]".parse().unwrap();
+    assert_eq!(alg.to_string(), "[Rw: [Uw, 3Rw']]");
+    let expanded: Vec<TilePerm<4>> = alg.expand().iter().map(TilePerm::from).collect();
+    let product = expanded.iter().fold(TilePerm::ID, |acc, p| acc * p);
+    assert_eq!(TilePerm::from(&alg), product);
+
+    let alg: Alg<MiddleMove<3>> = "[M: E2] S'".parse().unwrap();
+    assert_eq!(alg.expand(), vec![MiddleMove::M, MiddleMove::E2, MiddleMove::M3, MiddleMove::S3]);
+}
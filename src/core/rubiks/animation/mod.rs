@@ -0,0 +1,292 @@
+//! Rigid-body animation of moves and cube rotations.
+//!
+//! A [`Motion<N>`] describes a move as a physical turn: an axis through the centre of the
+//! cube, a total angle, and the set of cubies that take part. Sampling it at an
+//! interpolation parameter `t ∈ [0, 1]` gives the [`RigidTransform`] of every cubie and
+//! sticker part-way through the turn, which is what a visualiser needs to draw
+//! intermediate frames.
+//!
+//! # Coordinates
+//!
+//! Positions use the cube frame described by [`Point3`]: centred on the middle of the
+//! cube, `x` towards Right, `y` towards Up and `z` towards Front, one unit per tile.
+//! Cubies are addressed by integer coordinates `[x, y, z]` from `0` to `N-1`, as returned
+//! by [`TilePos::cubie`], and stickers by their [`TilePos`] in the state *before* the
+//! move.
+//!
+//! # Agreement with Tile Permutations
+//!
+//! At `t = 1` every transform lands each sticker exactly on the tile the corresponding
+//! [`TilePerm<N>`](crate::core::rubiks::tiles::TilePerm) sends it to: the centre of tile
+//! `p` is carried to the centre of tile `perm[p]`.
+//!
+//! Moves are animated through the same checked layer turn as that conversion, so a move
+//! whose layers do not fit the cube, such as a middle move on an even cube, panics
+//! alike; the moves' `checked` methods report it up front.
+//!
+//! # Example
+//!
+//! ```
+//! use rubiks_cube_representation::Face;
+//! use rubiks_cube_representation::core::rubiks::animation::Motion;
+//! use rubiks_cube_representation::core::rubiks::moves::BasicMove;
+//! use rubiks_cube_representation::core::rubiks::tiles::TilePos;
+//!
+//! let motion = Motion::from(&BasicMove::<3>::U);
+//! let tile = TilePos { face: Face::Front, row: 0, col: 1 };
+//!
+//! // Halfway through U the front-top sticker is swinging towards the Left face
+//! let halfway = motion.sticker_transform(tile, 0.5).apply(tile.centre::<3>());
+//! assert!(halfway[0] < 0.0 && halfway[2] > 0.0);
+//!
+//! // At the end it sits on the Left face
+//! let done = motion.sticker_transform(tile, 1.0).apply(tile.centre::<3>());
+//! assert_eq!(TilePos::at_point::<3>(done, 1e-9).unwrap().face, Face::Left);
+//! ```
+
+use std::array::from_fn;
+use std::f64::consts::FRAC_PI_2;
+
+use crate::core::cube::geometry::{Face, Point3, FACES};
+use crate::core::cube::rotations::CubeRotation;
+use crate::core::rubiks::moves::{BasicMove, MiddleMove, RangeMove, SliceMove, WideMove};
+use crate::core::rubiks::tiles::motion::LayerTurn;
+use crate::core::rubiks::tiles::TilePos;
+use crate::core::Angle;
+
+#[cfg(test)]
+mod tests;
+
+/// A rotation of 3D space about an axis through the centre of the cube.
+///
+/// Every motion of a cube part during a move or whole-cube rotation is of this form, so
+/// no translation component is needed.
+///
+/// # Fields
+///
+/// - **axis**: Unit vector of the rotation axis
+/// - **angle**: Rotation angle in radians, anticlockwise when looking down the axis
+///   towards the origin (right-hand rule)
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RigidTransform {
+    /// Unit vector of the rotation axis
+    pub axis: Point3,
+    /// Rotation angle in radians, following the right-hand rule
+    pub angle: f64,
+}
+
+impl RigidTransform {
+    /// The transform that leaves everything in place.
+    pub const IDENTITY: Self = Self { axis: [0.0, 1.0, 0.0], angle: 0.0 };
+
+    /// Returns the 3×3 rotation matrix, acting on column vectors.
+    ///
+    /// Computed with Rodrigues' rotation formula.
+    pub fn matrix(&self) -> [[f64; 3]; 3] {
+        let [x, y, z] = self.axis;
+        let (sin, cos) = self.angle.sin_cos();
+        let k = 1.0 - cos;
+        [
+            [cos + x * x * k, x * y * k - z * sin, x * z * k + y * sin],
+            [y * x * k + z * sin, cos + y * y * k, y * z * k - x * sin],
+            [z * x * k - y * sin, z * y * k + x * sin, cos + z * z * k],
+        ]
+    }
+
+    /// Returns the rotation as a unit quaternion `[x, y, z, w]`.
+    ///
+    /// The component order matches glTF and three.js.
+    pub fn quaternion(&self) -> [f64; 4] {
+        let (sin, cos) = (self.angle / 2.0).sin_cos();
+        [self.axis[0] * sin, self.axis[1] * sin, self.axis[2] * sin, cos]
+    }
+
+    /// Applies the transform to a point.
+    pub fn apply(&self, point: Point3) -> Point3 {
+        let m = self.matrix();
+        from_fn(|i| (0..3).map(|j| m[i][j] * point[j]).sum())
+    }
+}
+
+/// Which cubies take part in a [`Motion`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Moving {
+    /// Layers `start..=end` counted from `face`, 1-indexed
+    Layers { face: Face, start: usize, end: usize },
+    /// The whole cube
+    All,
+}
+
+/// A move or cube rotation as a continuous physical turn.
+///
+/// Motions are created from any move type or from a [`CubeRotation`] via [`From`], or
+/// directly with [`Motion::layers`] and [`Motion::whole_cube`].
+///
+/// # Layer Turns
+///
+/// A clockwise turn of layers counted from face `F` rotates them by -90° about `F`'s
+/// outward normal (clockwise as seen from outside the face). Half turns go clockwise
+/// through -180°.
+///
+/// # Whole-Cube Rotations
+///
+/// Each of the 24 rotations is animated along its shortest path, using
+/// [`CubeRotation::axis_angle`]: quarter and half turns about face axes, half turns
+/// about edge axes, and 120° turns about corner diagonals.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Motion<const N: usize> {
+    axis: Point3,
+    angle: f64,
+    moving: Moving,
+}
+
+impl<const N: usize> Motion<N> {
+    /// Creates a turn of layers `start_layer..=end_layer` counted from `face`.
+    ///
+    /// Layers are 1-indexed like the move notation: layer 1 is the face itself.
+    pub fn layers(face: Face, start_layer: usize, end_layer: usize, amount: Angle) -> Self {
+        let quarters = match amount {
+            Angle::Zero => 0.0,
+            Angle::CWQuarter => 1.0,
+            Angle::Half => 2.0,
+            Angle::ACWQuarter => -1.0,
+        };
+        Self {
+            axis: face.normal().map(f64::from),
+            angle: -quarters * FRAC_PI_2,
+            moving: Moving::Layers { face, start: start_layer, end: end_layer },
+        }
+    }
+
+    /// The motion of a move's layer turn, whose layers were checked as for its
+    /// [`TilePerm`](crate::core::rubiks::tiles::TilePerm) conversion.
+    fn turn(turn: LayerTurn) -> Self {
+        Self::layers(turn.face, turn.start, turn.end, turn.amount)
+    }
+
+    /// Creates a rotation of the whole cube.
+    pub fn whole_cube(rotation: CubeRotation) -> Self {
+        let (axis, angle) = match rotation.axis_angle() {
+            // Clockwise about the named axis is anticlockwise about its reverse
+            Some((axis, degrees)) => (normalised(axis.direction().map(|c| -f64::from(c))), f64::from(degrees).to_radians()),
+            None => (RigidTransform::IDENTITY.axis, 0.0),
+        };
+        Self { axis, angle, moving: Moving::All }
+    }
+
+    /// Returns the transform of the moving parts at parameter `t`.
+    ///
+    /// `t = 0` is the start of the turn and `t = 1` the end; values in between
+    /// interpolate the angle linearly. Apply an easing curve to `t` beforehand for
+    /// smoother animation.
+    pub fn at(&self, t: f64) -> RigidTransform {
+        RigidTransform { axis: self.axis, angle: self.angle * t }
+    }
+
+    /// Returns whether the cubie at integer coordinates `cubie` takes part in the motion.
+    pub fn moves_cubie(&self, cubie: [usize; 3]) -> bool {
+        match self.moving {
+            Moving::All => true,
+            Moving::Layers { face, start, end } => {
+                let normal = face.normal();
+                let along: f64 = (0..3).map(|i| (cubie[i] as f64 + 0.5 - N as f64 / 2.0) * normal[i] as f64).sum();
+                let layer = (N as f64 / 2.0 + 0.5 - along).round() as usize;
+                (start..=end).contains(&layer)
+            }
+        }
+    }
+
+    /// Returns the transform of a cubie at parameter `t`.
+    ///
+    /// Cubies outside the turning layers stay in place.
+    pub fn cubie_transform(&self, cubie: [usize; 3], t: f64) -> RigidTransform {
+        if self.moves_cubie(cubie) { self.at(t) } else { RigidTransform::IDENTITY }
+    }
+
+    /// Returns the transform of a sticker at parameter `t`.
+    ///
+    /// A sticker moves rigidly with the cubie it is attached to.
+    pub fn sticker_transform(&self, tile: TilePos, t: f64) -> RigidTransform {
+        self.cubie_transform(tile.cubie::<N>(), t)
+    }
+
+    /// Returns the transform of every cubie on the surface of the cube at parameter `t`.
+    ///
+    /// Cubies are listed in `x`, then `y`, then `z` order; interior cubies, which carry
+    /// no stickers, are omitted.
+    pub fn cubie_transforms(&self, t: f64) -> Vec<([usize; 3], RigidTransform)> {
+        let mut transforms = Vec::new();
+        for x in 0..N {
+            for y in 0..N {
+                for z in 0..N {
+                    let cubie = [x, y, z];
+                    if cubie.iter().any(|&c| c == 0 || c == N - 1) {
+                        transforms.push((cubie, self.cubie_transform(cubie, t)));
+                    }
+                }
+            }
+        }
+        transforms
+    }
+
+    /// Returns the transform of every sticker at parameter `t`, face by face in
+    /// row-major order.
+    pub fn sticker_transforms(&self, t: f64) -> Vec<(TilePos, RigidTransform)> {
+        let mut transforms = Vec::with_capacity(6 * N * N);
+        for face in FACES {
+            for row in 0..N {
+                for col in 0..N {
+                    let tile = TilePos { face, row, col };
+                    transforms.push((tile, self.sticker_transform(tile, t)));
+                }
+            }
+        }
+        transforms
+    }
+}
+
+impl<const N: usize> From<&BasicMove<N>> for Motion<N> {
+    fn from(value: &BasicMove<N>) -> Self {
+        Self::turn(LayerTurn::from(*value))
+    }
+}
+
+impl<const N: usize> From<&WideMove<N>> for Motion<N> {
+    fn from(value: &WideMove<N>) -> Self {
+        Self::turn(LayerTurn::from(*value))
+    }
+}
+
+impl<const N: usize> From<&SliceMove<N>> for Motion<N> {
+    fn from(value: &SliceMove<N>) -> Self {
+        Self::turn(LayerTurn::from(*value))
+    }
+}
+
+impl<const N: usize> From<&RangeMove<N>> for Motion<N> {
+    fn from(value: &RangeMove<N>) -> Self {
+        Self::turn(LayerTurn::from(*value))
+    }
+}
+
+impl<const N: usize> From<&MiddleMove<N>> for Motion<N> {
+    /// Middle moves turn the central layer, layer `N/2 + 1` counted from their face.
+    fn from(value: &MiddleMove<N>) -> Self {
+        Self::turn(LayerTurn::from(*value))
+    }
+}
+
+impl<const N: usize> From<&CubeRotation> for Motion<N> {
+    fn from(value: &CubeRotation) -> Self {
+        Self::whole_cube(*value)
+    }
+}
+
+fn norm(v: Point3) -> f64 {
+    v.iter().map(|c| c * c).sum::<f64>().sqrt()
+}
+
+fn normalised(v: Point3) -> Point3 {
+    let length = norm(v);
+    v.map(|c| c / length)
+}
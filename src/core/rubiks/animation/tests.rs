@@ -0,0 +1,138 @@
+use std::f64::consts::PI;
+
+use super::*;
+use crate::core::cube::rotations::{X, X2, Y, Y2, Y3, Z, Z2};
+use crate::core::rubiks::moves::{BasicMove, MiddleMove, RangeMove, SliceMove, WideMove};
+use crate::core::rubiks::tiles::TilePerm;
+
+const EPSILON: f64 = 1e-9;
+
+fn close(a: Point3, b: Point3) -> bool {
+    (0..3).all(|i| (a[i] - b[i]).abs() < EPSILON)
+}
+
+/// Checks that at t = 1 every sticker centre lands on the centre of its image tile.
+fn assert_agrees_with_perm<const N: usize>(motion: Motion<N>, perm: TilePerm<N>, label: &str) {
+    for (tile, transform) in motion.sticker_transforms(1.0) {
+        let landed = transform.apply(tile.centre::<N>());
+        assert!(close(landed, perm[tile].centre::<N>()),
+            "{label}: {tile:?} lands at {landed:?}, expected {:?}", perm[tile]);
+    }
+}
+
+#[test]
+fn test_basic_moves_agree_with_tile_perms() {
+    use BasicMove::*;
+    for mv in [U, U2, U3, D, D2, D3, L, L2, L3, R, R2, R3, F, F2, F3, B, B2, B3] {
+        assert_agrees_with_perm(Motion::<3>::from(&mv), TilePerm::from(&mv), &format!("{mv:?}"));
+        let mv4 = match mv { U => BasicMove::<4>::U, R2 => BasicMove::<4>::R2, _ => continue };
+        assert_agrees_with_perm(Motion::<4>::from(&mv4), TilePerm::from(&mv4), &format!("{mv4:?}"));
+    }
+}
+
+#[test]
+fn test_layer_moves_agree_with_tile_perms() {
+    let wide = [WideMove::<5>::Uw(2), WideMove::Rw3(3), WideMove::Fw2(2), WideMove::Bw(4)];
+    for mv in wide {
+        assert_agrees_with_perm(Motion::from(&mv), TilePerm::from(&mv), &format!("{mv:?}"));
+    }
+    let slices = [SliceMove::<5>::Us(2), SliceMove::Ls3(3), SliceMove::Fs2(4), SliceMove::Ds(5)];
+    for mv in slices {
+        assert_agrees_with_perm(Motion::from(&mv), TilePerm::from(&mv), &format!("{mv:?}"));
+    }
+    let ranges = [RangeMove::<5>::Ur(2, 3), RangeMove::Br3(1, 4), RangeMove::Lr2(3, 5)];
+    for mv in ranges {
+        assert_agrees_with_perm(Motion::from(&mv), TilePerm::from(&mv), &format!("{mv:?}"));
+    }
+    for mv in [MiddleMove::<3>::M, MiddleMove::E2, MiddleMove::S3] {
+        assert_agrees_with_perm(Motion::from(&mv), TilePerm::from(&mv), &format!("{mv:?}"));
+    }
+    for mv in [MiddleMove::<5>::M3, MiddleMove::E, MiddleMove::S2] {
+        assert_agrees_with_perm(Motion::from(&mv), TilePerm::from(&mv), &format!("{mv:?}"));
+    }
+}
+
+#[test]
+fn test_rotations_agree_with_tile_perms() {
+    let rotations = [CubeRotation::ID, X, X2, Y, Y3, Z, Z2, X * Y, Y * Z, X * Y * Y, Z * X2];
+    for rotation in rotations {
+        assert_agrees_with_perm(Motion::<3>::from(&rotation), TilePerm::from(&rotation), &format!("{rotation:?}"));
+        assert_agrees_with_perm(Motion::<2>::from(&rotation), TilePerm::from(&rotation), &format!("{rotation:?}"));
+    }
+}
+
+#[test]
+fn test_rotation_angles() {
+    assert!((Motion::<3>::from(&X).at(1.0).angle.abs() - FRAC_PI_2).abs() < EPSILON);
+    assert!((Motion::<3>::from(&Y2).at(1.0).angle - PI).abs() < EPSILON);
+    // A rotation about a corner diagonal turns by 120°
+    let diagonal = Motion::<3>::from(&(X * Y)).at(1.0);
+    assert!((diagonal.angle - 2.0 * PI / 3.0).abs() < EPSILON);
+    assert!(diagonal.axis.iter().all(|c| (c.abs() - 1.0 / 3f64.sqrt()).abs() < EPSILON));
+    assert_eq!(Motion::<3>::from(&CubeRotation::ID).at(1.0).angle, 0.0);
+}
+
+#[test]
+fn test_only_turning_layers_move() {
+    let motion = Motion::<4>::from(&WideMove::<4>::Rw(2));
+    for (cubie, transform) in motion.cubie_transforms(0.5) {
+        assert_eq!(transform != RigidTransform::IDENTITY, cubie[0] >= 2, "cubie {cubie:?}");
+    }
+    assert_eq!(motion.cubie_transforms(0.5).len(), 64 - 8);
+
+    let motion = Motion::<3>::from(&MiddleMove::<3>::E);
+    for (cubie, _) in motion.cubie_transforms(0.3) {
+        assert_eq!(motion.moves_cubie(cubie), cubie[1] == 1);
+    }
+}
+
+#[test]
+fn test_interpolation() {
+    let motion = Motion::<3>::from(&BasicMove::<3>::R);
+    assert_eq!(motion.at(0.0).angle, 0.0);
+    for (tile, transform) in motion.sticker_transforms(0.0) {
+        assert!(close(transform.apply(tile.centre::<3>()), tile.centre::<3>()));
+    }
+
+    // Halfway through R the UFR sticker on Front, centred at (1, 1, 1.5), has turned
+    // 45° from Front towards Up about the x axis
+    let tile = TilePos { face: Face::Front, row: 0, col: 2 };
+    let halfway = motion.sticker_transform(tile, 0.5).apply(tile.centre::<3>());
+    let s = std::f64::consts::FRAC_1_SQRT_2;
+    assert!(close(halfway, [1.0, 2.5 * s, 0.5 * s]), "halfway position {halfway:?}");
+}
+
+#[test]
+fn test_matrix_and_quaternion_agree() {
+    let transform = RigidTransform { axis: [0.0, 0.6, 0.8], angle: 0.7 };
+    let [x, y, z, w] = transform.quaternion();
+    let m = transform.matrix();
+    // Rotation matrix from the quaternion
+    let q = [
+        [1.0 - 2.0 * (y * y + z * z), 2.0 * (x * y - z * w), 2.0 * (x * z + y * w)],
+        [2.0 * (x * y + z * w), 1.0 - 2.0 * (x * x + z * z), 2.0 * (y * z - x * w)],
+        [2.0 * (x * z - y * w), 2.0 * (y * z + x * w), 1.0 - 2.0 * (x * x + y * y)],
+    ];
+    for i in 0..3 {
+        assert!(close(m[i], q[i]));
+    }
+    assert!(close(RigidTransform::IDENTITY.apply([1.0, 2.0, 3.0]), [1.0, 2.0, 3.0]));
+}
+
+#[test]
+#[should_panic(expected = "a 4×4×4 cube has no middle layer")]
+fn test_even_middle_move_panics() {
+    let _ = Motion::from(&MiddleMove::<4>::M);
+}
+
+#[test]
+#[should_panic(expected = "layer 0 is outside a 3×3×3 cube")]
+fn test_out_of_range_layer_panics() {
+    let _ = Motion::from(&SliceMove::<3>::Rs(0));
+}
+
+#[test]
+#[should_panic(expected = "the layer range 3-2 is reversed")]
+fn test_reversed_range_panics() {
+    let _ = Motion::from(&RangeMove::<4>::Rr(3, 2));
+}
@@ -0,0 +1,185 @@
+//! Applying operations to many cube states across threads.
+//!
+//! Analytics jobs apply the same algorithms to millions of scrambles. A [`Batch`]
+//! splits a slice of states into one contiguous chunk per worker thread, runs every
+//! chunk on its own [scoped thread](std::thread::scope), and joins the results back
+//! in input order, so its output is exactly what the sequential loop would give.
+//!
+//! # Operations
+//!
+//! - [`Batch::apply`]: Each state after a sequence of operations
+//! - [`Batch::apply_each`]: Each state after each operation separately
+//! - [`Batch::count_solved_on`]: For each operation, how many states it leaves solved
+//!   on a [`Restriction`], without keeping the states
+//! - [`Batch::map`] and [`Batch::count`]: The general forms, for any per-item work
+//!
+//! Operations are anything that implements [`ApplyInPlace`], so moves, algorithms,
+//! rotations and permutations all work, and each worker applies them in place to its
+//! own copy of a state.
+//!
+//! # Example
+//!
+//! ```
+//! use rubiks_cube_representation::core::rubiks::algs::Alg;
+//! use rubiks_cube_representation::core::rubiks::batch::Batch;
+//! use rubiks_cube_representation::core::rubiks::moves::BasicMove;
+//! use rubiks_cube_representation::core::rubiks::tiles::restrictions::Slice;
+//! use rubiks_cube_representation::{Face, RubiksState, Western};
+//!
+//! let sexy: Alg<BasicMove<3>> = "R U R' U'".parse().unwrap();
+//! let scrambles: Vec<_> = (0..6)
+//!     .map(|n| {
+//!         let mut cube = RubiksState::<3>::solved_in(Western);
+//!         cube.apply_all(std::iter::repeat_n(&sexy, n));
+//!         cube
+//!     })
+//!     .collect();
+//!
+//! let batch = Batch::with_threads(4);
+//! // Undoing one repetition solves only the scramble that was one repetition
+//! let undone = batch.apply(&scrambles, &[sexy.inverse()]);
+//! assert!(undone[1].is_solved());
+//! assert_eq!(batch.count(&undone, RubiksState::is_solved), 1);
+//!
+//! // How many scrambles each algorithm leaves with the bottom layer solved
+//! let bottom = Slice { face: Face::Down, slice_index: 0 };
+//! let counts = batch.count_solved_on(&scrambles, &[sexy.clone(), sexy.inverse()], bottom, Western);
+//! let after = batch.apply_each(&scrambles, &[sexy.clone(), sexy.inverse()]);
+//! for (j, &count) in counts.iter().enumerate() {
+//!     assert_eq!(count, after.iter().filter(|cubes| cubes[j].is_solved_on_in(bottom, Western)).count());
+//! }
+//! ```
+
+use std::num::NonZeroUsize;
+use std::thread;
+
+use crate::core::cube::schemes::ColourScheme;
+use crate::core::rubiks::tiles::in_place::ApplyInPlace;
+use crate::core::rubiks::tiles::restrictions::Restriction;
+use crate::core::rubiks::tiles::TilePos;
+use crate::core::rubiks::RubiksState;
+
+#[cfg(test)]
+mod tests;
+
+/// A pool size for running work over slices on scoped threads.
+///
+/// See the [module documentation](self) for the available operations. Every method
+/// returns its results in input order whatever the number of threads.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Batch {
+    threads: NonZeroUsize,
+}
+
+impl Default for Batch {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Batch {
+    /// A batch using one thread per available core, as reported by
+    /// [`std::thread::available_parallelism`], or a single thread if that is unknown.
+    pub fn new() -> Self {
+        Batch { threads: thread::available_parallelism().unwrap_or(NonZeroUsize::MIN) }
+    }
+
+    /// A batch using a fixed number of threads, at least one.
+    pub fn with_threads(threads: usize) -> Self {
+        Batch { threads: NonZeroUsize::new(threads).unwrap_or(NonZeroUsize::MIN) }
+    }
+
+    /// The number of worker threads.
+    pub fn threads(&self) -> usize {
+        self.threads.get()
+    }
+
+    /// Runs `work` on one contiguous chunk of `items` per thread and returns the
+    /// chunks' results in order. A single chunk runs on the calling thread.
+    fn run_chunks<T: Sync, A: Send>(&self, items: &[T], work: impl Fn(&[T]) -> A + Sync) -> Vec<A> {
+        let size = items.len().div_ceil(self.threads()).max(1);
+        if items.len() <= size {
+            return vec![work(items)];
+        }
+        let work = &work;
+        thread::scope(|scope| {
+            let workers: Vec<_> = items.chunks(size).map(|chunk| scope.spawn(move || work(chunk))).collect();
+            workers
+                .into_iter()
+                .map(|worker| worker.join().unwrap_or_else(|panic| std::panic::resume_unwind(panic)))
+                .collect()
+        })
+    }
+
+    /// Computes `f` of every item across the threads, in input order.
+    pub fn map<T: Sync, R: Send>(&self, items: &[T], f: impl Fn(&T) -> R + Sync) -> Vec<R> {
+        self.run_chunks(items, |chunk| chunk.iter().map(&f).collect::<Vec<_>>()).into_iter().flatten().collect()
+    }
+
+    /// Counts the items satisfying a predicate across the threads.
+    pub fn count<T: Sync>(&self, items: &[T], predicate: impl Fn(&T) -> bool + Sync) -> usize {
+        self.run_chunks(items, |chunk| chunk.iter().filter(|&item| predicate(item)).count()).into_iter().sum()
+    }
+
+    /// Applies the operations in sequence to every state, returning the results in
+    /// input order.
+    pub fn apply<const N: usize, Op: ApplyInPlace<N> + Sync>(&self, states: &[RubiksState<N>], ops: &[Op]) -> Vec<RubiksState<N>> {
+        self.map(states, |state| {
+            let mut state = state.clone();
+            state.apply_all(ops);
+            state
+        })
+    }
+
+    /// Applies each operation separately to every state: entry `[i][j]` is state `i`
+    /// after operation `j`.
+    pub fn apply_each<const N: usize, Op: ApplyInPlace<N> + Sync>(&self, states: &[RubiksState<N>], ops: &[Op]) -> Vec<Vec<RubiksState<N>>> {
+        self.map(states, |state| {
+            ops.iter()
+                .map(|op| {
+                    let mut state = state.clone();
+                    state.apply(op);
+                    state
+                })
+                .collect()
+        })
+    }
+
+    /// For each operation, counts the states that it leaves solved on a restriction,
+    /// in the sense of [`RubiksState::is_solved_on_in`].
+    ///
+    /// No resulting states are kept: each thread returns only its counts.
+    pub fn count_solved_on<const N: usize, Op, T, Scheme>(
+        &self,
+        states: &[RubiksState<N>],
+        ops: &[Op],
+        restriction: T,
+        scheme: Scheme,
+    ) -> Vec<usize>
+    where
+        Op: ApplyInPlace<N> + Sync,
+        T: Restriction<N>,
+        Scheme: ColourScheme + Sync,
+    {
+        // Collect the restriction once rather than once per state
+        let tiles: Vec<TilePos> = restriction.restricted_positions().collect();
+        let solved = |state: &RubiksState<N>| tiles.iter().all(|&tile| state[tile] == scheme.from_face(tile.face));
+        let chunks = self.run_chunks(states, |chunk| {
+            let mut counts = vec![0; ops.len()];
+            for state in chunk {
+                for (count, op) in counts.iter_mut().zip(ops) {
+                    let mut scratch = state.clone();
+                    scratch.apply(op);
+                    *count += usize::from(solved(&scratch));
+                }
+            }
+            counts
+        });
+        chunks.into_iter().fold(vec![0; ops.len()], |mut total, counts| {
+            for (total, count) in total.iter_mut().zip(counts) {
+                *total += count;
+            }
+            total
+        })
+    }
+}
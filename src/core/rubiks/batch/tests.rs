@@ -0,0 +1,87 @@
+use super::*;
+use crate::core::rubiks::algs::Alg;
+use crate::core::rubiks::moves::BasicMove;
+use crate::core::rubiks::tiles::restrictions::Slice;
+use crate::core::cube::schemes::Japanese;
+use crate::core::rubiks::tiles::TilePerm;
+use crate::{Face, Western};
+
+/// States reached by successive moves of a fixed sequence, all distinct enough to
+/// catch reordering.
+fn scrambles(count: usize) -> Vec<RubiksState<3>> {
+    let mut cube = RubiksState::<3>::solved_in(Western);
+    (0..count)
+        .map(|i| {
+            cube.apply(BasicMove::<3>::ALL[i * 7 % BasicMove::<3>::ALL.len()]);
+            cube.clone()
+        })
+        .collect()
+}
+
+#[test]
+fn test_threads() {
+    assert_eq!(Batch::with_threads(0).threads(), 1);
+    assert_eq!(Batch::with_threads(5).threads(), 5);
+    assert!(Batch::new().threads() >= 1);
+    assert_eq!(Batch::default(), Batch::new());
+}
+
+#[test]
+fn test_apply_matches_sequential() {
+    let alg: Alg<BasicMove<3>> = "R U R' U' F2".parse().unwrap();
+    for count in [0, 1, 2, 7, 40] {
+        let states = scrambles(count);
+        let expected: Vec<_> = states.iter().map(|state| state.clone() * &alg * &BasicMove::D).collect();
+        for threads in [1, 3, 64] {
+            let batch = Batch::with_threads(threads);
+            let ops = [TilePerm::from(&alg), TilePerm::from(&BasicMove::D)];
+            assert_eq!(batch.apply(&states, &ops), expected, "{count} states on {threads} threads");
+        }
+    }
+}
+
+#[test]
+fn test_apply_each() {
+    let states = scrambles(10);
+    let ops = [BasicMove::<3>::R, BasicMove::U2, BasicMove::F3];
+    let each = Batch::with_threads(4).apply_each(&states, &ops);
+    assert_eq!(each.len(), states.len());
+    for (state, results) in states.iter().zip(&each) {
+        let expected: Vec<_> = ops.iter().map(|op| state * op).collect();
+        assert_eq!(results, &expected);
+    }
+}
+
+#[test]
+fn test_map_and_count() {
+    let numbers: Vec<usize> = (0..1000).collect();
+    let batch = Batch::with_threads(6);
+    assert_eq!(batch.map(&numbers, |n| n * n), numbers.iter().map(|n| n * n).collect::<Vec<_>>());
+    assert_eq!(batch.count(&numbers, |n| n % 3 == 0), 334);
+    assert_eq!(batch.count(&[] as &[usize], |_| true), 0);
+}
+
+#[test]
+fn test_count_solved_on_matches_sequential() {
+    let states = scrambles(30);
+    let ops = [BasicMove::<3>::U, BasicMove::R, BasicMove::R3, BasicMove::D2];
+    let bottom = Slice { face: Face::Down, slice_index: 0 };
+    let expected: Vec<usize> = ops
+        .iter()
+        .map(|op| states.iter().filter(|&state| (state * op).is_solved_on_in(bottom, Western)).count())
+        .collect();
+    assert!(expected.iter().any(|&count| count > 0));
+    for threads in [1, 4, 100] {
+        assert_eq!(Batch::with_threads(threads).count_solved_on(&states, &ops, bottom, Western), expected);
+    }
+    assert_eq!(Batch::new().count_solved_on(&[], &ops, bottom, Western), vec![0; ops.len()]);
+}
+
+#[test]
+fn test_is_solved_on_in() {
+    let cube = RubiksState::<3>::solved_in(Western) * &BasicMove::U;
+    assert!(cube.is_solved_on_in(Slice { face: Face::Down, slice_index: 0 }, Western));
+    assert!(cube.is_solved_on_in(Slice { face: Face::Down, slice_index: 1 }, Western));
+    assert!(!cube.is_solved_on_in(Slice { face: Face::Up, slice_index: 0 }, Western));
+    assert!(!cube.is_solved_on_in(Slice { face: Face::Down, slice_index: 0 }, Japanese));
+}
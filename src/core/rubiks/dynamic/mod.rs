@@ -0,0 +1,544 @@
+//! Cubes whose size is chosen at run time.
+//!
+//! The rest of the crate fixes the cube size as a const generic, which is fast and
+//! catches size mismatches at compile time, but forces a program that lets its user
+//! pick N to monomorphise every operation for every size it supports. The types here
+//! carry N as a value instead:
+//!
+//! - [`DynTilePerm`]: A tile permutation of an N×N×N cube, like [`TilePerm<N>`]
+//! - [`DynMove`]: Any of the five move families, with its layers checked against N only
+//!   when it is applied
+//! - [`DynRubiksState`]: The colours of an N×N×N cube, like [`RubiksState<N>`]
+//!
+//! Each converts losslessly from its const-generic counterpart, and back with
+//! [`TryFrom`] when the sizes agree.
+//!
+//! # Geometry
+//!
+//! Moves and rotations are computed from 3D geometry by the same arithmetic as the
+//! const-generic types, in the [`motion`](super::tiles::motion) module, which takes N
+//! as a value. Tile centres are kept in *doubled* coordinates, so that every centre of
+//! an N×N×N cube centred on the origin has integer coordinates; turning a layer
+//! rotates these points exactly, and the rotated point names the destination tile.
+//!
+//! # Examples
+//!
+//! ```
+//! use rubiks_cube_representation::core::rubiks::dynamic::{DynMove, DynRubiksState};
+//! use rubiks_cube_representation::core::rubiks::RubiksState;
+//! use rubiks_cube_representation::Western;
+//!
+//! // The size comes from user input
+//! let n: usize = "4".parse().unwrap();
+//! let mut cube = DynRubiksState::solved_in(n, Western);
+//! for text in ["Rw", "U", "2F'"] {
+//!     let mv: DynMove = text.parse().unwrap();
+//!     cube = cube.apply(&mv).unwrap();
+//! }
+//! assert!(!cube.is_solved());
+//!
+//! // Layers are checked against the size when a move is applied
+//! assert!(cube.apply(&"5R".parse().unwrap()).is_err());
+//!
+//! // Convert to the const-generic type once N is known statically
+//! let fixed = RubiksState::<4>::try_from(&cube).unwrap();
+//! assert!(RubiksState::<3>::try_from(&cube).is_err());
+//! assert_eq!(DynRubiksState::from(&fixed), cube);
+//! ```
+
+use std::ops::{Index, Mul};
+
+use crate::core::cube::geometry::{Face, FACES};
+use crate::core::cube::rotations::CubeRotation;
+use crate::core::cube::schemes::{ColourPerm, ColourScheme};
+use crate::core::rubiks::moves::{BasicMove, BasicMoveInternal, MiddleMove, MiddleMoveInternal, RangeMove,
+    RangeMoveInternal, SliceMove, SliceMoveInternal, WideMove, WideMoveInternal};
+use crate::core::rubiks::tiles::motion::{rotated_tile, LayerTurn};
+use crate::core::rubiks::tiles::{TilePerm, TilePos};
+use crate::core::rubiks::RubiksState;
+use crate::core::{Angle, Colour};
+use crate::error::Error;
+
+#[cfg(test)]
+mod tests;
+
+/// The flat index of a tile on an N×N×N cube, as [`TilePos::index`] with N known at
+/// run time.
+fn index_in(tile: TilePos, n: usize) -> usize {
+    (tile.face as usize * n + tile.row) * n + tile.col
+}
+
+/// The tile with flat index `index` on an N×N×N cube.
+fn tile_in(index: usize, n: usize) -> TilePos {
+    TilePos { face: FACES[index / (n * n)], row: index / n % n, col: index % n }
+}
+
+/// Whether a tile position lies on an N×N×N cube.
+fn on_cube(tile: TilePos, n: usize) -> bool {
+    tile.row < n && tile.col < n
+}
+
+/// A permutation of the tiles of an N×N×N cube, with N chosen at run time.
+///
+/// This is the run-time counterpart of [`TilePerm<N>`]: indexing with a [`TilePos`]
+/// gives the tile's destination, and `a * b` means "apply `a`, then apply `b`".
+///
+/// # Panics
+///
+/// Composing permutations of different sizes panics, as does indexing with a tile
+/// outside the cube.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct DynTilePerm {
+    n: usize,
+    /// Destination of each tile, by flat index
+    images: Vec<TilePos>,
+}
+
+impl DynTilePerm {
+    /// The identity permutation of an N×N×N cube.
+    pub fn identity(n: usize) -> Self {
+        DynTilePerm { n, images: (0..6 * n * n).map(|index| tile_in(index, n)).collect() }
+    }
+
+    /// Builds a permutation from the destination of each tile, listed in flat index
+    /// order (see [`TilePos::index`]).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::WrongImageCount`] if there are not `6·N²` destinations,
+    /// [`Error::TileOutOfRange`] if a destination lies off the cube, or
+    /// [`Error::DuplicateImage`] if two tiles share one.
+    pub fn from_images(n: usize, images: Vec<TilePos>) -> Result<Self, Error> {
+        if images.len() != 6 * n * n {
+            return Err(Error::WrongImageCount { expected: 6 * n * n, found: images.len() });
+        }
+        let mut hit = vec![false; images.len()];
+        for &image in &images {
+            if !on_cube(image, n) {
+                return Err(Error::TileOutOfRange { tile: image, n });
+            }
+            let index = index_in(image, n);
+            if std::mem::replace(&mut hit[index], true) {
+                return Err(Error::DuplicateImage { index });
+            }
+        }
+        Ok(DynTilePerm { n, images })
+    }
+
+    /// Builds a permutation from the image of every tile.
+    fn from_image(n: usize, image: impl Fn(TilePos) -> TilePos) -> Self {
+        DynTilePerm { n, images: (0..6 * n * n).map(|index| image(tile_in(index, n))).collect() }
+    }
+
+    /// The permutation of a whole-cube rotation.
+    pub fn rotation(n: usize, rotation: CubeRotation) -> Self {
+        let matrix = rotation.matrix();
+        DynTilePerm::from_image(n, |tile| rotated_tile(tile, n, matrix))
+    }
+
+    /// The cube size N.
+    pub fn n(&self) -> usize {
+        self.n
+    }
+
+    /// Computes the inverse permutation.
+    pub fn inverse(&self) -> Self {
+        let mut images = self.images.clone();
+        for (index, &image) in self.images.iter().enumerate() {
+            images[index_in(image, self.n)] = tile_in(index, self.n);
+        }
+        DynTilePerm { n: self.n, images }
+    }
+}
+
+impl Index<TilePos> for DynTilePerm {
+    type Output = TilePos;
+
+    fn index(&self, tile: TilePos) -> &TilePos {
+        assert!(on_cube(tile, self.n), "{tile:?} is not a tile of a {0}×{0}×{0} cube", self.n);
+        &self.images[index_in(tile, self.n)]
+    }
+}
+
+impl Mul<&DynTilePerm> for &DynTilePerm {
+    type Output = DynTilePerm;
+
+    /// Applies `self`, then `rhs`.
+    fn mul(self, rhs: &DynTilePerm) -> DynTilePerm {
+        assert_eq!(self.n, rhs.n, "cannot compose permutations of different cube sizes");
+        DynTilePerm { n: self.n, images: self.images.iter().map(|&tile| rhs[tile]).collect() }
+    }
+}
+
+impl Mul<&DynTilePerm> for DynTilePerm {
+    type Output = DynTilePerm;
+
+    fn mul(self, rhs: &DynTilePerm) -> DynTilePerm {
+        &self * rhs
+    }
+}
+
+impl<const N: usize> From<&TilePerm<N>> for DynTilePerm {
+    fn from(perm: &TilePerm<N>) -> Self {
+        DynTilePerm { n: N, images: TilePos::all::<N>().map(|tile| perm[tile]).collect() }
+    }
+}
+
+impl<const N: usize> From<TilePerm<N>> for DynTilePerm {
+    fn from(perm: TilePerm<N>) -> Self {
+        Self::from(&perm)
+    }
+}
+
+impl<const N: usize> TryFrom<&DynTilePerm> for TilePerm<N> {
+    type Error = Error;
+
+    /// Fails if the permutation is not of an N×N×N cube.
+    fn try_from(perm: &DynTilePerm) -> Result<Self, Self::Error> {
+        if perm.n != N {
+            return Err(Error::SizeMismatch { expected: N, found: perm.n });
+        }
+        Ok(TilePerm::from_fn(|tile| perm.images[tile.index::<N>()]))
+    }
+}
+
+/// A move of any family, with the cube size left open.
+///
+/// Layer numbers start at 1 on the turned face, as for the const-generic moves. They
+/// are checked against N only when the move is applied, by [`DynMove::tile_perm`].
+/// Moves print and parse in the same notation as the const-generic move types
+/// (`R'`, `3Rw2`, `2R`, `2-3Rw`, `M'`), and a turn by [`Angle::Zero`], which no
+/// const-generic move represents, as `R0`. Converting such a turn to a const-generic
+/// move with [`TryFrom`] returns [`Error::ZeroTurn`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum DynMove {
+    /// An outer layer turn, as [`BasicMove`]
+    Basic { face: Face, amount: Angle },
+    /// The outer `depth` layers, as [`WideMove`]
+    Wide { face: Face, amount: Angle, depth: usize },
+    /// A single layer, as [`SliceMove`]
+    Slice { face: Face, amount: Angle, layer: usize },
+    /// The layers `start..=end`, as [`RangeMove`]
+    Range { face: Face, amount: Angle, start: usize, end: usize },
+    /// The central layer of an odd cube, as [`MiddleMove`], turning like its
+    /// reference face: Left for M, Down for E and Front for S
+    Middle { face: Face, amount: Angle },
+}
+
+impl DynMove {
+    /// The face this move turns like.
+    pub fn face(self) -> Face {
+        match self {
+            DynMove::Basic { face, .. }
+            | DynMove::Wide { face, .. }
+            | DynMove::Slice { face, .. }
+            | DynMove::Range { face, .. }
+            | DynMove::Middle { face, .. } => face,
+        }
+    }
+
+    /// The amount this move turns by.
+    pub fn amount(self) -> Angle {
+        match self {
+            DynMove::Basic { amount, .. }
+            | DynMove::Wide { amount, .. }
+            | DynMove::Slice { amount, .. }
+            | DynMove::Range { amount, .. }
+            | DynMove::Middle { amount, .. } => amount,
+        }
+    }
+
+    /// The layers turned on an N×N×N cube, numbered from 1 at [`DynMove::face`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidLayer`] if a layer lies outside the cube,
+    /// [`Error::ReversedLayers`] if a range is reversed, or [`Error::NoMiddleLayer`] if
+    /// a middle move is applied to an even cube.
+    pub fn layers(self, n: usize) -> Result<(usize, usize), Error> {
+        let (start, end) = match self {
+            DynMove::Basic { .. } => (1, 1),
+            DynMove::Wide { depth, .. } => (1, depth),
+            DynMove::Slice { layer, .. } => (layer, layer),
+            DynMove::Range { start, end, .. } => (start, end),
+            DynMove::Middle { .. } if n.is_multiple_of(2) => {
+                return Err(Error::NoMiddleLayer { n });
+            }
+            DynMove::Middle { .. } => (n.div_ceil(2), n.div_ceil(2)),
+        };
+        if let Some(layer) = [start, end].into_iter().find(|&layer| layer == 0 || layer > n) {
+            return Err(Error::InvalidLayer { layer, n });
+        }
+        if start > end {
+            return Err(Error::ReversedLayers { start, end });
+        }
+        Ok((start, end))
+    }
+
+    /// The permutation this move performs on an N×N×N cube.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the move's layers do not fit the cube; see
+    /// [`DynMove::layers`].
+    pub fn tile_perm(self, n: usize) -> Result<DynTilePerm, Error> {
+        let turn = LayerTurn::try_new(self, n)?;
+        Ok(DynTilePerm::from_image(n, |tile| turn.image(tile, n)))
+    }
+}
+
+impl<const N: usize> From<BasicMove<N>> for DynMove {
+    fn from(mv: BasicMove<N>) -> Self {
+        let BasicMoveInternal { face, amount } = BasicMoveInternal::from(mv);
+        DynMove::Basic { face, amount }
+    }
+}
+
+impl<const N: usize> From<WideMove<N>> for DynMove {
+    fn from(mv: WideMove<N>) -> Self {
+        let WideMoveInternal { face, amount, depth } = WideMoveInternal::from(mv);
+        DynMove::Wide { face, amount, depth }
+    }
+}
+
+impl<const N: usize> From<SliceMove<N>> for DynMove {
+    fn from(mv: SliceMove<N>) -> Self {
+        let SliceMoveInternal { face, amount, layer } = SliceMoveInternal::from(mv);
+        DynMove::Slice { face, amount, layer }
+    }
+}
+
+impl<const N: usize> From<RangeMove<N>> for DynMove {
+    fn from(mv: RangeMove<N>) -> Self {
+        let RangeMoveInternal { face, amount, start_layer, end_layer } = RangeMoveInternal::from(mv);
+        DynMove::Range { face, amount, start: start_layer, end: end_layer }
+    }
+}
+
+impl<const N: usize> From<MiddleMove<N>> for DynMove {
+    fn from(mv: MiddleMove<N>) -> Self {
+        let MiddleMoveInternal { face, amount } = MiddleMoveInternal::from(mv);
+        DynMove::Middle { face, amount }
+    }
+}
+
+impl<const N: usize> TryFrom<DynMove> for BasicMove<N> {
+    type Error = Error;
+
+    fn try_from(mv: DynMove) -> Result<Self, Self::Error> {
+        match mv {
+            DynMove::Basic { amount: Angle::Zero, .. } => Err(Error::ZeroTurn { mv }),
+            DynMove::Basic { face, amount } => Ok(BasicMove::from_parts(face, amount)),
+            _ => Err(Error::WrongMoveFamily { mv, family: "basic" }),
+        }
+    }
+}
+
+impl<const N: usize> TryFrom<DynMove> for WideMove<N> {
+    type Error = Error;
+
+    fn try_from(mv: DynMove) -> Result<Self, Self::Error> {
+        match mv {
+            DynMove::Wide { amount: Angle::Zero, .. } => Err(Error::ZeroTurn { mv }),
+            DynMove::Wide { face, amount, depth } => {
+                mv.layers(N)?;
+                Ok(WideMove::from_parts(face, amount, depth))
+            }
+            _ => Err(Error::WrongMoveFamily { mv, family: "wide" }),
+        }
+    }
+}
+
+impl<const N: usize> TryFrom<DynMove> for SliceMove<N> {
+    type Error = Error;
+
+    fn try_from(mv: DynMove) -> Result<Self, Self::Error> {
+        match mv {
+            DynMove::Slice { amount: Angle::Zero, .. } => Err(Error::ZeroTurn { mv }),
+            DynMove::Slice { face, amount, layer } => {
+                mv.layers(N)?;
+                Ok(SliceMove::from_parts(face, amount, layer))
+            }
+            _ => Err(Error::WrongMoveFamily { mv, family: "slice" }),
+        }
+    }
+}
+
+impl<const N: usize> TryFrom<DynMove> for RangeMove<N> {
+    type Error = Error;
+
+    fn try_from(mv: DynMove) -> Result<Self, Self::Error> {
+        match mv {
+            DynMove::Range { amount: Angle::Zero, .. } => Err(Error::ZeroTurn { mv }),
+            DynMove::Range { face, amount, start, end } => {
+                mv.layers(N)?;
+                Ok(RangeMove::from_parts(face, amount, start, end))
+            }
+            _ => Err(Error::WrongMoveFamily { mv, family: "range" }),
+        }
+    }
+}
+
+impl<const N: usize> TryFrom<DynMove> for MiddleMove<N> {
+    type Error = Error;
+
+    fn try_from(mv: DynMove) -> Result<Self, Self::Error> {
+        match mv {
+            DynMove::Middle { amount: Angle::Zero, .. } => Err(Error::ZeroTurn { mv }),
+            DynMove::Middle { face, amount } => {
+                mv.layers(N)?;
+                Ok(MiddleMove::from_parts(face, amount))
+            }
+            _ => Err(Error::WrongMoveFamily { mv, family: "middle" }),
+        }
+    }
+}
+
+/// The colours of an N×N×N cube, with N chosen at run time.
+///
+/// This is the run-time counterpart of [`RubiksState<N>`]. Applying an operation with
+/// `*` moves the sticker on each tile to the tile's image, as for `RubiksState<N>`.
+///
+/// # Panics
+///
+/// Applying a permutation of a different size panics, as does indexing with a tile
+/// outside the cube; [`DynRubiksState::try_permute`] and [`DynRubiksState::try_get`]
+/// report them as errors instead. [`DynRubiksState::apply`] reports invalid moves as
+/// errors.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct DynRubiksState {
+    n: usize,
+    /// The colour on each tile, by flat index
+    colours: Vec<Colour>,
+}
+
+impl DynRubiksState {
+    /// The solved N×N×N cube in a colour scheme.
+    pub fn solved_in<Scheme: ColourScheme>(n: usize, scheme: Scheme) -> Self {
+        let colours = FACES.iter().flat_map(|&face| std::iter::repeat_n(scheme.from_face(face), n * n)).collect();
+        DynRubiksState { n, colours }
+    }
+
+    /// The cube size N.
+    pub fn n(&self) -> usize {
+        self.n
+    }
+
+    /// The colours on a face, row by row from its principal corner.
+    pub fn face_colours(&self, face: Face) -> &[Colour] {
+        let size = self.n * self.n;
+        &self.colours[face as usize * size..(face as usize + 1) * size]
+    }
+
+    /// The colour on a tile, failing instead of panicking if the tile lies off the cube.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::TileOutOfRange`] if `row >= N` or `col >= N`.
+    pub fn try_get(&self, tile: TilePos) -> Result<Colour, Error> {
+        if on_cube(tile, self.n) {
+            Ok(self.colours[index_in(tile, self.n)])
+        } else {
+            Err(Error::TileOutOfRange { tile, n: self.n })
+        }
+    }
+
+    /// Applies a permutation, as `*` does, failing instead of panicking if it is a
+    /// permutation of a different size.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::SizeMismatch`] if the permutation is not of an N×N×N cube.
+    pub fn try_permute(&self, perm: &DynTilePerm) -> Result<Self, Error> {
+        if perm.n != self.n {
+            return Err(Error::SizeMismatch { expected: self.n, found: perm.n });
+        }
+        Ok(self * perm)
+    }
+
+    /// Checks whether every face shows its colour in `scheme`.
+    pub fn is_solved_in<Scheme: ColourScheme>(&self, scheme: Scheme) -> bool {
+        FACES.iter().all(|&face| self.face_colours(face).iter().all(|&colour| colour == scheme.from_face(face)))
+    }
+
+    /// Checks whether every face shows a single colour.
+    pub fn is_solved(&self) -> bool {
+        if self.n == 0 {
+            return true;
+        }
+        let [up, down, left, right, front, back] = FACES.map(|face| self.face_colours(face)[0]);
+        self.is_solved_in(ColourPerm { up, down, left, right, front, back })
+    }
+
+    /// Checks whether the cube is solved in `scheme` after some whole-cube rotation.
+    ///
+    /// Every one of the 24 rotations is tried, so this also works on even cubes, which
+    /// have no fixed centres to read the orientation from.
+    pub fn is_solved_up_to_rotation_in<Scheme: ColourScheme + Copy>(&self, scheme: Scheme) -> bool {
+        CubeRotation::all().into_iter().any(|rotation| (self * &rotation).is_solved_in(scheme))
+    }
+
+    /// Applies a move.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the move's layers do not fit the cube; see
+    /// [`DynMove::layers`].
+    pub fn apply(&self, mv: &DynMove) -> Result<Self, Error> {
+        Ok(self * &mv.tile_perm(self.n)?)
+    }
+}
+
+impl Index<TilePos> for DynRubiksState {
+    type Output = Colour;
+
+    fn index(&self, tile: TilePos) -> &Colour {
+        assert!(on_cube(tile, self.n), "{tile:?} is not a tile of a {0}×{0}×{0} cube", self.n);
+        &self.colours[index_in(tile, self.n)]
+    }
+}
+
+impl Mul<&DynTilePerm> for &DynRubiksState {
+    type Output = DynRubiksState;
+
+    fn mul(self, perm: &DynTilePerm) -> DynRubiksState {
+        assert_eq!(self.n, perm.n, "cannot apply a permutation of a different cube size");
+        let mut colours = self.colours.clone();
+        for (index, &image) in perm.images.iter().enumerate() {
+            colours[index_in(image, self.n)] = self.colours[index];
+        }
+        DynRubiksState { n: self.n, colours }
+    }
+}
+
+impl Mul<&CubeRotation> for &DynRubiksState {
+    type Output = DynRubiksState;
+
+    fn mul(self, rotation: &CubeRotation) -> DynRubiksState {
+        self * &DynTilePerm::rotation(self.n, *rotation)
+    }
+}
+
+impl<const N: usize> From<&RubiksState<N>> for DynRubiksState {
+    fn from(state: &RubiksState<N>) -> Self {
+        DynRubiksState { n: N, colours: TilePos::all::<N>().map(|tile| state[tile]).collect() }
+    }
+}
+
+impl<const N: usize> From<RubiksState<N>> for DynRubiksState {
+    fn from(state: RubiksState<N>) -> Self {
+        Self::from(&state)
+    }
+}
+
+impl<const N: usize> TryFrom<&DynRubiksState> for RubiksState<N> {
+    type Error = Error;
+
+    /// Fails if the state is not of an N×N×N cube.
+    fn try_from(state: &DynRubiksState) -> Result<Self, Self::Error> {
+        if state.n != N {
+            return Err(Error::SizeMismatch { expected: N, found: state.n });
+        }
+        Ok(RubiksState::from_fn(|tile| state[tile]))
+    }
+}
@@ -0,0 +1,124 @@
+use super::*;
+use crate::core::rubiks::test_support::{check_all_moves, reference_rotation, reference_turn, Layers, Move, MoveCheck};
+use crate::core::cube::rotations::{X, Y, Z};
+use crate::Western;
+
+/// Checks that a move's run-time permutation matches the reference permutation of its
+/// layers, and its const-generic one.
+struct MatchesReference;
+
+impl<const N: usize> MoveCheck<N> for MatchesReference {
+    fn check<M: Move<N>>(&mut self, mv: M, layers: Layers) {
+        let dynamic: DynMove = mv.into();
+        let expected = DynTilePerm::from(&reference_turn::<N>(layers));
+        assert_eq!(dynamic.tile_perm(N), Ok(expected.clone()), "{dynamic}");
+        assert_eq!(DynTilePerm::from(&mv.into()), expected, "{dynamic}");
+    }
+}
+
+#[test]
+fn test_moves_match_const_generic_moves() {
+    check_all_moves::<1>(&mut MatchesReference);
+    check_all_moves::<2>(&mut MatchesReference);
+    check_all_moves::<3>(&mut MatchesReference);
+    check_all_moves::<4>(&mut MatchesReference);
+    check_all_moves::<5>(&mut MatchesReference);
+}
+
+#[test]
+fn test_rotations_match_const_generic_rotations() {
+    for rotation in CubeRotation::all() {
+        assert_eq!(DynTilePerm::rotation(3, rotation), DynTilePerm::from(reference_rotation::<3>(rotation)));
+        assert_eq!(DynTilePerm::rotation(4, rotation), DynTilePerm::from(reference_rotation::<4>(rotation)));
+    }
+}
+
+#[test]
+fn test_perm_algebra() {
+    let r = DynMove::Basic { face: Face::Right, amount: Angle::CWQuarter }.tile_perm(4).unwrap();
+    let id = DynTilePerm::identity(4);
+    assert_eq!(&r * &r.inverse(), id);
+    assert_eq!(&(&r * &r) * &(&r * &r), id);
+    assert_eq!(id.n(), 4);
+
+    let fixed: TilePerm<4> = BasicMove::R.into();
+    assert_eq!(TilePerm::<4>::try_from(&r), Ok(fixed));
+    assert!(TilePerm::<3>::try_from(&r).is_err());
+}
+
+#[test]
+fn test_from_images_validates() {
+    let images: Vec<TilePos> = TilePos::all::<2>().collect();
+    assert_eq!(DynTilePerm::from_images(2, images.clone()), Ok(DynTilePerm::identity(2)));
+    assert!(DynTilePerm::from_images(3, images.clone()).is_err());
+
+    let mut repeated = images.clone();
+    repeated[1] = repeated[0];
+    assert!(DynTilePerm::from_images(2, repeated).is_err());
+
+    let mut off_cube = images;
+    off_cube[0].row = 2;
+    assert!(DynTilePerm::from_images(2, off_cube).is_err());
+}
+
+#[test]
+#[should_panic(expected = "different cube sizes")]
+fn test_composing_mismatched_sizes_panics() {
+    let _ = &DynTilePerm::identity(3) * &DynTilePerm::identity(4);
+}
+
+#[test]
+fn test_layer_validation() {
+    let r = |layer| DynMove::Slice { face: Face::Right, amount: Angle::Half, layer };
+    assert!(r(3).tile_perm(3).is_ok());
+    assert!(r(4).tile_perm(3).is_err());
+    assert!(r(0).tile_perm(3).is_err());
+    let reversed = DynMove::Range { face: Face::Up, amount: Angle::Half, start: 3, end: 2 };
+    assert!(reversed.tile_perm(4).is_err());
+    let m = DynMove::Middle { face: Face::Left, amount: Angle::CWQuarter };
+    assert_eq!(m.layers(5), Ok((3, 3)));
+    assert!(m.tile_perm(4).is_err());
+}
+
+#[test]
+fn test_move_conversions() {
+    let mv = DynMove::from(WideMove::<5>::Rw3(3));
+    assert_eq!(mv, DynMove::Wide { face: Face::Right, amount: Angle::ACWQuarter, depth: 3 });
+    assert_eq!(WideMove::<5>::try_from(mv), Ok(WideMove::Rw3(3)));
+    assert!(WideMove::<2>::try_from(mv).is_err());
+    assert!(SliceMove::<5>::try_from(mv).is_err());
+    assert!(MiddleMove::<4>::try_from(DynMove::from(MiddleMove::<3>::M)).is_err());
+    assert_eq!(RangeMove::<4>::try_from(DynMove::from(RangeMove::<4>::Ur(2, 3))), Ok(RangeMove::Ur(2, 3)));
+}
+
+#[test]
+fn test_state_matches_const_generic_state() {
+    let moves: [DynMove; 4] = ["Rw", "U'", "2F2", "3-4Lw"].map(|text| text.parse().unwrap());
+    let mut dynamic = DynRubiksState::solved_in(4, Western);
+    let mut fixed = RubiksState::<4>::solved_in(Western);
+    for mv in moves {
+        dynamic = dynamic.apply(&mv).unwrap();
+        fixed = &fixed * TilePerm::<4>::try_from(&mv.tile_perm(4).unwrap()).unwrap();
+    }
+    assert_eq!(DynRubiksState::from(&fixed), dynamic);
+    assert_eq!(RubiksState::<4>::try_from(&dynamic), Ok(fixed));
+    assert!(RubiksState::<3>::try_from(&dynamic).is_err());
+    assert!(!dynamic.is_solved());
+}
+
+#[test]
+fn test_solved_checks() {
+    let solved = DynRubiksState::solved_in(6, Western);
+    assert_eq!(solved.n(), 6);
+    assert!(solved.is_solved() && solved.is_solved_in(Western));
+    assert!(solved.face_colours(Face::Up).iter().all(|&colour| colour == Western.up()));
+
+    let rotated = &solved * &(X * Y * Z);
+    assert!(rotated.is_solved());
+    assert!(!rotated.is_solved_in(Western));
+    assert!(rotated.is_solved_up_to_rotation_in(Western));
+
+    let turned = solved.apply(&"3Rw".parse().unwrap()).unwrap();
+    assert!(!turned.is_solved_up_to_rotation_in(Western));
+    assert!(DynRubiksState::solved_in(0, Western).is_solved());
+}
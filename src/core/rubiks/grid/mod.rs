@@ -0,0 +1,163 @@
+//! Storage for the N×N grids that make up tile permutations and cube states.
+//!
+//! A [`TilePerm`](super::tiles::TilePerm) holds six grids of [`TilePos`](super::tiles::TilePos),
+//! one per face. Stored inline, a `TilePerm<101>` is about 1.5 MB, which overflows the
+//! stack the moment a few of them are alive at once, as in
+//! [`TilePerm::inverse`](super::tiles::TilePerm::inverse) or when a permutation is
+//! applied to a state. A [`RubiksState`](super::RubiksState) of one-byte colours does
+//! the same past N ≈ 590, beyond the 2 MiB stack of a spawned thread.
+//!
+//! A [`Grid<T, N>`] is a pointer-sized handle to its values, which live on the heap.
+//! It dereferences to `[[T; N]; N]`, so `grid[row][col]`, iteration and slicing work
+//! as they do on an array. Permutations and states hold their grids through
+//! [`TileGridHandle`](super::tiles::TileGridHandle) and
+//! [`FaceStateHandle`](super::FaceStateHandle), `Grid`s that dereference to
+//! [`TileGrid`](super::tiles::TileGrid) and [`FaceState`](super::FaceState), so they
+//! stay small whatever N is while `TileGrid` and `FaceState` keep their plain array
+//! fields.
+//!
+//! # Storage
+//!
+//! - [`Grid::from_fn`] allocates the grid and fills it row by row, so only one row
+//!   at a time ever passes through the stack.
+//! - [`Grid::from_static`] borrows a grid with `'static` lifetime without allocating.
+//!   This is what lets constants such as [`TilePerm::ID`](super::tiles::TilePerm::ID)
+//!   contain grids. A static grid is copied to the heap the first time it is mutated.
+//!
+//! # Examples
+//!
+//! ```
+//! use rubiks_cube_representation::core::rubiks::grid::Grid;
+//!
+//! let large = Grid::<u64, 101>::from_fn(|row, col| (row * col) as u64);
+//! assert_eq!(large[100][100], 10_000);
+//! assert_eq!(std::mem::size_of_val(&large), 2 * std::mem::size_of::<usize>());
+//!
+//! const ZEROS: Grid<u8, 2> = Grid::from_static(&[[0; 2]; 2]);
+//! let mut grid = ZEROS;
+//! grid[1][1] = 7;
+//! assert!(!grid.is_static());
+//! assert_eq!(grid, Grid::from([[0, 0], [0, 7]]));
+//! ```
+
+use std::array::from_fn;
+use std::cmp::Ordering;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::ops::{Deref, DerefMut};
+
+#[cfg(test)]
+mod tests;
+
+/// Where a grid's values live.
+enum Storage<T: 'static, const N: usize> {
+    Static(&'static [[T; N]; N]),
+    Heap(Box<[[T; N]; N]>),
+}
+
+/// An N×N grid of values, kept on the heap or in static memory.
+///
+/// See the [module documentation](self) for the storage policy. Comparison, ordering
+/// and hashing look only at the values, never at where they are stored.
+pub struct Grid<T: 'static, const N: usize>(Storage<T, N>);
+
+impl<T, const N: usize> Grid<T, N> {
+    /// Builds a grid on the heap from a function of `(row, col)`, filling it row by
+    /// row.
+    pub fn from_fn(mut value: impl FnMut(usize, usize) -> T) -> Self {
+        let rows: Box<[[T; N]]> = (0..N).map(|row| from_fn(|col| value(row, col))).collect();
+        match rows.try_into() {
+            Ok(rows) => Grid(Storage::Heap(rows)),
+            Err(_) => unreachable!("exactly N rows were collected"),
+        }
+    }
+
+    /// Wraps a grid in static memory without copying it.
+    ///
+    /// This is a `const fn`, for building constants that contain grids.
+    pub const fn from_static(vals: &'static [[T; N]; N]) -> Self {
+        Grid(Storage::Static(vals))
+    }
+
+    /// Whether the values are still borrowed from static memory.
+    pub fn is_static(&self) -> bool {
+        matches!(self.0, Storage::Static(_))
+    }
+
+    /// Builds a grid of the same size by applying a function to every value.
+    pub fn map<U>(&self, mut f: impl FnMut(&T) -> U) -> Grid<U, N> {
+        Grid::from_fn(|row, col| f(&self[row][col]))
+    }
+}
+
+impl<T, const N: usize> Deref for Grid<T, N> {
+    type Target = [[T; N]; N];
+
+    fn deref(&self) -> &[[T; N]; N] {
+        match &self.0 {
+            Storage::Static(vals) => vals,
+            Storage::Heap(vals) => vals,
+        }
+    }
+}
+
+impl<T: Clone, const N: usize> DerefMut for Grid<T, N> {
+    /// Copies a static grid to the heap before handing out a mutable reference.
+    fn deref_mut(&mut self) -> &mut [[T; N]; N] {
+        if let Storage::Static(vals) = self.0 {
+            *self = Grid::from_fn(|row, col| vals[row][col].clone());
+        }
+        match &mut self.0 {
+            Storage::Heap(vals) => vals,
+            Storage::Static(_) => unreachable!("static grids were just copied"),
+        }
+    }
+}
+
+impl<T, const N: usize> From<[[T; N]; N]> for Grid<T, N> {
+    fn from(vals: [[T; N]; N]) -> Self {
+        Grid(Storage::Heap(Box::new(vals)))
+    }
+}
+
+impl<T: Clone, const N: usize> Clone for Grid<T, N> {
+    fn clone(&self) -> Self {
+        match self.0 {
+            Storage::Static(vals) => Grid::from_static(vals),
+            // `Box::clone` would build the copy on the stack first
+            Storage::Heap(_) => Grid::from_fn(|row, col| self[row][col].clone()),
+        }
+    }
+}
+
+impl<T: fmt::Debug, const N: usize> fmt::Debug for Grid<T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&**self, f)
+    }
+}
+
+impl<T: PartialEq, const N: usize> PartialEq for Grid<T, N> {
+    fn eq(&self, other: &Self) -> bool {
+        **self == **other
+    }
+}
+
+impl<T: Eq, const N: usize> Eq for Grid<T, N> {}
+
+impl<T: PartialOrd, const N: usize> PartialOrd for Grid<T, N> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        (**self).partial_cmp(&**other)
+    }
+}
+
+impl<T: Ord, const N: usize> Ord for Grid<T, N> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (**self).cmp(&**other)
+    }
+}
+
+impl<T: Hash, const N: usize> Hash for Grid<T, N> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        (**self).hash(state);
+    }
+}
@@ -0,0 +1,48 @@
+use std::collections::hash_map::DefaultHasher;
+use std::mem::size_of;
+
+use super::*;
+
+fn hash_of<T: Hash>(value: &T) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
+static CHECKER: [[u8; 3]; 3] = [[0, 1, 0], [1, 0, 1], [0, 1, 0]];
+
+#[test]
+fn test_size_is_independent_of_n() {
+    assert_eq!(size_of::<Grid<u8, 2>>(), size_of::<Grid<[usize; 3], 1000>>());
+    assert_eq!(size_of::<Grid<u8, 2>>(), 2 * size_of::<usize>());
+}
+
+#[test]
+fn test_storage_is_invisible() {
+    let stored = Grid::from_static(&CHECKER);
+    let built = Grid::<u8, 3>::from_fn(|row, col| ((row + col) % 2) as u8);
+    assert!(stored.is_static() && !built.is_static());
+    assert_eq!(stored, built);
+    assert_eq!(stored.cmp(&built), Ordering::Equal);
+    assert_eq!(hash_of(&stored), hash_of(&built));
+    assert_eq!(format!("{stored:?}"), format!("{built:?}"));
+    assert!(stored.clone().is_static());
+}
+
+#[test]
+fn test_copy_on_write() {
+    let mut grid = Grid::from_static(&CHECKER);
+    grid[0][0] = 5;
+    assert!(!grid.is_static());
+    assert_eq!(grid[0], [5, 1, 0]);
+    assert_eq!(CHECKER[0], [0, 1, 0]);
+}
+
+#[test]
+fn test_access_and_map() {
+    let mut grid = Grid::<usize, 4>::from_fn(|row, col| 10 * row + col);
+    assert_eq!(grid[2][3], 23);
+    grid[2][3] = 0;
+    assert_eq!(grid.iter().flatten().sum::<usize>(), 4 * 6 + 10 * 4 * 6 - 23);
+    assert_eq!(grid.map(|&x| x % 10)[1], [0, 1, 2, 3]);
+}
@@ -1,10 +1,22 @@
+pub mod algs;
+pub mod animation;
+pub mod batch;
+pub mod dynamic;
+pub mod grid;
 pub mod moves;
+pub mod orientation;
+pub mod packed;
+pub mod pieces;
 pub mod tiles;
 
-use std::ops::Index;
+use std::fmt;
+use std::ops::{Deref, DerefMut, Index, IndexMut};
 
+use crate::core::rubiks::grid::Grid;
+use crate::core::rubiks::tiles::restrictions::Restriction;
 use crate::core::rubiks::tiles::TilePos;
 use crate::core::Colour;
+use crate::error::Error;
 use super::cube::geometry::{Face, FACES};
 use super::cube::schemes::{ColourScheme, ColourPerm};
 use super::cube::rotations::{CubeRotation, X, X3, Y, Y3};
@@ -15,19 +27,78 @@ use super::cube::rotations::{CubeRotation, X, X3, Y, Y3};
 /// a standard orientation where `vals[0][0]` represents the top-left corner when
 /// viewing the face directly. The top-left corner position is defined by that face's
 /// [`principal_corner`](super::cube::geometry::Face::principal_corner).
-#[derive(Clone, Debug, PartialEq, Eq)]
+///
+/// A [`RubiksState`] holds its faces through a [`FaceStateHandle`], which keeps the
+/// colours on the heap, so a state stays small enough for the stack on very large cubes.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
 pub struct FaceState<const DIM: usize> {
     /// 2D array of colors representing the face's tiles
     pub vals: [[Colour;DIM];DIM]
 }
 
 impl<const DIM: usize> FaceState<DIM> {
+    /// Views an array of colours as a face state, without copying it.
+    fn from_vals(vals: &[[Colour; DIM]; DIM]) -> &Self {
+        // SAFETY: `FaceState` is `repr(transparent)` over `[[Colour; DIM]; DIM]`, so
+        // both have the same layout, and the reference keeps the borrow's lifetime.
+        unsafe { &*std::ptr::from_ref(vals).cast::<Self>() }
+    }
+
+    /// Views an array of colours as a mutable face state, without copying it.
+    fn from_vals_mut(vals: &mut [[Colour; DIM]; DIM]) -> &mut Self {
+        // SAFETY: As in `from_vals`, and the reference is unique because `vals` is.
+        unsafe { &mut *std::ptr::from_mut(vals).cast::<Self>() }
+    }
+}
+
+/// A [`FaceState<DIM>`] kept on the heap.
+///
+/// [`RubiksState`] holds its six faces through this handle, so a state is a few
+/// pointers whatever DIM is; see the [`grid`] module for the storage policy. It
+/// dereferences to [`FaceState<DIM>`], so `cube.up.vals[row][col]` reads and writes the
+/// array as before.
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct FaceStateHandle<const DIM: usize>(Grid<Colour, DIM>);
+
+impl<const DIM: usize> FaceStateHandle<DIM> {
+    /// Builds a face on the heap from a function of `(row, col)`, filling it row by row.
+    pub fn from_fn(colour: impl FnMut(usize, usize) -> Colour) -> Self {
+        FaceStateHandle(Grid::from_fn(colour))
+    }
+
     /// Creates a face state where all tiles have the same color.
     ///
     /// This is used to create solved face states where every tile
     /// on the face shows the same color.
     fn flat(colour: Colour) -> Self {
-        Self { vals: [[colour;DIM];DIM] }
+        Self::from_fn(|_, _| colour)
+    }
+}
+
+impl<const DIM: usize> Deref for FaceStateHandle<DIM> {
+    type Target = FaceState<DIM>;
+
+    fn deref(&self) -> &FaceState<DIM> {
+        FaceState::from_vals(&self.0)
+    }
+}
+
+impl<const DIM: usize> DerefMut for FaceStateHandle<DIM> {
+    fn deref_mut(&mut self) -> &mut FaceState<DIM> {
+        FaceState::from_vals_mut(&mut self.0)
+    }
+}
+
+impl<const DIM: usize> From<FaceState<DIM>> for FaceStateHandle<DIM> {
+    fn from(face: FaceState<DIM>) -> Self {
+        FaceStateHandle(Grid::from(face.vals))
+    }
+}
+
+impl<const DIM: usize> fmt::Debug for FaceStateHandle<DIM> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&**self, f)
     }
 }
 
@@ -46,22 +117,23 @@ impl<const DIM: usize> FaceState<DIM> {
 /// ```
 ///
 /// Each face is stored as a [`FaceState<DIM>`] containing the colors of all
-/// tiles on that face. This representation supports cubes of any size
-/// through the const generic `DIM` parameter.
-#[derive(Clone, Debug, PartialEq, Eq)]
+/// tiles on that face, held on the heap through a [`FaceStateHandle<DIM>`]. This
+/// representation supports cubes of any size through the const generic `DIM`
+/// parameter.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct RubiksState<const DIM: usize> {
     /// The up face (top of the cube)
-    pub up: FaceState<DIM>,
+    pub up: FaceStateHandle<DIM>,
     /// The down face (bottom of the cube)
-    pub down: FaceState<DIM>,
+    pub down: FaceStateHandle<DIM>,
     /// The left face
-    pub left: FaceState<DIM>,
+    pub left: FaceStateHandle<DIM>,
     /// The right face
-    pub right: FaceState<DIM>,
+    pub right: FaceStateHandle<DIM>,
     /// The front face
-    pub front: FaceState<DIM>,
+    pub front: FaceStateHandle<DIM>,
     /// The back face
-    pub back: FaceState<DIM>
+    pub back: FaceStateHandle<DIM>
 }
 
 impl<const DIM: usize> RubiksState<DIM> {
@@ -81,6 +153,26 @@ impl<const DIM: usize> RubiksState<DIM> {
         }
     }
 
+    /// Returns the color of a tile, failing instead of panicking if the tile lies off
+    /// the cube.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::TileOutOfRange`] if `row >= N` or `col >= N`.
+    pub fn try_get(&self, tile: TilePos) -> Result<Colour, Error> {
+        Ok(self[tile.check::<DIM>()?])
+    }
+
+    /// Returns a mutable reference to the color of a tile, failing instead of
+    /// panicking if the tile lies off the cube.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::TileOutOfRange`] if `row >= N` or `col >= N`.
+    pub fn try_get_mut(&mut self, tile: TilePos) -> Result<&mut Colour, Error> {
+        Ok(&mut self[tile.check::<DIM>()?])
+    }
+
     /// Checks if the cube is solved in the given color scheme, allowing for any rotation.
     ///
     /// This implements a rotation-invariant solving algorithm that can detect when a cube
@@ -107,56 +199,44 @@ impl<const DIM: usize> RubiksState<DIM> {
             return true
         }
         let top_colour = self.up.vals[0][0];
-        println!("Debug: top_colour = {:?}", top_colour);
-
-        if let Ok(scheme_side) = scheme.get_face(top_colour) {
-            println!("Debug: found top_colour on scheme face: {:?}", scheme_side);
-
-            let first_edit_scheme = match scheme_side {
-                Face::Up => scheme.rotated(CubeRotation::ID),
-                Face::Down => scheme.rotated(X*X),
-                Face::Left => scheme.rotated(super::cube::rotations::Z),
-                Face::Right => scheme.rotated(super::cube::rotations::Z3),
-                Face::Front => scheme.rotated(X),
-                Face::Back => scheme.rotated(X3),
-            };
-
-            println!("Debug: after first rotation, scheme up={:?}, front={:?}",
-                     first_edit_scheme.up(), first_edit_scheme.front());
+        let front_colour = self.front.vals[0][0];
+        let Ok(top_face) = scheme.get_face(top_colour) else {
+            #[cfg(feature = "log")]
+            log::debug!(top_colour:?; "top colour is not in the scheme");
+            return false;
+        };
 
-            let front_colour = self.front.vals[0][0];
-            println!("Debug: front_colour = {:?}", front_colour);
+        let first_edit_scheme = match top_face {
+            Face::Up => scheme.rotated(CubeRotation::ID),
+            Face::Down => scheme.rotated(X*X),
+            Face::Left => scheme.rotated(super::cube::rotations::Z),
+            Face::Right => scheme.rotated(super::cube::rotations::Z3),
+            Face::Front => scheme.rotated(X),
+            Face::Back => scheme.rotated(X3),
+        };
 
-            let edited_scheme = match first_edit_scheme.get_face(front_colour) {
-                Ok(face) => {
-                    println!("Debug: found front_colour on face: {:?}", face);
-                    match face {
-                        Face::Front => first_edit_scheme,
-                        Face::Back => first_edit_scheme.rotated(Y*Y),
-                        Face::Left => first_edit_scheme.rotated(Y3),
-                        Face::Right => first_edit_scheme.rotated(Y),
-                        _ => {
-                            println!("Debug: unexpected face for front_colour: {:?}", face);
-                            return false;
-                        }
-                    }
-                },
-                Err(e) => {
-                    println!("Debug: front_colour not found in scheme: {}", e);
-                    return false;
-                }
-            };
-
-            println!("Debug: final scheme up={:?}, front={:?}",
-                     edited_scheme.up(), edited_scheme.front());
+        let edited_scheme = match first_edit_scheme.get_face(front_colour) {
+            Ok(Face::Front) => first_edit_scheme,
+            Ok(Face::Back) => first_edit_scheme.rotated(Y*Y),
+            Ok(Face::Left) => first_edit_scheme.rotated(Y3),
+            Ok(Face::Right) => first_edit_scheme.rotated(Y),
+            _ => {
+                #[cfg(feature = "log")]
+                log::debug!(top_colour:?, front_colour:?; "front colour is not beside the top colour in the scheme");
+                return false;
+            }
+        };
 
-            let result = self.is_solved_in(edited_scheme);
-            println!("Debug: is_solved_in result = {}", result);
-            result
-        } else {
-            println!("Debug: top_colour not found in scheme");
-            false
-        }
+        let solved = self.is_solved_in(edited_scheme);
+        #[cfg(feature = "log")]
+        log::debug!(
+            top_face:?,
+            up:? = edited_scheme.up(),
+            front:? = edited_scheme.front(),
+            solved;
+            "detected orientation"
+        );
+        solved
     }
 
     /// Checks if the cube is solved in the given color scheme with exact orientation.
@@ -181,6 +261,15 @@ impl<const DIM: usize> RubiksState<DIM> {
         .all(|(f,i,j)| self.face_state(f).vals[i][j]==scheme.from_face(f))
     }
 
+    /// Checks if every tile of a restriction shows its face's colour in the given
+    /// scheme, e.g. whether the first two layers of a 3×3×3 are solved.
+    ///
+    /// Tiles outside the restriction are ignored, and the check short-circuits on the
+    /// first wrong tile.
+    pub fn is_solved_on_in<T: Restriction<DIM>, Scheme: ColourScheme>(&self, restriction: T, scheme: Scheme) -> bool {
+        restriction.restricted_positions().all(|tile| self[tile] == scheme.from_face(tile.face))
+    }
+
     /// Checks if the cube is solved using its current color configuration.
     ///
     /// This determines if the cube is in a solved state by creating a color scheme
@@ -246,12 +335,26 @@ impl<const DIM: usize> RubiksState<DIM> {
     /// - Generating target states for solving algorithms
     pub fn solved_in<Scheme: ColourScheme>(scheme: Scheme) -> Self {
         Self {
-            up: FaceState::flat(scheme.up()),
-            down: FaceState::flat(scheme.down()),
-            left: FaceState::flat(scheme.left()),
-            right: FaceState::flat(scheme.right()),
-            front: FaceState::flat(scheme.front()),
-            back: FaceState::flat(scheme.back()),
+            up: FaceStateHandle::flat(scheme.up()),
+            down: FaceStateHandle::flat(scheme.down()),
+            left: FaceStateHandle::flat(scheme.left()),
+            right: FaceStateHandle::flat(scheme.right()),
+            front: FaceStateHandle::flat(scheme.front()),
+            back: FaceStateHandle::flat(scheme.back()),
+        }
+    }
+
+    /// Builds a state from a function giving the colour of each tile, filling each face
+    /// on the heap row by row.
+    pub(crate) fn from_fn(mut colour: impl FnMut(TilePos) -> Colour) -> Self {
+        let mut face = |face: Face| FaceStateHandle::from_fn(|row, col| colour(TilePos { face, row, col }));
+        RubiksState {
+            up: face(Face::Up),
+            down: face(Face::Down),
+            left: face(Face::Left),
+            right: face(Face::Right),
+            front: face(Face::Front),
+            back: face(Face::Back),
         }
     }
 }
@@ -277,7 +380,8 @@ impl<const N: usize> Index<TilePos> for RubiksState<N> {
     ///
     /// This will panic if `row >= N` or `col >= N`, as those would be out-of-bounds
     /// array accesses. Proper usage requires [`TilePos`] values to satisfy the invariant
-    /// that row and column indices are less than the cube dimension.
+    /// that row and column indices are less than the cube dimension;
+    /// [`RubiksState::try_get`] checks it instead.
     fn index(&self, index: TilePos) -> &Self::Output {
         let TilePos { face, row, col } = index;
         match face {
@@ -291,5 +395,27 @@ impl<const N: usize> Index<TilePos> for RubiksState<N> {
     }
 }
 
+impl<const N: usize> IndexMut<TilePos> for RubiksState<N> {
+    /// Returns a mutable reference to the color of the tile at the specified position,
+    /// for recolouring single stickers or moving them in place.
+    ///
+    /// # Panics
+    ///
+    /// As for indexing, panics if `row >= N` or `col >= N`.
+    fn index_mut(&mut self, index: TilePos) -> &mut Self::Output {
+        let TilePos { face, row, col } = index;
+        match face {
+            Face::Up => &mut self.up.vals[row][col],
+            Face::Down => &mut self.down.vals[row][col],
+            Face::Left => &mut self.left.vals[row][col],
+            Face::Right => &mut self.right.vals[row][col],
+            Face::Front => &mut self.front.vals[row][col],
+            Face::Back => &mut self.back.vals[row][col],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
 #[cfg(test)]
-mod tests;
\ No newline at end of file
+pub(crate) mod test_support;
\ No newline at end of file
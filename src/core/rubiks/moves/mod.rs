@@ -45,6 +45,20 @@
 //! This separation enables clean notation while providing the mathematical essence
 //! needed for cube operations and algorithm implementation.
 //!
+//! # Text Notation
+//!
+//! Every move type implements [`Display`](std::fmt::Display) and
+//! [`FromStr`](std::str::FromStr) in the standard WCA/SiGN notation: `R'`, `3Rw2`,
+//! `2R` for a single slice, `2-3Rw` for a layer range and `M'`. Move sequences with
+//! conjugates and commutators are handled by [`algs`](super::algs).
+//!
+//! # Invalid Layers
+//!
+//! The layered move types can name layers that an N×N×N cube does not have: a slice
+//! or depth of 0 or beyond N, a reversed range, or a middle layer on an even cube. Such
+//! moves panic when converted to a permutation, animated or applied to a state. Their
+//! `checked` methods, such as [`SliceMove::checked`], report the same error up front.
+//!
 //! # Design Philosophy
 //!
 //! - **Completeness**: Captures all mathematically distinct move types
@@ -55,6 +69,7 @@
 #[cfg(test)]
 mod tests;
 mod multiplication;
+mod notation;
 
 /// Standard single-layer face turns using traditional Rubik's cube notation.
 ///
@@ -95,7 +110,7 @@ mod multiplication;
 /// - Advanced algorithms (F2L, OLL, PLL in CFOP method)
 /// - Mathematical analysis of cube group structure
 /// - Algorithm optimization and move count analysis
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum BasicMove<const DIM: usize> {
     /// Up face 90° clockwise rotation
     U,
@@ -202,6 +217,44 @@ impl<const N: usize> From<BasicMove<N>> for BasicMoveInternal<N> {
     }
 }
 
+impl<const N: usize> BasicMove<N> {
+    /// All 18 basic moves, face by face in [`FACES`](crate::FACES) order, each as a
+    /// clockwise, half and anticlockwise turn.
+    pub const ALL: [BasicMove<N>; 18] = [U, U2, U3, D, D2, D3, L, L2, L3, R, R2, R3, F, F2, F3, B, B2, B3];
+
+    /// Builds the move turning `face` by `amount`, the inverse of the internal
+    /// conversion.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `amount` is [`Angle::Zero`], which no move notation represents.
+    pub(crate) fn from_parts(face: Face, amount: Angle) -> Self {
+        use Face::*;
+        use Angle::*;
+        match (face, amount) {
+            (Up, CWQuarter) => U,
+            (Up, Half) => U2,
+            (Up, ACWQuarter) => U3,
+            (Down, CWQuarter) => D,
+            (Down, Half) => D2,
+            (Down, ACWQuarter) => D3,
+            (Left, CWQuarter) => L,
+            (Left, Half) => L2,
+            (Left, ACWQuarter) => L3,
+            (Right, CWQuarter) => R,
+            (Right, Half) => R2,
+            (Right, ACWQuarter) => R3,
+            (Front, CWQuarter) => F,
+            (Front, Half) => F2,
+            (Front, ACWQuarter) => F3,
+            (Back, CWQuarter) => B,
+            (Back, Half) => B2,
+            (Back, ACWQuarter) => B3,
+            (_, Zero) => panic!("no BasicMove turns by zero"),
+        }
+    }
+}
+
 /// Multi-layer wide turns affecting multiple consecutive layers from a face.
 ///
 /// Wide moves extend the concept of basic face turns to include multiple layers,
@@ -236,7 +289,7 @@ impl<const N: usize> From<BasicMove<N>> for BasicMoveInternal<N> {
 /// multiple layers simultaneously. They form a natural extension of the basic
 /// move group for larger cube dimensions. Note that opposite wide moves can
 /// affect overlapping slices, though they still commute.
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum WideMove<const DIM: usize> {
     /// Up face wide turn, 90° clockwise, with specified depth
     Uw(usize),
@@ -325,6 +378,51 @@ impl<const N: usize> From<WideMove<N>> for WideMoveInternal<N> {
     }
 }
 
+impl<const N: usize> WideMove<N> {
+    /// Checks that the depth lies in `1..=N`, returning the move unchanged; see
+    /// [Invalid Layers](self#invalid-layers).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidLayer`] if the depth is 0 or more than N.
+    pub fn checked(self) -> Result<Self, Error> {
+        DynMove::from(self).layers(N)?;
+        Ok(self)
+    }
+
+    /// Builds the move turning the outer `depth` layers from `face` by `amount`, the inverse of the internal
+    /// conversion.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `amount` is [`Angle::Zero`], which no move notation represents.
+    pub(crate) fn from_parts(face: Face, amount: Angle, depth: usize) -> Self {
+        use Face::*;
+        use Angle::*;
+        match (face, amount) {
+            (Up, CWQuarter) => Uw(depth),
+            (Up, Half) => Uw2(depth),
+            (Up, ACWQuarter) => Uw3(depth),
+            (Down, CWQuarter) => Dw(depth),
+            (Down, Half) => Dw2(depth),
+            (Down, ACWQuarter) => Dw3(depth),
+            (Left, CWQuarter) => Lw(depth),
+            (Left, Half) => Lw2(depth),
+            (Left, ACWQuarter) => Lw3(depth),
+            (Right, CWQuarter) => Rw(depth),
+            (Right, Half) => Rw2(depth),
+            (Right, ACWQuarter) => Rw3(depth),
+            (Front, CWQuarter) => Fw(depth),
+            (Front, Half) => Fw2(depth),
+            (Front, ACWQuarter) => Fw3(depth),
+            (Back, CWQuarter) => Bw(depth),
+            (Back, Half) => Bw2(depth),
+            (Back, ACWQuarter) => Bw3(depth),
+            (_, Zero) => panic!("no WideMove turns by zero"),
+        }
+    }
+}
+
 
 /// Individual slice turns targeting specific internal layers by number.
 ///
@@ -335,7 +433,7 @@ impl<const N: usize> From<WideMove<N>> for WideMoveInternal<N> {
 /// # Notation: `[Face]s[Rotation]([Layer])`
 ///
 /// Examples: `Us(2)` (slice 2 from Up), `Rs3(4)` (slice 4 from Right, counterclockwise)
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum SliceMove<const DIM: usize> {
     Us(usize),
     Us2(usize),
@@ -404,6 +502,51 @@ impl<const N: usize> From<SliceMove<N>> for SliceMoveInternal<N> {
     }
 }
 
+impl<const N: usize> SliceMove<N> {
+    /// Checks that the layer lies in `1..=N`, returning the move unchanged; see
+    /// [Invalid Layers](self#invalid-layers).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidLayer`] if the layer is 0 or more than N.
+    pub fn checked(self) -> Result<Self, Error> {
+        DynMove::from(self).layers(N)?;
+        Ok(self)
+    }
+
+    /// Builds the move turning layer `layer` counted from `face` by `amount`, the inverse of the internal
+    /// conversion.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `amount` is [`Angle::Zero`], which no move notation represents.
+    pub(crate) fn from_parts(face: Face, amount: Angle, layer: usize) -> Self {
+        use Face::*;
+        use Angle::*;
+        match (face, amount) {
+            (Up, CWQuarter) => Us(layer),
+            (Up, Half) => Us2(layer),
+            (Up, ACWQuarter) => Us3(layer),
+            (Down, CWQuarter) => Ds(layer),
+            (Down, Half) => Ds2(layer),
+            (Down, ACWQuarter) => Ds3(layer),
+            (Left, CWQuarter) => Ls(layer),
+            (Left, Half) => Ls2(layer),
+            (Left, ACWQuarter) => Ls3(layer),
+            (Right, CWQuarter) => Rs(layer),
+            (Right, Half) => Rs2(layer),
+            (Right, ACWQuarter) => Rs3(layer),
+            (Front, CWQuarter) => Fs(layer),
+            (Front, Half) => Fs2(layer),
+            (Front, ACWQuarter) => Fs3(layer),
+            (Back, CWQuarter) => Bs(layer),
+            (Back, Half) => Bs2(layer),
+            (Back, ACWQuarter) => Bs3(layer),
+            (_, Zero) => panic!("no SliceMove turns by zero"),
+        }
+    }
+}
+
 /// Range-based turns affecting multiple consecutive layers within specified bounds.
 ///
 /// Range moves provide fine-grained control over layer selection by specifying
@@ -413,7 +556,7 @@ impl<const N: usize> From<SliceMove<N>> for SliceMoveInternal<N> {
 /// # Notation: `[Face]r[Rotation]([Start], [End])`
 ///
 /// Examples: `Ur(2,4)` (layers 2-4 from Up), `Lr3(1,3)` (layers 1-3 from Left, counterclockwise)
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum RangeMove<const DIM: usize> {
     Ur(usize,usize),
     Ur2(usize,usize),
@@ -484,6 +627,52 @@ impl<const N: usize> From<RangeMove<N>> for RangeMoveInternal<N> {
     }
 }
 
+impl<const N: usize> RangeMove<N> {
+    /// Checks that both ends of the range lie in `1..=N`, in order, returning the move
+    /// unchanged; see [Invalid Layers](self#invalid-layers).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidLayer`] if an end is 0 or more than N, or
+    /// [`Error::ReversedLayers`] if the start lies beyond the end.
+    pub fn checked(self) -> Result<Self, Error> {
+        DynMove::from(self).layers(N)?;
+        Ok(self)
+    }
+
+    /// Builds the move turning layers `start..=end` counted from `face` by `amount`, the inverse of the internal
+    /// conversion.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `amount` is [`Angle::Zero`], which no move notation represents.
+    pub(crate) fn from_parts(face: Face, amount: Angle, start: usize, end: usize) -> Self {
+        use Face::*;
+        use Angle::*;
+        match (face, amount) {
+            (Up, CWQuarter) => Ur(start, end),
+            (Up, Half) => Ur2(start, end),
+            (Up, ACWQuarter) => Ur3(start, end),
+            (Down, CWQuarter) => Dr(start, end),
+            (Down, Half) => Dr2(start, end),
+            (Down, ACWQuarter) => Dr3(start, end),
+            (Left, CWQuarter) => Lr(start, end),
+            (Left, Half) => Lr2(start, end),
+            (Left, ACWQuarter) => Lr3(start, end),
+            (Right, CWQuarter) => Rr(start, end),
+            (Right, Half) => Rr2(start, end),
+            (Right, ACWQuarter) => Rr3(start, end),
+            (Front, CWQuarter) => Fr(start, end),
+            (Front, Half) => Fr2(start, end),
+            (Front, ACWQuarter) => Fr3(start, end),
+            (Back, CWQuarter) => Br(start, end),
+            (Back, Half) => Br2(start, end),
+            (Back, ACWQuarter) => Br3(start, end),
+            (_, Zero) => panic!("no RangeMove turns by zero"),
+        }
+    }
+}
+
 /// Traditional middle slice moves for the central layers of odd-dimensioned cubes.
 ///
 /// These moves represent the classic middle slice notation used in standard
@@ -501,7 +690,7 @@ impl<const N: usize> From<RangeMove<N>> for RangeMoveInternal<N> {
 /// Middle moves form an important subset of slice operations, particularly
 /// useful in algorithms that manipulate cube parity and orientation states.
 /// They maintain the same rotational algebra as their corresponding face moves.
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum MiddleMove<const DIM: usize> {
     /// Middle slice 90° clockwise (like L)
     M,
@@ -560,16 +749,42 @@ impl<const N: usize> From<MiddleMove<N>> for MiddleMoveInternal<N> {
     }
 }
 
-use crate::{core::{rubiks::tiles::TilePerm, Angle}, Face};
-
-pub(crate) trait Move<const N: usize> : Into<TilePerm<N>> {}
-
-impl<const N: usize> Move<N> for BasicMove<N> {}
-
-impl<const N: usize> Move<N> for WideMove<N> {}
-
-impl<const N: usize> Move<N> for SliceMove<N> {}
+impl<const N: usize> MiddleMove<N> {
+    /// Checks that the cube has a middle layer, i.e. N is odd, returning the move
+    /// unchanged; see [Invalid Layers](self#invalid-layers).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::NoMiddleLayer`] if N is even.
+    pub fn checked(self) -> Result<Self, Error> {
+        DynMove::from(self).layers(N)?;
+        Ok(self)
+    }
 
-impl<const N: usize> Move<N> for RangeMove<N> {}
+    /// Builds the middle move turning the central layer like a turn of `face` by
+    /// `amount`. Faces opposite the reference faces (Left, Down, Front) turn the same
+    /// slice the other way.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `amount` is [`Angle::Zero`], which no move notation represents.
+    pub(crate) fn from_parts(face: Face, amount: Angle) -> Self {
+        use Face::*;
+        use Angle::*;
+        let amount = if matches!(face, Right | Up | Back) { Zero - amount } else { amount };
+        match (face, amount) {
+            (Left | Right, CWQuarter) => M,
+            (Left | Right, Half) => M2,
+            (Left | Right, ACWQuarter) => M3,
+            (Down | Up, CWQuarter) => E,
+            (Down | Up, Half) => E2,
+            (Down | Up, ACWQuarter) => E3,
+            (Front | Back, CWQuarter) => S,
+            (Front | Back, Half) => S2,
+            (Front | Back, ACWQuarter) => S3,
+            (_, Zero) => panic!("no MiddleMove turns by zero"),
+        }
+    }
+}
 
-impl<const N: usize> Move<N> for MiddleMove<N> {}
\ No newline at end of file
+use crate::{core::{rubiks::dynamic::DynMove, Angle}, error::Error, Face};
@@ -0,0 +1,236 @@
+//! Text notation for moves.
+//!
+//! Moves print and parse in the usual WCA/SiGN style rather than the Rust variant
+//! names:
+//!
+//! | Move                   | Notation | Meaning                                  |
+//! |------------------------|----------|------------------------------------------|
+//! | `BasicMove::R3`        | `R'`     | Outer layer of R, anticlockwise          |
+//! | `WideMove::Rw(2)`      | `Rw`     | Two layers from R (depth 2 is implicit)  |
+//! | `WideMove::Rw2(3)`     | `3Rw2`   | Three layers from R, half turn           |
+//! | `SliceMove::Rs(2)`     | `2R`     | The second layer from R only             |
+//! | `RangeMove::Rr3(2, 3)` | `2-3Rw'` | Layers 2 to 3 from R, anticlockwise      |
+//! | `MiddleMove::M3`       | `M'`     | The central slice, anticlockwise like L  |
+//!
+//! When parsing, `2'` is accepted as a half turn, and a `0` suffix as the turn by
+//! [`Angle::Zero`] that a [`DynMove`] can hold and prints that way. Layer numbers must lie in `1..=N`,
+//! ranges must not be reversed, and middle moves need an odd N. A
+//! [`DynMove`] uses the same notation, but its layers are only checked once
+//! it is applied to a cube of known size.
+//!
+//! Text that is not a move parses to an [`Error::Parse`]. A move of another family, a
+//! zero turn, or one whose layers do not fit the cube, parses to the [`Error`] of
+//! converting the equivalent [`DynMove`] with [`TryFrom`].
+
+use std::fmt;
+use std::str::FromStr;
+
+use crate::core::rubiks::dynamic::DynMove;
+use crate::core::Angle;
+use crate::error::Error;
+use crate::Face;
+
+use super::{BasicMove, BasicMoveInternal, MiddleMove, MiddleMoveInternal, RangeMove, RangeMoveInternal,
+    SliceMove, SliceMoveInternal, WideMove, WideMoveInternal};
+
+#[cfg(test)]
+mod tests;
+
+/// Returns the notation suffix for a turn amount.
+fn suffix(amount: Angle) -> &'static str {
+    match amount {
+        Angle::Zero => "0",
+        Angle::CWQuarter => "",
+        Angle::Half => "2",
+        Angle::ACWQuarter => "'",
+    }
+}
+
+/// A move token split into its parts, before checking it against a move type.
+struct Token {
+    /// The layer numbers before the letter: none, one, or a `start-end` range
+    layers: Vec<usize>,
+    letter: char,
+    wide: bool,
+    amount: Angle,
+}
+
+impl Token {
+    fn parse(text: &str) -> Result<Self, Error> {
+        let (rest, amount) = if let Some(rest) = text.strip_suffix("2'") {
+            (rest, Angle::Half)
+        } else if let Some(rest) = text.strip_suffix('2') {
+            (rest, Angle::Half)
+        } else if let Some(rest) = text.strip_suffix('\'') {
+            (rest, Angle::ACWQuarter)
+        } else if let Some(rest) = text.strip_suffix('0') {
+            (rest, Angle::Zero)
+        } else {
+            (text, Angle::CWQuarter)
+        };
+        let (rest, wide) = match rest.strip_suffix('w') {
+            Some(rest) => (rest, true),
+            None => (rest, false),
+        };
+        let letter = rest.chars().next_back().ok_or_else(|| invalid(text))?;
+        let prefix = &rest[..rest.len() - letter.len_utf8()];
+        let layers = if prefix.is_empty() {
+            Vec::new()
+        } else {
+            prefix.split('-').map(|layer| layer.parse().map_err(|_| invalid(text))).collect::<Result<_, _>>()?
+        };
+        Ok(Token { layers, letter, wide, amount })
+    }
+
+    fn face(&self, text: &str) -> Result<Face, Error> {
+        Face::from_letter(self.letter).ok_or_else(|| invalid(text))
+    }
+}
+
+/// The error for a move token that does not parse.
+fn invalid(text: &str) -> Error {
+    Error::Parse { position: 0, token: text.to_string() }
+}
+
+/// Parses a move of any family, leaving its layers to be checked against a cube size.
+fn parse_move(text: &str) -> Result<DynMove, Error> {
+    let token = Token::parse(text)?;
+    let amount = token.amount;
+    let middle = match token.letter {
+        'M' => Some(Face::Left),
+        'E' => Some(Face::Down),
+        'S' => Some(Face::Front),
+        _ => None,
+    };
+    match (token.wide, &token.layers[..], middle) {
+        (false, [], Some(face)) => Ok(DynMove::Middle { face, amount }),
+        (false, [], None) => Ok(DynMove::Basic { face: token.face(text)?, amount }),
+        (false, &[layer], _) => Ok(DynMove::Slice { face: token.face(text)?, amount, layer }),
+        (true, [], _) => Ok(DynMove::Wide { face: token.face(text)?, amount, depth: 2 }),
+        (true, &[depth], _) => Ok(DynMove::Wide { face: token.face(text)?, amount, depth }),
+        (true, &[start, end], _) if start <= end => Ok(DynMove::Range { face: token.face(text)?, amount, start, end }),
+        (true, &[start, end], _) => Err(Error::ReversedLayers { start, end }),
+        _ => Err(invalid(text)),
+    }
+}
+
+/// The letter of a middle move, from its reference face.
+fn middle_letter(face: Face) -> char {
+    match face {
+        Face::Left => 'M',
+        Face::Down => 'E',
+        _ => 'S',
+    }
+}
+
+impl<const N: usize> fmt::Display for BasicMove<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let BasicMoveInternal { face, amount } = BasicMoveInternal::from(*self);
+        write!(f, "{}{}", face.letter(), suffix(amount))
+    }
+}
+
+impl<const N: usize> FromStr for BasicMove<N> {
+    type Err = Error;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        parse_move(text)?.try_into()
+    }
+}
+
+impl<const N: usize> fmt::Display for WideMove<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let WideMoveInternal { face, amount, depth } = WideMoveInternal::from(*self);
+        if depth != 2 {
+            write!(f, "{depth}")?;
+        }
+        write!(f, "{}w{}", face.letter(), suffix(amount))
+    }
+}
+
+impl<const N: usize> FromStr for WideMove<N> {
+    type Err = Error;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        parse_move(text)?.try_into()
+    }
+}
+
+impl<const N: usize> fmt::Display for SliceMove<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let SliceMoveInternal { face, amount, layer } = SliceMoveInternal::from(*self);
+        write!(f, "{layer}{}{}", face.letter(), suffix(amount))
+    }
+}
+
+impl<const N: usize> FromStr for SliceMove<N> {
+    type Err = Error;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        parse_move(text)?.try_into()
+    }
+}
+
+impl<const N: usize> fmt::Display for RangeMove<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let RangeMoveInternal { face, amount, start_layer, end_layer } = RangeMoveInternal::from(*self);
+        write!(f, "{start_layer}-{end_layer}{}w{}", face.letter(), suffix(amount))
+    }
+}
+
+impl<const N: usize> FromStr for RangeMove<N> {
+    type Err = Error;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        parse_move(text)?.try_into()
+    }
+}
+
+impl<const N: usize> fmt::Display for MiddleMove<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let MiddleMoveInternal { face, amount } = MiddleMoveInternal::from(*self);
+        write!(f, "{}{}", middle_letter(face), suffix(amount))
+    }
+}
+
+impl<const N: usize> FromStr for MiddleMove<N> {
+    type Err = Error;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        parse_move(text)?.try_into()
+    }
+}
+
+impl fmt::Display for DynMove {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            DynMove::Basic { face, amount } => write!(f, "{}{}", face.letter(), suffix(amount)),
+            DynMove::Wide { face, amount, depth } => {
+                if depth != 2 {
+                    write!(f, "{depth}")?;
+                }
+                write!(f, "{}w{}", face.letter(), suffix(amount))
+            }
+            DynMove::Slice { face, amount, layer } => write!(f, "{layer}{}{}", face.letter(), suffix(amount)),
+            DynMove::Range { face, amount, start, end } => {
+                write!(f, "{start}-{end}{}w{}", face.letter(), suffix(amount))
+            }
+            DynMove::Middle { face, amount } => write!(f, "{}{}", middle_letter(face), suffix(amount)),
+        }
+    }
+}
+
+impl FromStr for DynMove {
+    type Err = Error;
+
+    /// Parses a move of any family. Layer numbers must be positive, but are not
+    /// checked against a cube size.
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        match parse_move(text)? {
+            DynMove::Wide { depth: 0, .. } | DynMove::Slice { layer: 0, .. } | DynMove::Range { start: 0, .. } => {
+                Err(invalid(text))
+            }
+            mv => Ok(mv),
+        }
+    }
+}
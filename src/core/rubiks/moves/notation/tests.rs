@@ -0,0 +1,77 @@
+use super::*;
+
+/// Checks that every move prints as `text` and parses back to itself.
+fn assert_round_trip<M>(moves: &[M], texts: &[&str])
+where
+    M: fmt::Display + FromStr<Err = Error> + PartialEq + fmt::Debug,
+{
+    for (mv, text) in moves.iter().zip(texts) {
+        assert_eq!(mv.to_string(), *text);
+        assert_eq!(text.parse::<M>().as_ref(), Ok(mv));
+    }
+}
+
+#[test]
+fn test_round_trips() {
+    use BasicMove::*;
+    assert_round_trip::<BasicMove<3>>(&[U, D2, L3, R, F2, B3], &["U", "D2", "L'", "R", "F2", "B'"]);
+    assert_round_trip::<WideMove<5>>(
+        &[WideMove::Rw(2), WideMove::Uw3(3), WideMove::Fw2(1)],
+        &["Rw", "3Uw'", "1Fw2"],
+    );
+    assert_round_trip::<SliceMove<5>>(&[SliceMove::Rs(2), SliceMove::Ds3(5)], &["2R", "5D'"]);
+    assert_round_trip::<RangeMove<5>>(&[RangeMove::Rr3(2, 3), RangeMove::Br2(1, 5)], &["2-3Rw'", "1-5Bw2"]);
+    assert_round_trip::<MiddleMove<3>>(
+        &[MiddleMove::M, MiddleMove::E2, MiddleMove::S3],
+        &["M", "E2", "S'"],
+    );
+}
+
+#[test]
+fn test_parsing_variants() {
+    assert_eq!("R2'".parse::<BasicMove<3>>(), Ok(BasicMove::R2));
+    assert_eq!("2Rw".parse::<WideMove<4>>(), Ok(WideMove::Rw(2)));
+    assert_eq!("M'".parse::<MiddleMove<5>>(), Ok(MiddleMove::M3));
+}
+
+#[test]
+fn test_parse_errors() {
+    assert!("X".parse::<BasicMove<3>>().is_err());
+    assert!("".parse::<BasicMove<3>>().is_err());
+    assert!("Rw".parse::<BasicMove<3>>().is_err());
+    assert!("2R".parse::<BasicMove<3>>().is_err());
+    assert!("R".parse::<WideMove<3>>().is_err());
+    assert!("4Rw".parse::<WideMove<3>>().is_err());
+    assert!("0R".parse::<SliceMove<3>>().is_err());
+    assert!("3-2Rw".parse::<RangeMove<3>>().is_err());
+    assert!("2-Rw".parse::<RangeMove<3>>().is_err());
+    assert!("M".parse::<MiddleMove<4>>().is_err());
+    assert!("R".parse::<MiddleMove<3>>().is_err());
+    assert_eq!("4Rw".parse::<WideMove<3>>(), Err(Error::InvalidLayer { layer: 4, n: 3 }));
+    assert_eq!("0R".parse::<SliceMove<3>>(), Err(Error::InvalidLayer { layer: 0, n: 3 }));
+    assert_eq!("3-2Rw".parse::<RangeMove<3>>(), Err(Error::ReversedLayers { start: 3, end: 2 }));
+    assert_eq!("M".parse::<MiddleMove<4>>(), Err(Error::NoMiddleLayer { n: 4 }));
+    let wide = DynMove::Wide { face: Face::Right, amount: Angle::CWQuarter, depth: 2 };
+    assert_eq!("Rw".parse::<BasicMove<3>>(), Err(Error::WrongMoveFamily { mv: wide, family: "basic" }));
+    assert_eq!("X".parse::<BasicMove<3>>(), Err(Error::Parse { position: 0, token: "X".to_string() }));
+    assert_eq!("2-Rw".parse::<RangeMove<3>>(), Err(Error::Parse { position: 0, token: "2-Rw".to_string() }));
+    let zero = DynMove::Slice { face: Face::Right, amount: Angle::Zero, layer: 2 };
+    assert_eq!("2R0".parse::<SliceMove<3>>(), Err(Error::ZeroTurn { mv: zero }));
+    assert_eq!("2R0".parse::<BasicMove<3>>(), Err(Error::WrongMoveFamily { mv: zero, family: "basic" }));
+}
+
+#[test]
+fn test_dyn_move_notation() {
+    let texts = ["R'", "Uw", "3Fw2", "2L", "2-5Dw'", "M", "E2", "S'", "1Bw", "R0", "2-3Uw0", "M0"];
+    for text in texts {
+        let mv: DynMove = text.parse().unwrap();
+        assert_eq!(mv.to_string(), text);
+    }
+    assert_eq!("7R".parse(), Ok(DynMove::Slice { face: Face::Right, amount: Angle::CWQuarter, layer: 7 }));
+    assert_eq!("R0".parse(), Ok(DynMove::Basic { face: Face::Right, amount: Angle::Zero }));
+    assert_eq!(DynMove::from(RangeMove::<5>::Rr3(2, 3)).to_string(), RangeMove::<5>::Rr3(2, 3).to_string());
+    assert_eq!("0R".parse::<DynMove>(), Err(Error::Parse { position: 0, token: "0R".to_string() }));
+    assert!("3-2Rw".parse::<DynMove>().is_err());
+    assert!("2M".parse::<DynMove>().is_err());
+    assert!("1-2-3Rw".parse::<DynMove>().is_err());
+}
@@ -0,0 +1,115 @@
+//! Detecting how a cube is held, and turning it to a colour scheme's orientation.
+//!
+//! [`RubiksState::is_solved_up_to_rotation_in`] only answers whether a solved cube is
+//! solved in some orientation. The methods here find the orientation itself, as the
+//! [`CubeRotation`] that turns the cube so each face is where the scheme puts its
+//! colour, for any state, solved or not:
+//!
+//! - [`RubiksState::orientations_in`]: Every rotation that fits best, for patterns with
+//!   several equally good orientations
+//! - [`RubiksState::orientation_in`]: The first of them
+//! - [`RubiksState::normalised_in`] and [`RubiksState::normalise_in`]: The cube after
+//!   that rotation
+//!
+//! # Algorithm
+//!
+//! Each face's colour is read from its centre stickers, which no move takes off the
+//! face's centre region:
+//!
+//! - **Odd N**: The fixed central sticker
+//! - **Even N ≥ 4**: The (N−2)×(N−2) block of centre stickers, which moves can scramble
+//!   among the faces, so the block's majority decides
+//! - **N = 2**: There are no centres, so every sticker of the face votes
+//!
+//! Every one of the 24 rotations is scored by how many of these stickers it brings
+//! onto the face where the scheme puts their colour, and the rotations with the
+//! highest score are returned. Scoring reads the stickers through the inverse rotation,
+//! without building any rotated states. On odd cubes the centres fix a single
+//! orientation; on even cubes ties are possible when the centres are evenly mixed.
+//!
+//! # Example
+//!
+//! ```
+//! use rubiks_cube_representation::core::rubiks::moves::WideMove;
+//! use rubiks_cube_representation::{RubiksState, Western, CubeRotation, X, Y};
+//!
+//! // A 4×4×4 turned with Rw and held differently: no centre is fixed, but the majority
+//! // of each centre block still reveals the orientation
+//! let cube = RubiksState::<4>::solved_in(Western) * &WideMove::Rw(2) * &X * &Y;
+//! let rotation = cube.orientation_in(Western);
+//! assert_eq!(rotation, (X * Y).inverse());
+//! assert_eq!(cube.normalised_in(Western), RubiksState::<4>::solved_in(Western) * &WideMove::Rw(2));
+//!
+//! // Without centres at all, a solved 2×2×2 still has exactly one orientation
+//! assert_eq!(RubiksState::<2>::solved_in(Western).orientations_in(Western), vec![CubeRotation::ID]);
+//! ```
+
+use crate::core::cube::rotations::CubeRotation;
+use crate::core::cube::schemes::ColourScheme;
+use crate::core::rubiks::tiles::motion::rotated_tile;
+use crate::core::rubiks::tiles::TilePos;
+use crate::core::rubiks::RubiksState;
+use crate::FACES;
+
+#[cfg(test)]
+mod tests;
+
+/// The stickers of a face that decide its colour: see the [module documentation](self).
+fn centre_tiles<const N: usize>() -> impl Iterator<Item = (usize, usize)> {
+    let range = match N {
+        2 => 0..2,
+        _ if N % 2 == 1 => N / 2..N / 2 + 1,
+        _ => 1..N.saturating_sub(1),
+    };
+    range.clone().flat_map(move |row| range.clone().map(move |col| (row, col)))
+}
+
+impl<const N: usize> RubiksState<N> {
+    /// Every rotation that best turns this cube to the orientation of `scheme`, in the
+    /// order of [`CubeRotation::all`].
+    ///
+    /// Applying any of them with `*` puts as many centre stickers as possible on the
+    /// face where the scheme puts their colour; see the
+    /// [module documentation](crate::core::rubiks::orientation) for which stickers count.
+    /// On odd cubes with a valid arrangement of centres there is exactly one.
+    pub fn orientations_in<Scheme: ColourScheme>(&self, scheme: Scheme) -> Vec<CubeRotation> {
+        let centres: Vec<TilePos> = FACES
+            .iter()
+            .flat_map(|&face| centre_tiles::<N>().map(move |(row, col)| TilePos { face, row, col }))
+            .collect();
+        let score = |rotation: CubeRotation| {
+            let matrix = rotation.inverse().matrix();
+            centres
+                .iter()
+                .filter(|&&tile| self[rotated_tile(tile, N, matrix)] == scheme.from_face(tile.face))
+                .count()
+        };
+        let scores = CubeRotation::all().map(|rotation| (rotation, score(rotation)));
+        let best = scores.iter().map(|&(_, score)| score).max().unwrap_or(0);
+        let rotations: Vec<CubeRotation> =
+            scores.into_iter().filter(|&(_, score)| score == best).map(|(rotation, _)| rotation).collect();
+        #[cfg(feature = "log")]
+        log::debug!(rotations:?, matched = best, of = centres.len(); "detected orientations");
+        rotations
+    }
+
+    /// The first of [`RubiksState::orientations_in`]: a rotation that turns this cube to
+    /// the orientation of `scheme`.
+    pub fn orientation_in<Scheme: ColourScheme>(&self, scheme: Scheme) -> CubeRotation {
+        self.orientations_in(scheme)[0]
+    }
+
+    /// This cube turned to the orientation of `scheme` by
+    /// [`RubiksState::orientation_in`].
+    pub fn normalised_in<Scheme: ColourScheme>(&self, scheme: Scheme) -> Self {
+        self * &self.orientation_in(scheme)
+    }
+
+    /// Turns this cube in place to the orientation of `scheme`, returning the rotation
+    /// applied.
+    pub fn normalise_in<Scheme: ColourScheme>(&mut self, scheme: Scheme) -> CubeRotation {
+        let rotation = self.orientation_in(scheme);
+        self.apply(rotation);
+        rotation
+    }
+}
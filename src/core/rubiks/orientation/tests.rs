@@ -0,0 +1,67 @@
+use super::*;
+use crate::core::cube::schemes::{ColourPerm, Japanese, Western};
+use crate::core::rubiks::algs::Alg;
+use crate::core::rubiks::moves::{BasicMove, SliceMove, WideMove};
+use crate::core::Colour;
+
+/// Checks that every orientation of a state is detected and undone.
+fn assert_detects_all_orientations<const N: usize, Scheme: ColourScheme + Copy>(state: &RubiksState<N>, scheme: Scheme) {
+    for rotation in CubeRotation::all() {
+        let held = state * &rotation;
+        assert_eq!(held.orientations_in(scheme), vec![rotation.inverse()], "N = {N}, held by {rotation}");
+        assert_eq!(&held.normalised_in(scheme), state);
+        let mut normalised = held.clone();
+        assert_eq!(normalised.normalise_in(scheme), rotation.inverse());
+        assert_eq!(&normalised, state);
+    }
+}
+
+#[test]
+fn test_solved_orientations() {
+    assert_detects_all_orientations(&RubiksState::<1>::solved_in(Western), Western);
+    assert_detects_all_orientations(&RubiksState::<2>::solved_in(Western), Western);
+    assert_detects_all_orientations(&RubiksState::<3>::solved_in(Japanese), Japanese);
+    assert_detects_all_orientations(&RubiksState::<4>::solved_in(Western), Western);
+    assert_detects_all_orientations(&RubiksState::<5>::solved_in(Western), Western);
+}
+
+#[test]
+fn test_scrambled_orientations() {
+    // Odd cubes: the fixed centres decide, however scrambled the rest
+    let alg: Alg<BasicMove<3>> = "R U2 F' L D B2 R' U F D' L2 B".parse().unwrap();
+    assert_detects_all_orientations(&(RubiksState::<3>::solved_in(Western) * &alg), Western);
+
+    // Even cubes: a slice turn moves part of each centre block, leaving the majority
+    let cube = RubiksState::<6>::solved_in(Western) * &SliceMove::Rs(3) * &WideMove::Uw(2) * &BasicMove::F;
+    assert_detects_all_orientations(&cube, Western);
+}
+
+#[test]
+fn test_symmetric_patterns() {
+    // Every face the same colour fits every rotation equally
+    let blank = RubiksState::<4>::solved_in(ColourPerm {
+        up: Colour::White,
+        down: Colour::White,
+        left: Colour::White,
+        right: Colour::White,
+        front: Colour::White,
+        back: Colour::White,
+    });
+    assert_eq!(blank.orientations_in(Western), CubeRotation::all().to_vec());
+    assert_eq!(blank.orientation_in(Western), CubeRotation::all()[0]);
+
+    // Swapping the centre halves of opposite faces leaves two orientations equally good
+    let cube = RubiksState::<4>::solved_in(Western) * &SliceMove::Rs2(2);
+    let orientations = cube.orientations_in(Western);
+    assert!(orientations.contains(&CubeRotation::ID));
+    assert!(orientations.len() > 1);
+}
+
+#[test]
+fn test_agrees_with_solved_up_to_rotation() {
+    for rotation in CubeRotation::all() {
+        let cube = RubiksState::<3>::solved_in(Western) * &rotation;
+        assert!(cube.is_solved_up_to_rotation_in(Western));
+        assert!(cube.normalised_in(Western).is_solved_in(Western));
+    }
+}
@@ -0,0 +1,197 @@
+//! Bit-packed cube states for hashing and searching.
+//!
+//! A [`RubiksState<N>`] keeps one [`Colour`] per sticker in six separately allocated
+//! heap grids, one [`FaceStateHandle`] per face, which is convenient to inspect but
+//! costly to hash, compare and store by the million, as a breadth-first search or a
+//! duplicate-detection table must. A [`PackedState<N>`]
+//! stores each sticker in 3 bits of one contiguous buffer of `u64` words instead, so a
+//! 3×3×3 state is 24 bytes and hashing or comparing it is a few word operations.
+//!
+//! # Layout
+//!
+//! Stickers are numbered by [`TilePos::index`] and stored 21 to a word, most
+//! significant first, as the index of their colour in [`COLOURS`]. The lowest bit of
+//! every word is unused and always zero. Because the first sticker sits in the highest
+//! bits, comparing the words in order compares the stickers in order, so the
+//! [`Ord`] of packed states agrees with the [`Ord`] of the states they pack.
+//!
+//! # Operations
+//!
+//! Moves, rotations and permutations apply to the packed form directly through
+//! [`PackedState::apply`], without unpacking. Moves rewrite only the stickers of their
+//! layers, as do cached [`PartialTilePerm`]s; full permutations gather every sticker
+//! into a new buffer.
+//!
+//! # Example
+//!
+//! ```
+//! use std::collections::HashSet;
+//!
+//! use rubiks_cube_representation::core::rubiks::moves::BasicMove::{self, *};
+//! use rubiks_cube_representation::core::rubiks::packed::PackedState;
+//! use rubiks_cube_representation::{RubiksState, Western};
+//!
+//! // Breadth-first search over the states two face turns from solved
+//! let start = PackedState::<3>::solved_in(Western);
+//! let mut seen = HashSet::from([start.clone()]);
+//! let mut frontier = vec![start];
+//! for _ in 0..2 {
+//!     let mut next = Vec::new();
+//!     for state in &frontier {
+//!         for mv in BasicMove::<3>::ALL {
+//!             let mut neighbour = state.clone();
+//!             neighbour.apply(mv);
+//!             if seen.insert(neighbour.clone()) {
+//!                 next.push(neighbour);
+//!             }
+//!         }
+//!     }
+//!     frontier = next;
+//! }
+//! assert_eq!(seen.len(), 1 + 18 + 243);
+//!
+//! let mut packed = PackedState::from(&RubiksState::<3>::solved_in(Western));
+//! packed.apply_all([R, U, R3, U3]);
+//! assert_eq!(RubiksState::from(&packed), RubiksState::<3>::solved_in(Western) * &R * &U * &R3 * &U3);
+//! ```
+//!
+//! [`PartialTilePerm`]: super::tiles::partial::PartialTilePerm
+//! [`FaceStateHandle`]: super::FaceStateHandle
+
+
+use crate::core::cube::schemes::ColourScheme;
+use crate::core::rubiks::tiles::in_place::{StickerOperation, Stickers};
+use crate::core::rubiks::tiles::TilePos;
+use crate::core::rubiks::RubiksState;
+use crate::core::{Colour, COLOURS};
+
+#[cfg(test)]
+mod tests;
+
+/// The number of 3-bit stickers stored in each word.
+const PER_WORD: usize = 21;
+
+/// The stickers of an N×N×N cube, 3 bits each.
+///
+/// See the [module documentation](self) for the layout. Equality, ordering and hashing
+/// compare the stickers, and ordering agrees with [`RubiksState`]'s.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PackedState<const N: usize> {
+    words: Box<[u64]>,
+}
+
+impl<const N: usize> PackedState<N> {
+    /// The number of stickers, 6·N².
+    const STICKERS: usize = 6 * N * N;
+
+    /// The packed solved state in a colour scheme.
+    pub fn solved_in<Scheme: ColourScheme>(scheme: Scheme) -> Self {
+        Self::from(&RubiksState::solved_in(scheme))
+    }
+
+    /// The packed words, 21 stickers to a word.
+    pub fn words(&self) -> &[u64] {
+        &self.words
+    }
+
+    /// The colour of a sticker.
+    pub fn get(&self, tile: TilePos) -> Colour {
+        COLOURS[self.code(tile.index::<N>()) as usize]
+    }
+
+    /// Recolours a sticker.
+    pub fn set(&mut self, tile: TilePos, colour: Colour) {
+        self.set_code(tile.index::<N>(), colour as u64);
+    }
+
+    /// Whether every face shows a single colour.
+    pub fn is_solved(&self) -> bool {
+        (0..6).all(|face| {
+            let first = self.code(face * N * N);
+            (face * N * N..(face + 1) * N * N).all(|index| self.code(index) == first)
+        })
+    }
+
+    /// Applies an operation to the packed stickers, with the same result as applying
+    /// it to the unpacked state.
+    pub fn apply(&mut self, op: impl ApplyPacked<N>) {
+        op.apply_packed(self);
+    }
+
+    /// Applies a sequence of operations in order, as repeated [`PackedState::apply`].
+    pub fn apply_all<Op: ApplyPacked<N>>(&mut self, ops: impl IntoIterator<Item = Op>) {
+        for op in ops {
+            op.apply_packed(self);
+        }
+    }
+
+    /// An all-white state, to be filled in.
+    fn blank() -> Self {
+        PackedState { words: vec![0; Self::STICKERS.div_ceil(PER_WORD)].into_boxed_slice() }
+    }
+
+    /// The bit offset of a sticker within its word, leaving bit 0 unused.
+    fn shift(index: usize) -> usize {
+        3 * (PER_WORD - 1 - index % PER_WORD) + 1
+    }
+
+    /// The colour code of the sticker with flat index `index`.
+    fn code(&self, index: usize) -> u64 {
+        self.words[index / PER_WORD] >> Self::shift(index) & 0b111
+    }
+
+    fn set_code(&mut self, index: usize, code: u64) {
+        let word = &mut self.words[index / PER_WORD];
+        *word = *word & !(0b111 << Self::shift(index)) | code << Self::shift(index);
+    }
+}
+
+impl<const N: usize> From<&RubiksState<N>> for PackedState<N> {
+    fn from(state: &RubiksState<N>) -> Self {
+        let mut packed = Self::blank();
+        for tile in TilePos::all::<N>() {
+            packed.set_code(tile.index::<N>(), state[tile] as u64);
+        }
+        packed
+    }
+}
+
+impl<const N: usize> From<&PackedState<N>> for RubiksState<N> {
+    fn from(packed: &PackedState<N>) -> Self {
+        RubiksState::from_fn(|tile| packed.get(tile))
+    }
+}
+
+/// An operation that can move the stickers of a [`PackedState`] directly.
+///
+/// Implemented, and sealed, exactly as
+/// [`ApplyInPlace`](super::tiles::in_place::ApplyInPlace): both traits apply the same
+/// description of each operation to their own store of stickers.
+pub trait ApplyPacked<const N: usize>: StickerOperation<N> {
+    /// Moves the sticker on each tile of `state` to the tile's image.
+    fn apply_packed(&self, state: &mut PackedState<N>);
+}
+
+impl<const N: usize, Op: StickerOperation<N>> ApplyPacked<N> for Op {
+    fn apply_packed(&self, state: &mut PackedState<N>) {
+        self.apply_to(state);
+    }
+}
+
+impl<const N: usize> Stickers<N> for PackedState<N> {
+    fn move_stickers(&mut self, moves: impl Iterator<Item = (TilePos, TilePos)>) {
+        let moved: Vec<_> = moves.map(|(from, to)| (to.index::<N>(), self.code(from.index::<N>()))).collect();
+        for (to, code) in moved {
+            self.set_code(to, code);
+        }
+    }
+
+    /// Gathers every sticker into a new buffer.
+    fn permute(&mut self, image: impl Fn(TilePos) -> TilePos) {
+        let mut out = Self::blank();
+        for tile in TilePos::all::<N>() {
+            out.set_code(image(tile).index::<N>(), self.code(tile.index::<N>()));
+        }
+        *self = out;
+    }
+}
@@ -0,0 +1,93 @@
+use std::cmp::Ordering;
+use std::ops::Mul;
+
+use super::*;
+use crate::core::cube::symmetries::{CubeSymmetry, MirrorPlane};
+use crate::core::rubiks::moves::BasicMove::{self, *};
+use crate::core::rubiks::test_support::{assert_applies_like_operator, check_all_moves, scrambled, Layers, Move, MoveCheck};
+use crate::core::rubiks::tiles::indexed::IndexPerm;
+use crate::core::rubiks::tiles::partial::PartialTilePerm;
+use crate::core::rubiks::tiles::TilePerm;
+use crate::{Face, Western, X, Y};
+
+/// Checks that applying `op` to the packed form agrees with the operator form.
+fn assert_matches<const N: usize, Op: ApplyPacked<N>>(op: &Op)
+where
+    for<'a> &'a RubiksState<N>: Mul<&'a Op, Output = RubiksState<N>>,
+{
+    assert_applies_like_operator(op, |state, op| {
+        let mut packed = PackedState::from(&*state);
+        packed.apply(op);
+        *state = RubiksState::from(&packed);
+    });
+}
+
+struct Packed;
+
+impl<const N: usize> MoveCheck<N> for Packed {
+    fn check<M: Move<N>>(&mut self, mv: M, _: Layers) {
+        let cube = scrambled::<N>();
+        let mut packed = PackedState::from(&cube);
+        packed.apply(mv);
+        assert_eq!(RubiksState::from(&packed), &cube * Into::<TilePerm<N>>::into(mv), "{mv:?}");
+    }
+}
+
+#[test]
+fn test_round_trip_and_size() {
+    let cube = scrambled::<4>();
+    let packed = PackedState::from(&cube);
+    assert_eq!(RubiksState::from(&packed), cube);
+    assert_eq!(packed.words().len(), (6 * 16_usize).div_ceil(21));
+    assert!(packed.words().iter().all(|word| word & 1 == 0));
+    assert_eq!(PackedState::<3>::solved_in(Western).words().len(), 3);
+
+    let tile = TilePos { face: Face::Front, row: 3, col: 2 };
+    let mut changed = packed.clone();
+    changed.set(tile, Colour::Green);
+    assert_eq!(changed.get(tile), Colour::Green);
+    let mut expected = cube.clone();
+    expected[tile] = Colour::Green;
+    assert_eq!(RubiksState::from(&changed), expected);
+}
+
+#[test]
+fn test_order_matches_unpacked() {
+    let a = scrambled::<4>();
+    let states = [a.clone(), &a * &R, &a * &U3, RubiksState::<4>::solved_in(Western), &a * &F2];
+    for x in &states {
+        for y in &states {
+            assert_eq!(PackedState::from(x).cmp(&PackedState::from(y)), x.cmp(y));
+            assert_eq!(PackedState::from(x) == PackedState::from(y), x == y);
+        }
+    }
+    assert_eq!(PackedState::from(&a).cmp(&PackedState::from(&a)), Ordering::Equal);
+}
+
+#[test]
+fn test_operations_match_unpacked() {
+    check_all_moves::<4>(&mut Packed);
+    check_all_moves::<5>(&mut Packed);
+    for mv in BasicMove::<4>::ALL {
+        assert_matches(&PartialTilePerm::from(&mv));
+        assert_matches(&IndexPerm::from(TilePerm::from(&mv)));
+    }
+    assert_matches::<4, _>(&Y);
+    assert_matches::<4, _>(&CubeSymmetry::mirror(MirrorPlane::M));
+
+    let cube = scrambled::<4>();
+    let mut packed = PackedState::from(&cube);
+    packed.apply(TilePerm::from(&Y));
+    assert_eq!(RubiksState::from(&packed), &cube * &Y);
+}
+
+#[test]
+fn test_is_solved() {
+    let mut packed = PackedState::<3>::solved_in(Western);
+    assert!(packed.is_solved());
+    packed.apply(X);
+    assert!(packed.is_solved());
+    packed.apply_all([R, U]);
+    assert!(!packed.is_solved());
+    assert!(PackedState::<1>::from(&RubiksState::<1>::solved_in(Western)).is_solved());
+}
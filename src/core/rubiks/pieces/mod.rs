@@ -0,0 +1,314 @@
+//! Physical pieces of an N×N×N cube and piece-level cycle analysis.
+//!
+//! Sticker cycles are the natural output of a [`TilePerm<N>`], but they are noisy for
+//! humans: a 3-cycle of corners shows up as three separate 3-cycles of tiles. This
+//! module groups tiles into the physical pieces (cubies) that carry them, and reports
+//! an operation as cycles of pieces with orientation changes, for example
+//! `URF→UBR→ULB, twisted clockwise`.
+//!
+//! # Piece Kinds
+//!
+//! Every cubie on the surface is classified by where it sits, for any N:
+//!
+//! - **Corner**: Three stickers, at a corner of the cube
+//! - **Edge**: Two stickers, the central cubie of an edge on odd cubes (a "midge")
+//! - **Wing**: Two stickers, any other edge cubie
+//! - **Centre**: One sticker, the fixed central cubie of a face on odd cubes
+//! - **XCentre**: One sticker, on a diagonal of the face
+//! - **TCentre**: One sticker, on the central row or column of the face (odd cubes)
+//! - **Oblique**: Any other face cubie
+//!
+//! # Sticker Order and Orientation
+//!
+//! The stickers of a piece are listed starting from its *reference sticker*: the one on
+//! the Up or Down face if there is one, otherwise the one on Front or Back, otherwise
+//! the one on Left or Right. Corner stickers then follow clockwise as seen from outside
+//! the cube, which gives the usual names `URF`, `UFL`, `ULB`, `UBR`, `DFR`, `DLF`,
+//! `DBL` and `DRB`.
+//!
+//! An operation moves the reference sticker of a piece to some sticker of the target
+//! piece; the index of that sticker is the piece's twist (`0`, `1` or `2` for corners,
+//! `0` or `1` for edges and wings). Summing the twists around a cycle gives the net
+//! orientation change of the pieces once the cycle closes.
+//!
+//! # Example
+//!
+//! ```
+//! use rubiks_cube_representation::core::rubiks::moves::BasicMove::*;
+//! use rubiks_cube_representation::core::rubiks::pieces::PieceKind;
+//! use rubiks_cube_representation::core::rubiks::tiles::TilePerm;
+//!
+//! let sune = [R, U, R3, U, R, U2, R3]
+//!     .iter()
+//!     .fold(TilePerm::<3>::ID, |acc, mv| acc * TilePerm::from(mv));
+//! let cycles = sune.piece_cycles().unwrap();
+//!
+//! // Sune 3-cycles the top edges and swaps two pairs of top corners with a twist
+//! let edges: Vec<_> = cycles.iter().filter(|c| c.kind == PieceKind::Edge).collect();
+//! let corners: Vec<_> = cycles.iter().filter(|c| c.kind == PieceKind::Corner).collect();
+//! assert_eq!(edges.len(), 1);
+//! assert_eq!(edges[0].to_string(), "UL→UR→UB");
+//! assert_eq!(corners.len(), 2);
+//! assert!(corners.iter().all(|c| c.pieces.len() == 2 && c.twist != 0));
+//! ```
+
+use std::fmt;
+
+use crate::core::cube::geometry::{Face, Point3};
+use crate::core::rubiks::tiles::{TilePerm, TilePos};
+use crate::error::Error;
+
+#[cfg(test)]
+mod tests;
+
+/// The kind of a physical piece, determined by its position on the cube.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum PieceKind {
+    /// A corner cubie with three stickers
+    Corner,
+    /// The central edge cubie on an odd cube
+    Edge,
+    /// A non-central edge cubie
+    Wing,
+    /// The fixed central cubie of a face on an odd cube
+    Centre,
+    /// A face cubie on one of the face's diagonals
+    XCentre,
+    /// A face cubie on the central row or column of an odd cube's face
+    TCentre,
+    /// Any other face cubie
+    Oblique,
+}
+
+impl PieceKind {
+    /// Number of distinct orientations a piece of this kind can take in place.
+    pub fn orientations(self) -> u8 {
+        match self {
+            PieceKind::Corner => 3,
+            PieceKind::Edge | PieceKind::Wing => 2,
+            _ => 1,
+        }
+    }
+}
+
+/// A physical piece (cubie) on the surface of an N×N×N cube.
+///
+/// # Fields
+///
+/// - **cubie**: Integer coordinates `[x, y, z]` of the cubie, as returned by
+///   [`TilePos::cubie`]
+/// - **kind**: The [`PieceKind`] of the cubie
+/// - **stickers**: The tiles on the cubie, reference sticker first (see the module
+///   documentation for the ordering)
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Piece {
+    /// Integer coordinates of the cubie
+    pub cubie: [usize; 3],
+    /// What kind of piece this is
+    pub kind: PieceKind,
+    /// The piece's stickers, reference sticker first
+    pub stickers: Vec<TilePos>,
+}
+
+impl Piece {
+    /// Returns the piece at the given cubie coordinates, or `None` for interior cubies
+    /// and coordinates outside the cube.
+    ///
+    /// On a 1×1×1 cube the single cubie carries all six stickers; it is classified as a
+    /// [`PieceKind::Centre`] and has no orientation.
+    pub fn at<const N: usize>(cubie: [usize; 3]) -> Option<Piece> {
+        if cubie.iter().any(|&c| c >= N) {
+            return None;
+        }
+        let centre: Point3 = cubie.map(|c| c as f64 + 0.5 - N as f64 / 2.0);
+        let mut faces = Vec::new();
+        for axis in 0..3 {
+            for sign in [-1, 1] {
+                let outer = if sign < 0 { 0 } else { N - 1 };
+                if cubie[axis] == outer {
+                    let mut normal = [0; 3];
+                    normal[axis] = sign;
+                    faces.extend(Face::from_normal(normal));
+                }
+            }
+        }
+        if faces.is_empty() {
+            return None;
+        }
+
+        faces.sort_by_key(|&face| reference_priority(face));
+        if faces.len() == 3 {
+            let [a, b, c] = [faces[0], faces[1], faces[2]].map(Face::normal);
+            let cross = [a[1] * b[2] - a[2] * b[1], a[2] * b[0] - a[0] * b[2], a[0] * b[1] - a[1] * b[0]];
+            if (0..3).map(|i| cross[i] * c[i]).sum::<i32>() > 0 {
+                faces.swap(1, 2);
+            }
+        }
+
+        let stickers = faces
+            .iter()
+            .map(|face| {
+                let normal = face.normal();
+                let point: Point3 = std::array::from_fn(|i| centre[i] + 0.5 * normal[i] as f64);
+                TilePos::at_point::<N>(point, 1e-9).expect("sticker centre lies on the surface")
+            })
+            .collect();
+
+        Some(Piece { cubie, kind: classify::<N>(cubie), stickers })
+    }
+
+    /// Returns the piece carrying a tile.
+    pub fn containing<const N: usize>(tile: TilePos) -> Piece {
+        Piece::at::<N>(tile.cubie::<N>()).expect("every tile lies on a surface cubie")
+    }
+
+    /// Returns every piece of an N×N×N cube, ordered by cubie coordinates.
+    pub fn all<const N: usize>() -> Vec<Piece> {
+        let mut pieces = Vec::new();
+        for x in 0..N {
+            for y in 0..N {
+                for z in 0..N {
+                    pieces.extend(Piece::at::<N>([x, y, z]));
+                }
+            }
+        }
+        pieces
+    }
+
+    /// Returns the piece's name: the face letters of its stickers, in sticker order.
+    ///
+    /// Corners and central edges are named uniquely (`URF`, `UF`). Other kinds share
+    /// names with their siblings, so [`Display`](fmt::Display) adds the reference tile.
+    pub fn name(&self) -> String {
+        self.stickers.iter().map(|tile| tile.face.letter()).collect()
+    }
+}
+
+impl fmt::Display for Piece {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.kind {
+            PieceKind::Corner | PieceKind::Edge => write!(f, "{}", self.name()),
+            _ => {
+                let TilePos { row, col, .. } = self.stickers[0];
+                write!(f, "{}[{row},{col}]", self.name())
+            }
+        }
+    }
+}
+
+/// A cycle of pieces under an operation, with the net orientation change.
+///
+/// The piece at `pieces[i]` moves to the position of `pieces[i + 1]`, and the last
+/// piece moves back to the position of the first. A cycle of length one is a piece
+/// that stays in place but changes orientation.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct PieceCycle {
+    /// The kind shared by every piece in the cycle
+    pub kind: PieceKind,
+    /// The positions visited, in order
+    pub pieces: Vec<Piece>,
+    /// Net orientation change once the cycle closes, from `0` to `orientations - 1`
+    pub twist: u8,
+}
+
+impl fmt::Display for PieceCycle {
+    /// Formats the cycle as e.g. `URF→UBR→ULB, twisted clockwise`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let names = self.pieces.iter().map(ToString::to_string).collect::<Vec<_>>();
+        write!(f, "{}", names.join("→"))?;
+        match (self.kind.orientations(), self.twist) {
+            (_, 0) => Ok(()),
+            (3, 1) => write!(f, ", twisted clockwise"),
+            (3, _) => write!(f, ", twisted anticlockwise"),
+            _ => write!(f, ", flipped"),
+        }
+    }
+}
+
+impl<const N: usize> TilePerm<N> {
+    /// Describes this permutation as cycles of physical pieces.
+    ///
+    /// Pieces that stay in place with their orientation unchanged are omitted. Cycles
+    /// are sorted by piece kind and then by their first piece.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::NotRigid`], naming the offending tile, if the permutation does
+    /// not move pieces rigidly, i.e. if two stickers of the same piece end up on
+    /// different pieces, or if a piece is sent to a piece of a different kind. Every
+    /// move and cube rotation moves pieces rigidly.
+    pub fn piece_cycles(&self) -> Result<Vec<PieceCycle>, Error> {
+        let pieces = Piece::all::<N>();
+        let index_of = |cubie: [usize; 3]| pieces.iter().position(|p| p.cubie == cubie);
+
+        // Where each piece goes, and with which twist
+        let mut images = Vec::with_capacity(pieces.len());
+        for piece in &pieces {
+            let target = Piece::containing::<N>(self[piece.stickers[0]]);
+            if target.kind != piece.kind {
+                return Err(Error::NotRigid { tile: piece.stickers[0] });
+            }
+            for &sticker in &piece.stickers {
+                if !target.stickers.contains(&self[sticker]) {
+                    return Err(Error::NotRigid { tile: sticker });
+                }
+            }
+            let twist = target.stickers.iter().position(|&t| t == self[piece.stickers[0]]).unwrap_or(0);
+            let twist = if piece.kind.orientations() > 1 { twist as u8 } else { 0 };
+            images.push((index_of(target.cubie).expect("target is a surface piece"), twist));
+        }
+
+        let mut visited = vec![false; pieces.len()];
+        let mut cycles = Vec::new();
+        for start in 0..pieces.len() {
+            if visited[start] {
+                continue;
+            }
+            let mut members = Vec::new();
+            let mut twist = 0;
+            let mut current = start;
+            while !visited[current] {
+                visited[current] = true;
+                members.push(pieces[current].clone());
+                twist += images[current].1;
+                current = images[current].0;
+            }
+            let kind = pieces[start].kind;
+            let twist = twist % kind.orientations();
+            if members.len() > 1 || twist != 0 {
+                cycles.push(PieceCycle { kind, pieces: members, twist });
+            }
+        }
+        cycles.sort_by(|a, b| (a.kind, &a.pieces[0]).cmp(&(b.kind, &b.pieces[0])));
+        Ok(cycles)
+    }
+}
+
+/// Classifies a surface cubie by its coordinates.
+fn classify<const N: usize>(cubie: [usize; 3]) -> PieceKind {
+    let on_boundary = |c: usize| c == 0 || c == N - 1;
+    // Twice the distance from the middle of the cube along an axis, in cubies
+    let offset = |c: usize| (2 * c).abs_diff(N - 1);
+    let inner: Vec<usize> = cubie.iter().copied().filter(|&c| !on_boundary(c)).collect();
+    match inner.as_slice() {
+        [] if N == 1 => PieceKind::Centre,
+        [] => PieceKind::Corner,
+        [c] => if offset(*c) == 0 { PieceKind::Edge } else { PieceKind::Wing },
+        [a, b] => match (offset(*a), offset(*b)) {
+            (0, 0) => PieceKind::Centre,
+            (x, y) if x == y => PieceKind::XCentre,
+            (0, _) | (_, 0) => PieceKind::TCentre,
+            _ => PieceKind::Oblique,
+        },
+        _ => unreachable!("surface cubies have at least one coordinate on the boundary"),
+    }
+}
+
+/// Faces on Up/Down come first, then Front/Back, then Left/Right.
+fn reference_priority(face: Face) -> u8 {
+    match face {
+        Face::Up | Face::Down => 0,
+        Face::Front | Face::Back => 1,
+        Face::Left | Face::Right => 2,
+    }
+}
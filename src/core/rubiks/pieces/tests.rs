@@ -0,0 +1,166 @@
+use super::*;
+use crate::core::cube::rotations::Y;
+use crate::core::rubiks::moves::{BasicMove, SliceMove, WideMove};
+use crate::core::rubiks::tiles::TileGrid;
+use crate::FACES;
+
+fn perm<const N: usize>(moves: &[BasicMove<N>]) -> TilePerm<N> {
+    moves.iter().fold(TilePerm::ID, |acc, mv| acc * TilePerm::from(mv))
+}
+
+fn count(kind: PieceKind, pieces: &[Piece]) -> usize {
+    pieces.iter().filter(|p| p.kind == kind).count()
+}
+
+#[test]
+fn test_piece_counts() {
+    use PieceKind::*;
+    let pieces = Piece::all::<3>();
+    assert_eq!(pieces.len(), 26);
+    assert_eq!([count(Corner, &pieces), count(Edge, &pieces), count(Centre, &pieces)], [8, 12, 6]);
+
+    let pieces = Piece::all::<4>();
+    assert_eq!([count(Corner, &pieces), count(Wing, &pieces), count(XCentre, &pieces)], [8, 24, 24]);
+
+    let pieces = Piece::all::<5>();
+    assert_eq!(
+        [Corner, Edge, Wing, Centre, XCentre, TCentre, Oblique].map(|k| count(k, &pieces)),
+        [8, 12, 24, 6, 24, 24, 0]
+    );
+
+    let pieces = Piece::all::<7>();
+    assert_eq!(count(Oblique, &pieces), 48);
+    assert_eq!(count(Wing, &pieces), 48);
+
+    // Every tile belongs to exactly one piece
+    let stickers: usize = pieces.iter().map(|p| p.stickers.len()).sum();
+    assert_eq!(stickers, 6 * 49);
+    assert_eq!(Piece::all::<1>().len(), 1);
+    assert_eq!(Piece::all::<1>()[0].stickers.len(), 6);
+}
+
+#[test]
+fn test_corner_names_are_clockwise() {
+    let mut names: Vec<String> = Piece::all::<3>().iter().filter(|p| p.kind == PieceKind::Corner).map(Piece::name).collect();
+    names.sort();
+    let mut expected = ["URF", "UFL", "ULB", "UBR", "DFR", "DLF", "DBL", "DRB"].map(String::from).to_vec();
+    expected.sort();
+    assert_eq!(names, expected);
+
+    let mut edges: Vec<String> = Piece::all::<3>().iter().filter(|p| p.kind == PieceKind::Edge).map(Piece::name).collect();
+    edges.sort();
+    let mut expected = ["UR", "UF", "UL", "UB", "DR", "DF", "DL", "DB", "FR", "FL", "BL", "BR"].map(String::from).to_vec();
+    expected.sort();
+    assert_eq!(edges, expected);
+}
+
+#[test]
+fn test_stickers_belong_to_their_piece() {
+    for face in FACES {
+        for row in 0..5 {
+            for col in 0..5 {
+                let tile = TilePos { face, row, col };
+                let piece = Piece::containing::<5>(tile);
+                assert!(piece.stickers.contains(&tile), "{tile:?} not on {piece}");
+            }
+        }
+    }
+}
+
+#[test]
+fn test_quarter_turn_cycles() {
+    let u = TilePerm::<3>::from(&BasicMove::<3>::U);
+    let cycles = u.piece_cycles().unwrap();
+    assert_eq!(cycles.len(), 2);
+    assert_eq!(cycles[0].kind, PieceKind::Corner);
+    assert_eq!(cycles[0].pieces.len(), 4);
+    assert_eq!(cycles[0].twist, 0);
+    assert_eq!(cycles[1].kind, PieceKind::Edge);
+    assert_eq!(cycles[1].twist, 0);
+
+    // R twists each corner it moves, but the twists cancel around the 4-cycle
+    let r = TilePerm::<3>::from(&BasicMove::<3>::R).piece_cycles().unwrap();
+    assert!(r.iter().all(|c| c.pieces.len() == 4 && c.twist == 0));
+}
+
+#[test]
+fn test_sune_twists_corners() {
+    use BasicMove::*;
+    let sune = perm::<3>(&[R, U, R3, U, R, U2, R3]);
+    let cycles = sune.piece_cycles().unwrap();
+    let corners: Vec<&PieceCycle> = cycles.iter().filter(|c| c.kind == PieceKind::Corner).collect();
+    assert!(corners.iter().any(|c| c.twist != 0));
+    // Corner orientation is conserved: total twist over all cycles is a multiple of 3
+    let total: u32 = corners.iter().map(|c| c.twist as u32).sum();
+    assert_eq!(total % 3, 0);
+}
+
+#[test]
+fn test_corner_three_cycle() {
+    use BasicMove::*;
+    // A9 commutator [R' D' R, U]: a pure 3-cycle of corners
+    let inner = perm::<3>(&[R3, D3, R]);
+    let u = TilePerm::<3>::from(&U);
+    let comm = &(&(&inner * &u) * &inner.inverse()) * &u.inverse();
+    let cycles = comm.piece_cycles().unwrap();
+    assert_eq!(cycles.len(), 1, "{:?}", cycles.iter().map(ToString::to_string).collect::<Vec<_>>());
+    assert_eq!(cycles[0].kind, PieceKind::Corner);
+    assert_eq!(cycles[0].pieces.len(), 3);
+    // The tile decomposition sees three separate 3-cycles
+    assert_eq!(comm.cycles().len(), 3);
+}
+
+#[test]
+fn test_flip_and_twist_display() {
+    use BasicMove::F;
+    // A single edge staying in place with an odd total flip reads "flipped"
+    let cycle = PieceCycle {
+        kind: PieceKind::Edge,
+        pieces: vec![Piece::at::<3>([1, 2, 2]).unwrap()],
+        twist: 1,
+    };
+    assert_eq!(cycle.to_string(), "UF, flipped");
+
+    let corner = Piece::at::<3>([2, 2, 2]).unwrap();
+    assert_eq!(corner.name(), "URF");
+    let cycle = PieceCycle { kind: PieceKind::Corner, pieces: vec![corner.clone()], twist: 1 };
+    assert_eq!(cycle.to_string(), "URF, twisted clockwise");
+    let cycle = PieceCycle { kind: PieceKind::Corner, pieces: vec![corner], twist: 2 };
+    assert_eq!(cycle.to_string(), "URF, twisted anticlockwise");
+
+    // F moves edges between U/D and F/B reference stickers, but the flips cancel
+    let f = TilePerm::<3>::from(&F).piece_cycles().unwrap();
+    assert!(f.iter().all(|c| c.twist == 0));
+}
+
+#[test]
+fn test_big_cube_moves() {
+    // A slice move on 5x5 cycles wings, t-centres and x-centres
+    let us = TilePerm::<5>::from(&SliceMove::<5>::Us(2)).piece_cycles().unwrap();
+    let kinds: Vec<PieceKind> = us.iter().map(|c| c.kind).collect();
+    assert!(kinds.contains(&PieceKind::Wing));
+    assert!(kinds.contains(&PieceKind::XCentre));
+    assert!(kinds.contains(&PieceKind::TCentre));
+    assert!(!kinds.contains(&PieceKind::Corner));
+
+    let uw = TilePerm::<4>::from(&WideMove::<4>::Uw(2)).piece_cycles().unwrap();
+    assert!(uw.iter().all(|c| c.pieces.len() == 4));
+
+    // Whole-cube rotations move centres too
+    let y = TilePerm::<3>::from(&Y).piece_cycles().unwrap();
+    assert!(y.iter().any(|c| c.kind == PieceKind::Centre && c.pieces.len() == 4));
+}
+
+#[test]
+fn test_non_rigid_permutation_is_rejected() {
+    // Swap two stickers of the same corner with a sticker of another corner
+    let mut p = TilePerm::<2>::ID;
+    let a = TilePos { face: Face::Up, row: 0, col: 0 };
+    let b = TilePos { face: Face::Up, row: 0, col: 1 };
+    let swap = |grid: &mut TileGrid<2>| {
+        grid.vals[0][0] = b;
+        grid.vals[0][1] = a;
+    };
+    swap(&mut p.up);
+    assert!(p.piece_cycles().is_err());
+}
@@ -0,0 +1,162 @@
+//! Fixtures shared by the tests of the modules that apply moves and operations.
+
+use std::fmt::Debug;
+use std::ops::Mul;
+
+use crate::core::cube::geometry::FACES;
+use crate::core::cube::rotations::{CubeRotation, FacePerm, X};
+use crate::core::rubiks::algs::Alg;
+use crate::core::rubiks::dynamic::DynMove;
+use crate::core::rubiks::moves::{BasicMove, MiddleMove, RangeMove, SliceMove, WideMove};
+use crate::core::rubiks::tiles::in_place::ApplyInPlace;
+use crate::core::rubiks::tiles::motion::LayerTurn;
+use crate::core::cube::geometry::FACE_SIDES;
+use crate::core::rubiks::tiles::partial::PartialTilePerm;
+use crate::core::rubiks::tiles::restrictions::Slice;
+use crate::core::rubiks::tiles::{TilePerm, TilePos};
+use crate::core::rubiks::RubiksState;
+use crate::core::Angle;
+use crate::{Face, Western};
+
+/// A move of any of the five families, for checks written once over all of them.
+pub(crate) trait Move<const N: usize>:
+    Copy + Debug + Into<TilePerm<N>> + Into<LayerTurn> + Into<DynMove> + ApplyInPlace<N>
+{
+}
+
+impl<const N: usize> Move<N> for BasicMove<N> {}
+
+impl<const N: usize> Move<N> for WideMove<N> {}
+
+impl<const N: usize> Move<N> for SliceMove<N> {}
+
+impl<const N: usize> Move<N> for RangeMove<N> {}
+
+impl<const N: usize> Move<N> for MiddleMove<N> {}
+
+/// The non-zero turn amounts.
+pub(crate) const AMOUNTS: [Angle; 3] = [Angle::CWQuarter, Angle::Half, Angle::ACWQuarter];
+
+/// An N×N×N state with every kind of sticker out of place, so that misplaced stickers
+/// show.
+pub(crate) fn scrambled<const N: usize>() -> RubiksState<N> {
+    let alg: Alg<BasicMove<N>> = "R U2 F' L D B2 R' U".parse().unwrap();
+    RubiksState::<N>::solved_in(Western) * TilePerm::from(&alg) * &X
+}
+
+/// Checks that `apply`, applying `op` by some other route, agrees with `&state * op` on
+/// the scrambled state.
+pub(crate) fn assert_applies_like_operator<const N: usize, Op>(op: &Op, apply: impl Fn(&mut RubiksState<N>, &Op))
+where
+    for<'a> &'a RubiksState<N>: Mul<&'a Op, Output = RubiksState<N>>,
+{
+    let mut state = scrambled::<N>();
+    apply(&mut state, op);
+    assert_eq!(state, &scrambled::<N>() * op);
+}
+
+/// The layers a move of [`check_all_moves`] turns, taken from the loops that built it
+/// rather than from any conversion.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct Layers {
+    pub(crate) face: Face,
+    pub(crate) amount: Angle,
+    pub(crate) start: usize,
+    pub(crate) end: usize,
+}
+
+/// A check to run on moves of every family.
+pub(crate) trait MoveCheck<const N: usize> {
+    fn check<M: Move<N>>(&mut self, mv: M, layers: Layers);
+}
+
+/// Runs a check on every move of every family on an N×N×N cube.
+pub(crate) fn check_all_moves<const N: usize>(check: &mut impl MoveCheck<N>) {
+    for face in FACES {
+        for amount in AMOUNTS {
+            let layers = |start, end| Layers { face, amount, start, end };
+            check.check(BasicMove::<N>::from_parts(face, amount), layers(1, 1));
+            for layer in 1..=N {
+                check.check(WideMove::<N>::from_parts(face, amount, layer), layers(1, layer));
+                check.check(SliceMove::<N>::from_parts(face, amount, layer), layers(layer, layer));
+                for end in layer..=N {
+                    check.check(RangeMove::<N>::from_parts(face, amount, layer, end), layers(layer, end));
+                }
+            }
+        }
+    }
+    if N % 2 == 1 {
+        for face in [Face::Left, Face::Down, Face::Front] {
+            for amount in AMOUNTS {
+                let layers = Layers { face, amount, start: N / 2 + 1, end: N / 2 + 1 };
+                check.check(MiddleMove::<N>::from_parts(face, amount), layers);
+            }
+        }
+    }
+}
+
+/// The tiles of a face rotated in place, from [`Angle::rotate_indices`].
+fn rotate_face_only<const N: usize>(face: Face, angle: Angle) -> PartialTilePerm<N> {
+    let rotated = |row, col| {
+        let (row, col) = angle.rotate_indices::<N>(row, col);
+        TilePos { face, row, col }
+    };
+    PartialTilePerm((0..N).flat_map(|row| (0..N).map(move |col| (TilePos { face, row, col }, rotated(row, col)))).collect())
+}
+
+/// The ring of tiles a slice carries around the four faces beside it, each moved to
+/// the side of the slice's face that `angle` turns its own side to.
+fn rotate_outside_of_slice<const N: usize>(slice: Slice, angle: Angle) -> PartialTilePerm<N> {
+    let Slice { face, slice_index } = slice;
+    let adjacencies = face.adjacencies();
+    let map = FACE_SIDES
+        .iter()
+        .flat_map(move |&side| {
+            (0..N).map(move |index| {
+                (
+                    adjacencies.on_side(side).side_pos_at_depth::<N>(index, slice_index),
+                    adjacencies.on_side(side * angle).side_pos_at_depth::<N>(index, slice_index),
+                )
+            })
+        })
+        .collect();
+    PartialTilePerm(map)
+}
+
+/// The permutation turning some layers, assembled from partial face and ring rotations
+/// as the conversions did before the `motion` arithmetic, to check it against.
+pub(crate) fn reference_turn<const N: usize>(layers: Layers) -> TilePerm<N> {
+    let Layers { face, amount, start, end } = layers;
+    let mut combined = PartialTilePerm::new();
+    for slice_index in start - 1..end {
+        let face_perm = if slice_index == 0 {
+            rotate_face_only::<N>(face, amount)
+        } else if slice_index == N - 1 {
+            rotate_face_only::<N>(face.opposite(), Angle::Zero - amount)
+        } else {
+            PartialTilePerm::new()
+        };
+        let ring = rotate_outside_of_slice::<N>(Slice { face, slice_index }, amount);
+        combined = &combined * &(&face_perm * &ring);
+    }
+    combined.to_tile_perm()
+}
+
+/// The permutation of a rotation, from where it sends each face and that face's
+/// principal diagonal rather than from its matrix.
+pub(crate) fn reference_rotation<const N: usize>(rotation: CubeRotation) -> TilePerm<N> {
+    TilePerm::from_fn(|tile| {
+        let face = FacePerm::from(rotation)[tile.face];
+        // Diagonals are measured in quarter turns around the face from its ULF diagonal
+        let (d2, d3, d4) = face.diag_orientation_following_ulf();
+        let position = |diag| match diag {
+            _ if diag == d2 => Angle::CWQuarter,
+            _ if diag == d3 => Angle::Half,
+            _ if diag == d4 => Angle::ACWQuarter,
+            _ => Angle::Zero,
+        };
+        let turn = position(rotation[tile.face.principal_diag()]) - position(face.principal_diag());
+        let (row, col) = turn.rotate_indices::<N>(tile.row, tile.col);
+        TilePos { face, row, col }
+    })
+}
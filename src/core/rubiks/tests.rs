@@ -121,21 +121,21 @@ fn test_1x1_cube_rotations() {
 fn test_2x2_cube_x_rotation() {
     use Colour::*;
     let initial = RubiksState {
-        up: FaceState { vals: [[Orange, Red], [Yellow, White]] },
-        down: FaceState { vals: [[Blue, Green], [Red, Orange]] },
-        left: FaceState { vals: [[White, Yellow], [Blue, Green]] },
-        right: FaceState { vals: [[Green, Blue], [Orange, Red]] },
-        front: FaceState { vals: [[Yellow, White], [Green, Blue]] },
-        back: FaceState { vals: [[Red, Orange], [White, Yellow]] },
+        up: FaceState { vals: [[Orange, Red], [Yellow, White]] }.into(),
+        down: FaceState { vals: [[Blue, Green], [Red, Orange]] }.into(),
+        left: FaceState { vals: [[White, Yellow], [Blue, Green]] }.into(),
+        right: FaceState { vals: [[Green, Blue], [Orange, Red]] }.into(),
+        front: FaceState { vals: [[Yellow, White], [Green, Blue]] }.into(),
+        back: FaceState { vals: [[Red, Orange], [White, Yellow]] }.into(),
     };
     
     let expected_after_x = RubiksState {
-        up: FaceState { vals: [[Yellow, White], [Green, Blue]] },
-        down: FaceState { vals: [[Red, Orange], [White, Yellow]] },
-        left: FaceState { vals: [[Yellow, Green], [White, Blue]] },
-        right: FaceState { vals: [[Orange, Green], [Red, Blue]] },
-        front: FaceState { vals: [[Blue, Green], [Red, Orange]] },
-        back: FaceState { vals: [[Orange, Red], [Yellow, White]] },
+        up: FaceState { vals: [[Yellow, White], [Green, Blue]] }.into(),
+        down: FaceState { vals: [[Red, Orange], [White, Yellow]] }.into(),
+        left: FaceState { vals: [[Yellow, Green], [White, Blue]] }.into(),
+        right: FaceState { vals: [[Orange, Green], [Red, Blue]] }.into(),
+        front: FaceState { vals: [[Blue, Green], [Red, Orange]] }.into(),
+        back: FaceState { vals: [[Orange, Red], [Yellow, White]] }.into(),
     };
 
     assert_eq!(&initial * &X, expected_after_x);
@@ -145,21 +145,21 @@ fn test_2x2_cube_x_rotation() {
 fn test_2x2_cube_y_rotation() {
     use Colour::*;
     let initial = RubiksState {
-        up: FaceState { vals: [[Orange, Red], [Yellow, White]] },
-        down: FaceState { vals: [[Blue, Green], [Red, Orange]] },
-        left: FaceState { vals: [[White, Yellow], [Blue, Green]] },
-        right: FaceState { vals: [[Green, Blue], [Orange, Red]] },
-        front: FaceState { vals: [[Yellow, White], [Green, Blue]] },
-        back: FaceState { vals: [[Red, Orange], [White, Yellow]] },
+        up: FaceState { vals: [[Orange, Red], [Yellow, White]] }.into(),
+        down: FaceState { vals: [[Blue, Green], [Red, Orange]] }.into(),
+        left: FaceState { vals: [[White, Yellow], [Blue, Green]] }.into(),
+        right: FaceState { vals: [[Green, Blue], [Orange, Red]] }.into(),
+        front: FaceState { vals: [[Yellow, White], [Green, Blue]] }.into(),
+        back: FaceState { vals: [[Red, Orange], [White, Yellow]] }.into(),
     };
     
     let expected_after_y = RubiksState {
-        up: FaceState { vals: [[Yellow, Orange], [White, Red]] },
-        down: FaceState { vals: [[Green, Orange], [Blue, Red]] },
-        left: FaceState { vals: [[Yellow, White], [Green, Blue]] },
-        right: FaceState { vals: [[Yellow, White], [Orange, Red]] },
-        front: FaceState { vals: [[Green, Blue], [Orange, Red]] },
-        back: FaceState { vals: [[Green, Blue], [Yellow, White]] },
+        up: FaceState { vals: [[Yellow, Orange], [White, Red]] }.into(),
+        down: FaceState { vals: [[Green, Orange], [Blue, Red]] }.into(),
+        left: FaceState { vals: [[Yellow, White], [Green, Blue]] }.into(),
+        right: FaceState { vals: [[Yellow, White], [Orange, Red]] }.into(),
+        front: FaceState { vals: [[Green, Blue], [Orange, Red]] }.into(),
+        back: FaceState { vals: [[Green, Blue], [Yellow, White]] }.into(),
     };
 
     assert_eq!(&initial * &Y, expected_after_y);
@@ -169,21 +169,21 @@ fn test_2x2_cube_y_rotation() {
 fn test_2x2_cube_z_rotation() {
     use Colour::*;
     let initial = RubiksState {
-        up: FaceState { vals: [[Orange, Red], [Yellow, White]] },
-        down: FaceState { vals: [[Blue, Green], [Red, Orange]] },
-        left: FaceState { vals: [[White, Yellow], [Blue, Green]] },
-        right: FaceState { vals: [[Green, Blue], [Orange, Red]] },
-        front: FaceState { vals: [[Yellow, White], [Green, Blue]] },
-        back: FaceState { vals: [[Red, Orange], [White, Yellow]] },
+        up: FaceState { vals: [[Orange, Red], [Yellow, White]] }.into(),
+        down: FaceState { vals: [[Blue, Green], [Red, Orange]] }.into(),
+        left: FaceState { vals: [[White, Yellow], [Blue, Green]] }.into(),
+        right: FaceState { vals: [[Green, Blue], [Orange, Red]] }.into(),
+        front: FaceState { vals: [[Yellow, White], [Green, Blue]] }.into(),
+        back: FaceState { vals: [[Red, Orange], [White, Yellow]] }.into(),
     };
     
     let expected_after_z = RubiksState {
-        up: FaceState { vals: [[Blue, White], [Green, Yellow]] },
-        down: FaceState { vals: [[Orange, Green], [Red, Blue]] },
-        left: FaceState { vals: [[Red, Blue], [Orange, Green]] },
-        right: FaceState { vals: [[Yellow, Orange], [White, Red]] },
-        front: FaceState { vals: [[Green, Yellow], [Blue, White]] },
-        back: FaceState { vals: [[Orange, Yellow], [Red, White]] },
+        up: FaceState { vals: [[Blue, White], [Green, Yellow]] }.into(),
+        down: FaceState { vals: [[Orange, Green], [Red, Blue]] }.into(),
+        left: FaceState { vals: [[Red, Blue], [Orange, Green]] }.into(),
+        right: FaceState { vals: [[Yellow, Orange], [White, Red]] }.into(),
+        front: FaceState { vals: [[Green, Yellow], [Blue, White]] }.into(),
+        back: FaceState { vals: [[Orange, Yellow], [Red, White]] }.into(),
     };
 
     assert_eq!(&initial * &Z, expected_after_z);
@@ -385,4 +385,65 @@ fn test_middle_moves_agree_with_rotations_on_slices() {
             }
         }
     }
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_large_state_fits_thread_stack() {
+    // 6·601² one-byte colours are about 2.1 MB, more than the 2 MiB stack of a spawned
+    // thread, so every state and temporary face must live on the heap
+    let worker = std::thread::Builder::new().stack_size(2 * 1024 * 1024).spawn(|| {
+        let cube = RubiksState::<601>::solved_in(Western);
+        let turned = &cube * &BasicMove::<601>::R;
+        assert!(!turned.is_solved_in(Western));
+        let packed = crate::core::rubiks::packed::PackedState::from(&turned);
+        assert_eq!(RubiksState::from(&packed), turned);
+        let dynamic = crate::core::rubiks::dynamic::DynRubiksState::from(&turned);
+        assert_eq!(RubiksState::<601>::try_from(&dynamic).as_ref(), Ok(&turned));
+    });
+    worker.unwrap().join().unwrap();
+}
+
+#[cfg(feature = "log")]
+#[test]
+fn test_orientation_is_logged() {
+    use std::sync::Mutex;
+    use std::thread::{self, ThreadId};
+
+    #[derive(Clone)]
+    struct Entry {
+        thread: ThreadId,
+        level: log::Level,
+        message: String,
+        solved: Option<String>,
+    }
+
+    struct Capture(Mutex<Vec<Entry>>);
+
+    impl log::Log for Capture {
+        fn enabled(&self, _: &log::Metadata) -> bool {
+            true
+        }
+
+        fn log(&self, record: &log::Record) {
+            self.0.lock().unwrap().push(Entry {
+                thread: thread::current().id(),
+                level: record.level(),
+                message: record.args().to_string(),
+                solved: record.key_values().get(log::kv::Key::from("solved")).map(|value| value.to_string()),
+            });
+        }
+
+        fn flush(&self) {}
+    }
+
+    static CAPTURE: Capture = Capture(Mutex::new(Vec::new()));
+    log::set_logger(&CAPTURE).unwrap();
+    log::set_max_level(log::LevelFilter::Trace);
+
+    assert!(RubiksState::<3>::solved_in(Western.rotated(X * Y)).is_solved_up_to_rotation_in(Western));
+    // Other tests run on other threads and may log too
+    let records: Vec<Entry> = CAPTURE.0.lock().unwrap().iter().filter(|entry| entry.thread == thread::current().id()).cloned().collect();
+    assert!(records.iter().any(|entry| entry.level == log::Level::Trace && entry.message == "rotated colour scheme"));
+    let detected = records.iter().find(|entry| entry.message == "detected orientation").unwrap();
+    assert_eq!((detected.level, detected.solved.as_deref()), (log::Level::Debug, Some("true")));
+}
@@ -0,0 +1,173 @@
+//! Cycle structure of tile permutations.
+//!
+//! Every permutation of the 6·N² tiles splits into disjoint cycles: the tile at `p`
+//! moves to `perm[p]`, which moves to `perm[perm[p]]`, and so on until the orbit closes.
+//! This module exposes that structure on [`TilePerm<N>`] together with the invariants
+//! derived from it.
+//!
+//! # Provided Methods
+//!
+//! - [`TilePerm::cycles`]: The non-trivial disjoint cycles, as [`TileCycle`]s
+//! - [`TilePerm::order`]: Least common multiple of the cycle lengths
+//! - [`TilePerm::sign`]: `+1` for even permutations and `-1` for odd ones
+//! - [`TilePerm::support`]: The set of moved tiles, as a [`TileSet`] restriction
+//!
+//! `TilePerm<N>` also implements [`Display`](fmt::Display) in cycle notation.
+//!
+//! # Notation
+//!
+//! A tile is written as its face letter followed by `[row,col]`, so `U[0,2]` is the
+//! top-right sticker of the Up face. A cycle lists tiles in the order they move:
+//! `(U[0,0] R[0,0] F[0,0])` sends `U[0,0]` to `R[0,0]`, `R[0,0]` to `F[0,0]` and
+//! `F[0,0]` back to `U[0,0]`. The identity is written `()`.
+//!
+//! # Example
+//!
+//! ```
+//! use rubiks_cube_representation::core::rubiks::moves::BasicMove;
+//! use rubiks_cube_representation::core::rubiks::tiles::TilePerm;
+//!
+//! let u = TilePerm::<3>::from(&BasicMove::<3>::U);
+//! assert_eq!(u.order(), 4);
+//! assert_eq!(u.cycles().len(), 5); // two on the face, three around it
+//! assert_eq!(u.sign(), -1); // five 4-cycles: an odd permutation
+//! assert_eq!(u.support().len(), 20);
+//! ```
+
+use std::collections::BTreeMap;
+use std::fmt;
+
+use crate::algorithms::bignum::BigUint;
+
+use super::restrictions::TileSet;
+use super::{TilePerm, TilePos};
+
+#[cfg(test)]
+mod tests;
+
+/// A single cycle of a tile permutation.
+///
+/// The tiles are listed in the order they move, starting from the smallest tile in the
+/// cycle (by the derived [`Ord`] on [`TilePos`]), so equal cycles compare equal.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct TileCycle(pub Vec<TilePos>);
+
+impl TileCycle {
+    /// Number of tiles in the cycle.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns whether the cycle is empty.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl fmt::Display for TileCycle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "(")?;
+        for (i, tile) in self.0.iter().enumerate() {
+            if i > 0 {
+                write!(f, " ")?;
+            }
+            write_tile(f, *tile)?;
+        }
+        write!(f, ")")
+    }
+}
+
+impl<const N: usize> TilePerm<N> {
+    /// Returns the disjoint cycle decomposition, omitting fixed tiles.
+    ///
+    /// Cycles are sorted by their first (smallest) tile.
+    ///
+    /// # Algorithm
+    ///
+    /// Walk the tiles in index order, following `perm[p]` from each tile not yet
+    /// visited until the walk returns to its start. Each tile is visited once, so the
+    /// decomposition takes O(N²) time.
+    pub fn cycles(&self) -> Vec<TileCycle> {
+        let mut visited = vec![false; 6 * N * N];
+        let mut cycles = Vec::new();
+        for start in TilePos::all::<N>() {
+            if visited[start.index::<N>()] {
+                continue;
+            }
+            let mut cycle = vec![start];
+            visited[start.index::<N>()] = true;
+            let mut next = self[start];
+            while next != start {
+                visited[next.index::<N>()] = true;
+                cycle.push(next);
+                next = self[next];
+            }
+            if cycle.len() > 1 {
+                cycles.push(TileCycle(cycle));
+            }
+        }
+        cycles
+    }
+
+    /// Returns the order of the permutation: the smallest `k ≥ 1` with `perm^k = ID`.
+    ///
+    /// This is the least common multiple of the cycle lengths, built as the product of
+    /// the highest power of each prime dividing one of them. It is a [`BigUint`] because
+    /// on large cubes it can exceed any fixed-width integer.
+    pub fn order(&self) -> BigUint {
+        let mut powers: BTreeMap<usize, usize> = BTreeMap::new();
+        for cycle in self.cycles() {
+            let mut len = cycle.len();
+            let mut prime = 2;
+            while len > 1 {
+                if prime * prime > len {
+                    prime = len;
+                }
+                let mut power = 1;
+                while len.is_multiple_of(prime) {
+                    len /= prime;
+                    power *= prime;
+                }
+                let highest = powers.entry(prime).or_insert(1);
+                *highest = (*highest).max(power);
+                prime += 1;
+            }
+        }
+        powers.into_values().map(BigUint::from).product()
+    }
+
+    /// Returns the sign of the permutation: `1` if it is even and `-1` if it is odd.
+    ///
+    /// A cycle of length `k` is a product of `k - 1` transpositions, so the sign is
+    /// `(-1)^Σ(k - 1)` over all cycles.
+    pub fn sign(&self) -> i8 {
+        let transpositions: usize = self.cycles().iter().map(|cycle| cycle.len() - 1).sum();
+        if transpositions.is_multiple_of(2) { 1 } else { -1 }
+    }
+
+    /// Returns the set of tiles moved by the permutation.
+    ///
+    /// The result is a [`Restriction`](super::restrictions::Restriction), so it can be
+    /// passed to [`TilePerm::agree_on`] to compare permutations on the moved tiles only.
+    pub fn support(&self) -> TileSet<N> {
+        TilePos::all::<N>().filter(|&tile| self[tile] != tile).collect()
+    }
+}
+
+impl<const N: usize> fmt::Display for TilePerm<N> {
+    /// Formats the permutation in cycle notation, e.g. `(U[0,0] R[0,0] F[0,0])`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let cycles = self.cycles();
+        if cycles.is_empty() {
+            return write!(f, "()");
+        }
+        for cycle in cycles {
+            write!(f, "{cycle}")?;
+        }
+        Ok(())
+    }
+}
+
+fn write_tile(f: &mut fmt::Formatter<'_>, TilePos { face, row, col }: TilePos) -> fmt::Result {
+    write!(f, "{}[{row},{col}]", face.letter())
+}
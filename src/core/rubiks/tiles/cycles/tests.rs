@@ -0,0 +1,107 @@
+use super::*;
+use crate::core::cube::rotations::{X, Y};
+use crate::core::rubiks::moves::{BasicMove, MiddleMove, SliceMove};
+use crate::core::rubiks::tiles::indexed::IndexPerm;
+use crate::core::rubiks::tiles::restrictions::{Restriction, Slice};
+use crate::Face;
+
+fn perm<const N: usize>(moves: &[BasicMove<N>]) -> TilePerm<N> {
+    moves.iter().fold(TilePerm::ID, |acc, mv| acc * TilePerm::from(mv))
+}
+
+#[test]
+fn test_identity_has_no_cycles() {
+    let id = TilePerm::<3>::ID;
+    assert!(id.cycles().is_empty());
+    assert_eq!(id.order(), 1);
+    assert_eq!(id.sign(), 1);
+    assert!(id.support().is_empty());
+    assert_eq!(id.to_string(), "()");
+}
+
+#[test]
+fn test_cycles_follow_the_permutation() {
+    let p = perm::<4>(&[BasicMove::R, BasicMove::U, BasicMove::F2]);
+    let cycles = p.cycles();
+    let moved: usize = cycles.iter().map(TileCycle::len).sum();
+    assert_eq!(moved, p.support().len());
+    for cycle in &cycles {
+        assert!(cycle.len() > 1);
+        assert_eq!(cycle.0[0], *cycle.0.iter().min().unwrap());
+        for (i, &tile) in cycle.0.iter().enumerate() {
+            assert_eq!(p[tile], cycle.0[(i + 1) % cycle.len()]);
+        }
+    }
+    assert!(cycles.windows(2).all(|w| w[0].0[0] < w[1].0[0]));
+}
+
+#[test]
+fn test_orders_of_known_algorithms() {
+    use BasicMove::*;
+    assert_eq!(TilePerm::<3>::from(&U).order(), 4);
+    assert_eq!(TilePerm::<3>::from(&U2).order(), 2);
+    // The sexy move has order 6 and R U has order 105
+    assert_eq!(perm::<3>(&[R, U, R3, U3]).order(), 6);
+    assert_eq!(perm::<3>(&[R, U]).order(), 105);
+    assert_eq!(TilePerm::<3>::from(&(X * Y)).order(), 3);
+    assert_eq!(TilePerm::<5>::from(&SliceMove::<5>::Us(2)).order(), 4);
+
+    // p^order is the identity
+    let p = perm::<3>(&[R, U]);
+    let power = (0..105).fold(TilePerm::ID, |acc, _| acc * &p);
+    assert_eq!(power, TilePerm::ID);
+}
+
+#[test]
+fn test_order_beyond_u128() {
+    // One cycle for each prime up to 103: the order is their product, about 2.4·10⁴⁰
+    let primes: Vec<usize> = (2..=103).filter(|&n| (2..n).all(|d| n % d != 0)).collect();
+    let mut images: Vec<u16> = (0..6 * 15 * 15).collect();
+    let mut start = 0;
+    for &prime in &primes {
+        images[start..start + prime].rotate_left(1);
+        start += prime;
+    }
+    let p = TilePerm::<15>::from(IndexPerm::<15>::from_images(images).unwrap());
+    let expected: BigUint = primes.iter().map(|&prime| BigUint::from(prime)).product();
+    assert_eq!(p.order(), expected);
+    assert_eq!(p.order().to_u128(), None);
+}
+
+#[test]
+fn test_sign() {
+    // A quarter turn of the outer layer on 3×3 is two 4-cycles on the face plus three
+    // 4-cycles around it: five odd cycles, so odd overall
+    assert_eq!(TilePerm::<3>::from(&BasicMove::<3>::U).sign(), -1);
+    assert_eq!(TilePerm::<3>::from(&BasicMove::<3>::U2).sign(), 1);
+    assert_eq!(TilePerm::<3>::from(&MiddleMove::<3>::M).sign(), -1);
+
+    let p = perm::<3>(&[BasicMove::R, BasicMove::U]);
+    let q = perm::<3>(&[BasicMove::F, BasicMove::D2, BasicMove::L]);
+    assert_eq!((&p * &q).sign(), p.sign() * q.sign());
+    assert_eq!(p.inverse().sign(), p.sign());
+}
+
+#[test]
+fn test_support_is_a_restriction() {
+    let u = TilePerm::<3>::from(&BasicMove::<3>::U);
+    let support = u.support();
+    let top = Slice { face: Face::Up, slice_index: 0 };
+    // The centre of Up is fixed, everything else in the top slice moves
+    assert_eq!(support.len(), <Slice as Restriction<3>>::restricted_positions(&top).count() - 1);
+    assert!(!support.contains(&TilePos { face: Face::Up, row: 1, col: 1 }));
+    assert!(u.agree_on(&TilePerm::ID, TileSet::<3>::from_iter(TilePos::all::<3>().filter(|t| !support.contains(t)))));
+    assert!(!u.agree_on(&TilePerm::ID, support.clone()));
+    assert!(u.agree_on(&u, support));
+}
+
+#[test]
+fn test_cycle_notation() {
+    let u = TilePerm::<2>::from(&BasicMove::<2>::U);
+    let text = u.to_string();
+    assert!(text.starts_with("(U[0,0] U[0,1] U[1,1] U[1,0])"), "{text}");
+    assert_eq!(text.matches('(').count(), u.cycles().len());
+
+    let y = TilePerm::<1>::from(&Y);
+    assert_eq!(y.to_string(), "(L[0,0] B[0,0] R[0,0] F[0,0])");
+}
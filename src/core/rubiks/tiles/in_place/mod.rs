@@ -0,0 +1,220 @@
+//! Applying operations to a cube state in place.
+//!
+//! `&cube * &op` builds a new [`RubiksState`] and inverts the operation's whole
+//! [`TilePerm`] first. That is the right shape for algebra, but wasteful in a
+//! simulation applying millions of moves. The [`ApplyInPlace`] trait mutates the state
+//! instead, through [`RubiksState::apply`] and [`RubiksState::apply_all`].
+//!
+//! # Algorithm
+//!
+//! - **Moves** touch only the stickers they move: the stickers of the turned layers
+//!   (the turned face and the rings of the turned layers) are read out of the state and
+//!   written back at their destinations, computed by the [`motion`](super::motion)
+//!   module without hashing, leaving every other sticker alone.
+//! - **Full permutations** ([`TilePerm`], [`IndexPerm`], rotations and symmetries) are
+//!   applied cycle by cycle, carrying one sticker around each cycle, so no inverse and
+//!   no second state are needed.
+//! - **Algorithms** apply their expanded moves one by one.
+//!
+//! In every case the result is identical to the operator form. Each operation is written
+//! once, against a crate-internal sticker store that [`RubiksState`] implements, so
+//! other representations of the stickers can share it.
+//!
+//! # Example
+//!
+//! ```
+//! use rubiks_cube_representation::core::rubiks::moves::BasicMove::*;
+//! use rubiks_cube_representation::{RubiksState, Western};
+//!
+//! let mut cube = RubiksState::<3>::solved_in(Western);
+//! cube.apply_all([R, U, R3, U3]);
+//! assert_eq!(cube, RubiksState::<3>::solved_in(Western) * &R * &U * &R3 * &U3);
+//!
+//! for _ in 0..5 {
+//!     cube.apply_all(&[R, U, R3, U3]);
+//! }
+//! assert!(cube.is_solved());
+//! ```
+
+use crate::core::cube::rotations::CubeRotation;
+use crate::core::cube::symmetries::CubeSymmetry;
+use crate::core::rubiks::algs::{Alg, Turn};
+use crate::core::rubiks::moves::{BasicMove, MiddleMove, RangeMove, SliceMove, WideMove};
+use crate::core::rubiks::RubiksState;
+
+use super::indexed::IndexPerm;
+use super::motion::LayerTurn;
+use super::partial::PartialTilePerm;
+use super::{TilePerm, TilePos};
+
+#[cfg(test)]
+mod tests;
+
+/// An operation that can move the stickers of a [`RubiksState`] in place.
+///
+/// Implemented for moves of every family, [`TilePerm`], [`IndexPerm`],
+/// [`PartialTilePerm`], rotations, symmetries, algorithms and references to any of them,
+/// and sealed to those.
+pub trait ApplyInPlace<const N: usize>: StickerOperation<N> {
+    /// Moves the sticker on each tile of `state` to the tile's image.
+    fn apply_in_place(&self, state: &mut RubiksState<N>);
+}
+
+impl<const N: usize> RubiksState<N> {
+    /// Applies an operation to this state in place, with the same result as
+    /// `&state * op`.
+    pub fn apply(&mut self, op: impl ApplyInPlace<N>) {
+        op.apply_in_place(self);
+    }
+
+    /// Applies a sequence of operations in order, as repeated [`RubiksState::apply`].
+    pub fn apply_all<Op: ApplyInPlace<N>>(&mut self, ops: impl IntoIterator<Item = Op>) {
+        for op in ops {
+            op.apply_in_place(self);
+        }
+    }
+}
+
+impl<const N: usize, Op: StickerOperation<N>> ApplyInPlace<N> for Op {
+    fn apply_in_place(&self, state: &mut RubiksState<N>) {
+        self.apply_to(state);
+    }
+}
+
+pub(crate) use sealed::{StickerOperation, Stickers};
+
+// Public so that `ApplyInPlace` may name them, in a private module so that nothing
+// outside the crate can
+mod sealed {
+    use crate::core::rubiks::tiles::TilePos;
+
+    /// A store of stickers that operations can rearrange, such as a [`RubiksState`].
+    ///
+    /// [`RubiksState`]: crate::RubiksState
+    pub trait Stickers<const N: usize> {
+        /// Moves the sticker of each `from` tile to its `to` tile, reading them all first.
+        fn move_stickers(&mut self, moves: impl Iterator<Item = (TilePos, TilePos)>);
+
+        /// Moves the sticker of every tile to its image under a full permutation.
+        fn permute(&mut self, image: impl Fn(TilePos) -> TilePos);
+    }
+
+    /// An operation, written once against [`Stickers`] and so applied alike to every
+    /// kind of store: each move family moves the tiles of its layer turn, and whole-cube
+    /// operations permute every tile.
+    pub trait StickerOperation<const N: usize> {
+        fn apply_to(&self, stickers: &mut impl Stickers<N>);
+    }
+
+    impl<const N: usize, Op: StickerOperation<N>> StickerOperation<N> for &Op {
+        fn apply_to(&self, stickers: &mut impl Stickers<N>) {
+            (**self).apply_to(stickers);
+        }
+    }
+}
+
+impl<const N: usize> StickerOperation<N> for PartialTilePerm<N> {
+    fn apply_to(&self, stickers: &mut impl Stickers<N>) {
+        stickers.move_stickers(self.iter().filter(|(from, to)| from != to));
+    }
+}
+
+impl<const N: usize> StickerOperation<N> for TilePerm<N> {
+    fn apply_to(&self, stickers: &mut impl Stickers<N>) {
+        stickers.permute(|tile| self[tile]);
+    }
+}
+
+impl<const N: usize> StickerOperation<N> for IndexPerm<N> {
+    fn apply_to(&self, stickers: &mut impl Stickers<N>) {
+        stickers.permute(|tile| self.image(tile));
+    }
+}
+
+impl<const N: usize> StickerOperation<N> for CubeRotation {
+    fn apply_to(&self, stickers: &mut impl Stickers<N>) {
+        TilePerm::<N>::from(self).apply_to(stickers);
+    }
+}
+
+impl<const N: usize> StickerOperation<N> for CubeSymmetry {
+    fn apply_to(&self, stickers: &mut impl Stickers<N>) {
+        TilePerm::<N>::from(self).apply_to(stickers);
+    }
+}
+
+impl<const N: usize> StickerOperation<N> for BasicMove<N> {
+    fn apply_to(&self, stickers: &mut impl Stickers<N>) {
+        stickers.move_stickers(turned(LayerTurn::from(*self), N));
+    }
+}
+
+impl<const N: usize> StickerOperation<N> for WideMove<N> {
+    fn apply_to(&self, stickers: &mut impl Stickers<N>) {
+        stickers.move_stickers(turned(LayerTurn::from(*self), N));
+    }
+}
+
+impl<const N: usize> StickerOperation<N> for SliceMove<N> {
+    fn apply_to(&self, stickers: &mut impl Stickers<N>) {
+        stickers.move_stickers(turned(LayerTurn::from(*self), N));
+    }
+}
+
+impl<const N: usize> StickerOperation<N> for RangeMove<N> {
+    fn apply_to(&self, stickers: &mut impl Stickers<N>) {
+        stickers.move_stickers(turned(LayerTurn::from(*self), N));
+    }
+}
+
+impl<const N: usize> StickerOperation<N> for MiddleMove<N> {
+    fn apply_to(&self, stickers: &mut impl Stickers<N>) {
+        stickers.move_stickers(turned(LayerTurn::from(*self), N));
+    }
+}
+
+impl<const N: usize, M: Turn + StickerOperation<N>> StickerOperation<N> for Alg<M> {
+    fn apply_to(&self, stickers: &mut impl Stickers<N>) {
+        for mv in self.expand() {
+            mv.apply_to(stickers);
+        }
+    }
+}
+
+impl<const N: usize> Stickers<N> for RubiksState<N> {
+    fn move_stickers(&mut self, moves: impl Iterator<Item = (TilePos, TilePos)>) {
+        // Read every moving sticker before writing any of them
+        let moved: Vec<_> = moves.map(|(from, to)| (to, self[from])).collect();
+        for (to, colour) in moved {
+            self[to] = colour;
+        }
+    }
+
+    fn permute(&mut self, image: impl Fn(TilePos) -> TilePos) {
+        apply_cycles(self, image);
+    }
+}
+
+/// The tiles of the turned layers, each paired with its image.
+fn turned(turn: LayerTurn, n: usize) -> impl Iterator<Item = (TilePos, TilePos)> {
+    turn.moved_tiles(n).map(move |tile| (tile, turn.image(tile, n)))
+}
+
+/// Applies a full permutation by carrying one sticker around each of its cycles.
+fn apply_cycles<const N: usize>(state: &mut RubiksState<N>, image: impl Fn(TilePos) -> TilePos) {
+    let mut done = vec![false; 6 * N * N];
+    for start in TilePos::all::<N>() {
+        if done[start.index::<N>()] {
+            continue;
+        }
+        let mut carried = state[start];
+        let mut tile = image(start);
+        while tile != start {
+            std::mem::swap(&mut carried, &mut state[tile]);
+            done[tile.index::<N>()] = true;
+            tile = image(tile);
+        }
+        state[start] = carried;
+        done[start.index::<N>()] = true;
+    }
+}
@@ -0,0 +1,63 @@
+use std::ops::Mul;
+
+use super::*;
+use crate::core::cube::geometry::Face;
+use crate::core::cube::rotations::{X, Y};
+use crate::core::cube::symmetries::MirrorPlane;
+use crate::core::rubiks::moves::BasicMove::*;
+use crate::core::rubiks::test_support::{assert_applies_like_operator, check_all_moves, scrambled, Layers, Move, MoveCheck};
+use crate::core::rubiks::tiles::indexed::MoveTable;
+use crate::core::Colour;
+use crate::Western;
+
+/// Checks that applying `op` in place agrees with the operator form.
+fn assert_matches<Op: ApplyInPlace<5>>(op: &Op)
+where
+    for<'a> &'a RubiksState<5>: Mul<&'a Op, Output = RubiksState<5>>,
+{
+    assert_applies_like_operator(op, |state, op| state.apply(op));
+}
+
+struct InPlace;
+
+impl<const N: usize> MoveCheck<N> for InPlace {
+    fn check<M: Move<N>>(&mut self, mv: M, _: Layers) {
+        let mut in_place = scrambled::<N>();
+        in_place.apply(mv);
+        assert_eq!(in_place, &scrambled::<N>() * Into::<TilePerm<N>>::into(mv), "{mv:?}");
+    }
+}
+
+#[test]
+fn test_moves_match_operator() {
+    check_all_moves::<4>(&mut InPlace);
+    check_all_moves::<5>(&mut InPlace);
+}
+
+#[test]
+fn test_permutations_match_operator() {
+    let perm = TilePerm::<5>::from(&(X * Y));
+    let mut cube = scrambled::<5>();
+    cube.apply(&perm);
+    assert_eq!(cube, &scrambled() * perm.clone());
+    assert_matches(&(X * Y));
+    assert_matches(&CubeSymmetry::mirror(MirrorPlane::M));
+    assert_matches(&MoveTable::<5, BasicMove<5>>::basic().sequence(&[R, U, F]).unwrap());
+}
+
+#[test]
+fn test_algs_and_sequences() {
+    let alg: Alg<BasicMove<5>> = "[R U: [R', D]]".parse().unwrap();
+    assert_matches(&alg);
+    assert_applies_like_operator(&alg, |state, alg| state.apply_all(alg.expand().iter()));
+}
+
+#[test]
+fn test_single_sticker_writes() {
+    let mut cube = RubiksState::<3>::solved_in(Western);
+    let tile = TilePos { face: Face::Up, row: 0, col: 0 };
+    cube[tile] = Colour::Red;
+    cube.apply(U);
+    assert_eq!(cube[TilePos { face: Face::Up, row: 0, col: 2 }], Colour::Red);
+    assert!(!cube.is_solved());
+}
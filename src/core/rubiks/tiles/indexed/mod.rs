@@ -0,0 +1,277 @@
+//! Compact tile permutations over flat tile indices.
+//!
+//! A [`TilePerm<N>`] stores a full [`TilePos`] per tile and composes by walking six
+//! grids, which is convenient but slow in the inner loop of a search. An
+//! [`IndexPerm<N>`] numbers the tiles by [`TilePos::index`] instead and stores the
+//! image of each tile as a `u16`, so a permutation is one contiguous array of 2·6·N²
+//! bytes and composition is a single gather over it.
+//!
+//! A [`MoveTable`] computes the `IndexPerm` of every move in a move set once, so a
+//! search never converts a move to a permutation more than once. The table of the 18
+//! basic moves is cached process-wide for each N by [`MoveTable::basic`].
+//!
+//! # Performance
+//!
+//! Composing with [`IndexPerm::compose_into`] reuses its output buffer and allocates
+//! nothing; applying an `IndexPerm` to a [`RubiksState`] scatters the colours through
+//! one flat buffer rather than inverting the permutation first. The
+//! `index_perm` benchmark compares both against `TilePerm`
+//! (`cargo bench --bench index_perm`).
+//!
+//! # Limits
+//!
+//! `u16` indices cover up to 65,536 tiles, so `IndexPerm<N>` supports cubes up to
+//! 104×104×104. Larger N fail to compile.
+//!
+//! # Example
+//!
+//! ```
+//! use rubiks_cube_representation::core::rubiks::moves::BasicMove::{self, *};
+//! use rubiks_cube_representation::core::rubiks::tiles::indexed::{IndexPerm, MoveTable};
+//! use rubiks_cube_representation::core::rubiks::tiles::TilePerm;
+//! use rubiks_cube_representation::{RubiksState, Western};
+//!
+//! let table = MoveTable::<3, BasicMove<3>>::basic();
+//! let sexy = table.sequence(&[R, U, R3, U3]).unwrap();
+//! assert_eq!(TilePerm::from(sexy.clone()), [R, U, R3, U3].iter().fold(TilePerm::ID, |acc, mv| acc * TilePerm::from(mv)));
+//!
+//! // Six sexy moves solve the cube again
+//! let mut power = IndexPerm::identity();
+//! let mut scratch = IndexPerm::identity();
+//! for _ in 0..6 {
+//!     power.compose_into(&sexy, &mut scratch);
+//!     std::mem::swap(&mut power, &mut scratch);
+//! }
+//! assert_eq!(power, IndexPerm::identity());
+//!
+//! let cube = RubiksState::<3>::solved_in(Western);
+//! assert!(!(&cube * &sexy).is_solved());
+//! ```
+
+use std::any::Any;
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::ops::Mul;
+use std::sync::{Mutex, OnceLock};
+
+use crate::core::rubiks::moves::BasicMove;
+use crate::core::rubiks::RubiksState;
+use crate::error::Error;
+
+use super::{TilePerm, TilePos};
+
+#[cfg(test)]
+mod tests;
+
+/// A permutation of the tiles of an N×N×N cube as a flat array of tile indices.
+///
+/// Entry `i` is the index of the tile that tile `i` moves to, with tiles numbered by
+/// [`TilePos::index`]. As for [`TilePerm`], `a * b` means "apply `a`, then apply `b`".
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct IndexPerm<const N: usize> {
+    images: Box<[u16]>,
+}
+
+impl<const N: usize> IndexPerm<N> {
+    /// Rejects cube sizes whose tile indices do not fit in a `u16`.
+    const FITS: () = assert!(6 * N * N <= 1 << 16, "IndexPerm supports cubes up to 104×104×104");
+
+    /// The identity permutation.
+    pub fn identity() -> Self {
+        let () = Self::FITS;
+        IndexPerm { images: (0..6 * N * N).map(|index| index as u16).collect() }
+    }
+
+    /// Builds a permutation from the image of each tile index.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::WrongImageCount`] if there are not 6·N² images,
+    /// [`Error::IndexOutOfRange`] if an image is not below 6·N², or
+    /// [`Error::DuplicateImage`] if two tiles share an image.
+    pub fn from_images(images: Vec<u16>) -> Result<Self, Error> {
+        let () = Self::FITS;
+        if images.len() != 6 * N * N {
+            return Err(Error::WrongImageCount { expected: 6 * N * N, found: images.len() });
+        }
+        let mut hit = vec![false; images.len()];
+        for &image in &images {
+            match hit.get_mut(image as usize) {
+                Some(seen) if !*seen => *seen = true,
+                Some(_) => return Err(Error::DuplicateImage { index: image as usize }),
+                None => return Err(Error::IndexOutOfRange { index: image as usize, n: N }),
+            }
+        }
+        Ok(IndexPerm { images: images.into_boxed_slice() })
+    }
+
+    /// The image of each tile index.
+    pub fn images(&self) -> &[u16] {
+        &self.images
+    }
+
+    /// The tile that `tile` moves to.
+    pub fn image(&self, tile: TilePos) -> TilePos {
+        TilePos::from_index::<N>(self.images[tile.index::<N>()] as usize)
+    }
+
+    /// Computes the inverse permutation.
+    pub fn inverse(&self) -> Self {
+        let mut images = vec![0; self.images.len()];
+        for (index, &image) in self.images.iter().enumerate() {
+            images[image as usize] = index as u16;
+        }
+        IndexPerm { images: images.into_boxed_slice() }
+    }
+
+    /// Writes `self * rhs` (apply `self`, then `rhs`) into `out`, reusing its buffer.
+    pub fn compose_into(&self, rhs: &IndexPerm<N>, out: &mut IndexPerm<N>) {
+        for (out, &image) in out.images.iter_mut().zip(self.images.iter()) {
+            *out = rhs.images[image as usize];
+        }
+    }
+
+    /// Replaces `self` with `self * rhs`, composing in place.
+    pub fn then(&mut self, rhs: &IndexPerm<N>) {
+        for image in self.images.iter_mut() {
+            *image = rhs.images[*image as usize];
+        }
+    }
+
+    /// Converts to a [`TilePerm`].
+    pub fn to_tile_perm(&self) -> TilePerm<N> {
+        TilePerm::from_fn(|tile| self.image(tile))
+    }
+
+    /// Applies the permutation to a cube state, moving the sticker on each tile to the
+    /// tile's image.
+    pub fn apply_to(&self, state: &RubiksState<N>) -> RubiksState<N> {
+        let faces = [&state.up, &state.down, &state.left, &state.right, &state.front, &state.back];
+        let source: Vec<_> = faces.iter().flat_map(|face| face.vals.iter().flatten().copied()).collect();
+        let mut colours = source.clone();
+        for (&colour, &image) in source.iter().zip(self.images.iter()) {
+            colours[image as usize] = colour;
+        }
+        RubiksState::from_fn(|tile| colours[tile.index::<N>()])
+    }
+}
+
+impl<const N: usize> Mul<&IndexPerm<N>> for &IndexPerm<N> {
+    type Output = IndexPerm<N>;
+
+    /// Applies `self`, then `rhs`.
+    fn mul(self, rhs: &IndexPerm<N>) -> IndexPerm<N> {
+        IndexPerm { images: self.images.iter().map(|&image| rhs.images[image as usize]).collect() }
+    }
+}
+
+impl<const N: usize> Mul<&IndexPerm<N>> for IndexPerm<N> {
+    type Output = IndexPerm<N>;
+
+    /// Applies `self`, then `rhs`, reusing `self`'s buffer.
+    fn mul(mut self, rhs: &IndexPerm<N>) -> IndexPerm<N> {
+        self.then(rhs);
+        self
+    }
+}
+
+impl<const N: usize> Mul<&IndexPerm<N>> for &RubiksState<N> {
+    type Output = RubiksState<N>;
+
+    /// See [`IndexPerm::apply_to`].
+    fn mul(self, perm: &IndexPerm<N>) -> RubiksState<N> {
+        perm.apply_to(self)
+    }
+}
+
+impl<const N: usize> Mul<&IndexPerm<N>> for RubiksState<N> {
+    type Output = RubiksState<N>;
+
+    fn mul(self, perm: &IndexPerm<N>) -> RubiksState<N> {
+        perm.apply_to(&self)
+    }
+}
+
+impl<const N: usize> From<&TilePerm<N>> for IndexPerm<N> {
+    fn from(perm: &TilePerm<N>) -> Self {
+        let () = Self::FITS;
+        IndexPerm { images: TilePos::all::<N>().map(|tile| perm[tile].index::<N>() as u16).collect() }
+    }
+}
+
+impl<const N: usize> From<TilePerm<N>> for IndexPerm<N> {
+    fn from(perm: TilePerm<N>) -> Self {
+        Self::from(&perm)
+    }
+}
+
+// Owned only; see the blanket `CubeOperation` impl in `tiles/mod.rs`
+impl<const N: usize> From<IndexPerm<N>> for TilePerm<N> {
+    fn from(perm: IndexPerm<N>) -> Self {
+        perm.to_tile_perm()
+    }
+}
+
+/// The precomputed [`IndexPerm`] of every move in a move set.
+///
+/// Build one with [`MoveTable::new`] for any move type, or use the process-wide cached
+/// table of the basic moves from [`MoveTable::basic`].
+#[derive(Clone, Debug)]
+pub struct MoveTable<const N: usize, M> {
+    moves: Vec<M>,
+    perms: Vec<IndexPerm<N>>,
+    positions: HashMap<M, usize>,
+}
+
+impl<const N: usize, M: Copy + Eq + Hash + Into<TilePerm<N>>> MoveTable<N, M> {
+    /// Computes the permutation of every move. Repeated moves are stored once.
+    pub fn new(moves: impl IntoIterator<Item = M>) -> Self {
+        let mut table = MoveTable { moves: Vec::new(), perms: Vec::new(), positions: HashMap::new() };
+        for mv in moves {
+            if !table.positions.contains_key(&mv) {
+                table.positions.insert(mv, table.moves.len());
+                table.moves.push(mv);
+                table.perms.push(IndexPerm::from(mv.into()));
+            }
+        }
+        table
+    }
+
+    /// The moves in the table, in the order they were first given.
+    pub fn moves(&self) -> &[M] {
+        &self.moves
+    }
+
+    /// The number of moves in the table.
+    pub fn len(&self) -> usize {
+        self.moves.len()
+    }
+
+    /// Whether the table has no moves.
+    pub fn is_empty(&self) -> bool {
+        self.moves.is_empty()
+    }
+
+    /// The permutation of a move, or `None` if it is not in the table.
+    pub fn perm(&self, mv: &M) -> Option<&IndexPerm<N>> {
+        self.positions.get(mv).map(|&position| &self.perms[position])
+    }
+
+    /// The permutation of a move sequence, or `None` if one of its moves is not in the
+    /// table.
+    pub fn sequence(&self, moves: &[M]) -> Option<IndexPerm<N>> {
+        moves.iter().try_fold(IndexPerm::identity(), |acc, mv| Some(acc * self.perm(mv)?))
+    }
+}
+
+impl<const N: usize> MoveTable<N, BasicMove<N>> {
+    /// The table of all 18 basic moves for this N, computed on first use and shared
+    /// for the rest of the process.
+    pub fn basic() -> &'static Self {
+        // One static serves every N, so the tables are keyed by N and stored as `Any`
+        static TABLES: OnceLock<Mutex<HashMap<usize, &'static (dyn Any + Send + Sync)>>> = OnceLock::new();
+        let mut tables = TABLES.get_or_init(Default::default).lock().unwrap_or_else(|poison| poison.into_inner());
+        let table = *tables.entry(N).or_insert_with(|| Box::leak(Box::new(MoveTable::new(BasicMove::<N>::ALL))));
+        table.downcast_ref().expect("tables are keyed by N")
+    }
+}
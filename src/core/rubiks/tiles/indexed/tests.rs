@@ -0,0 +1,66 @@
+use super::*;
+use crate::core::rubiks::moves::BasicMove::*;
+use crate::core::rubiks::moves::SliceMove;
+use crate::{Face, Western};
+
+#[test]
+fn test_round_trip_with_tile_perm() {
+    for mv in BasicMove::<4>::ALL {
+        let perm = TilePerm::<4>::from(mv);
+        let indexed = IndexPerm::from(&perm);
+        assert_eq!(indexed.to_tile_perm(), perm);
+        assert_eq!(indexed.inverse().to_tile_perm(), perm.inverse());
+        let tile = TilePos { face: Face::Front, row: 0, col: 3 };
+        assert_eq!(indexed.image(tile), perm[tile]);
+    }
+}
+
+#[test]
+fn test_composition_matches_tile_perm() {
+    let table = MoveTable::<3, BasicMove<3>>::new(BasicMove::ALL);
+    let moves = [R, U2, F3, L, D, B2, R3];
+    let expected = moves.iter().fold(TilePerm::<3>::ID, |acc, mv| acc * TilePerm::from(mv));
+    let indexed = table.sequence(&moves).unwrap();
+    assert_eq!(TilePerm::from(indexed.clone()), expected);
+
+    let (r, u) = (table.perm(&R).unwrap(), table.perm(&U).unwrap());
+    let mut out = IndexPerm::identity();
+    r.compose_into(u, &mut out);
+    assert_eq!(out, r * u);
+    let mut in_place = r.clone();
+    in_place.then(u);
+    assert_eq!(in_place, out);
+    assert_eq!(&out * &out.inverse(), IndexPerm::identity());
+}
+
+#[test]
+fn test_application_matches_tile_perm() {
+    let table = MoveTable::<5, BasicMove<5>>::basic();
+    let moves = [R, U, F3, D2, L3];
+    let indexed = table.sequence(&moves).unwrap();
+    let cube = RubiksState::<5>::solved_in(Western);
+    let expected = moves.iter().fold(cube.clone(), |state, mv| state * mv);
+    assert_eq!(&cube * &indexed, expected);
+    assert_eq!(cube * &indexed, expected);
+}
+
+#[test]
+fn test_from_images_validates() {
+    assert_eq!(IndexPerm::<1>::from_images(vec![0, 1, 2, 3, 4, 5]), Ok(IndexPerm::identity()));
+    assert!(IndexPerm::<1>::from_images(vec![0, 1, 2, 3, 4]).is_err());
+    assert!(IndexPerm::<1>::from_images(vec![0, 1, 2, 3, 4, 4]).is_err());
+    assert!(IndexPerm::<1>::from_images(vec![0, 1, 2, 3, 4, 6]).is_err());
+}
+
+#[test]
+fn test_move_tables() {
+    let first = MoveTable::<3, BasicMove<3>>::basic();
+    assert!(std::ptr::eq(first, MoveTable::<3, BasicMove<3>>::basic()));
+    assert_eq!(first.len(), 18);
+    assert_eq!(MoveTable::<2, BasicMove<2>>::basic().perm(&U).unwrap().images().len(), 24);
+
+    let slices = MoveTable::<4, SliceMove<4>>::new([SliceMove::Rs(2), SliceMove::Rs(2), SliceMove::Us(3)]);
+    assert_eq!(slices.moves(), [SliceMove::Rs(2), SliceMove::Us(3)]);
+    assert!(slices.perm(&SliceMove::Rs(3)).is_none());
+    assert!(slices.sequence(&[SliceMove::Rs(3)]).is_none());
+}
@@ -9,25 +9,46 @@
 //! - [`restrictions`]: Tile subset analysis framework using the [`Restriction`](restrictions::Restriction) trait
 //!   - Defines geometric subsets like slices and ranges
 //!   - Iterator-based design for on-demand position generation
-//!   - Used in partial permutation construction
 //!
-//! - [`partial`]: Sparse permutations and move construction primitives
-//!   - [`PartialTilePerm`](partial::PartialTilePerm) for efficient sparse permutations
-//!   - Helper functions [`rotate_face_only`](partial::rotate_face_only) and [`rotate_outside_of_slice`](partial::rotate_outside_of_slice)
-//!   - Compositional building blocks for all move types
+//! - [`partial`]: Sparse permutations
+//!   - [`PartialTilePerm`](partial::PartialTilePerm) for sparse permutations, applied
+//!     and composed in time proportional to the tiles they move
+//!   - Built for every move from the tiles in its turned layers
+//!
+//! - [`cycles`]: Cycle decomposition, order, sign and support of a [`TilePerm`]
+//!
+//! - [`indexed`]: Compact permutations over flat `u16` tile indices for fast searches
+//!   - [`IndexPerm`](indexed::IndexPerm) with allocation-free composition
+//!   - [`MoveTable`](indexed::MoveTable) caching the permutation of every move in a set
+//!
+//! - [`in_place`]: [`ApplyInPlace`](in_place::ApplyInPlace) and `RubiksState::apply` for
+//!   mutating a state without allocating a new one
+//!
+//! - [`motion`]: Where moves and rotations send each tile, computed by `const fn`
+//!   arithmetic
+//!   - Compile-time tables behind [`BasicMove::tile_perm`] and
+//!     [`CubeRotation::tile_perm`] for cubes up to [`MAX_TABLE_N`](motion::MAX_TABLE_N)
 //!
 //! - `implementations`: Conversions from operations to tile permutations (private module)
 //!   - `From` implementations for all move types and cube rotations
 //!   - Contains the geometric algorithms for permutation construction
 //!   - Provides the bridge from abstract operations to concrete state transformations
 
-use std::{array::from_fn, ops::{Index, Mul}};
+use std::fmt;
+use std::ops::{Deref, DerefMut, Index, Mul};
 
-use crate::{core::rubiks::{moves::{BasicMove, MiddleMove, RangeMove, SliceMove, WideMove}, tiles::{partial::PartialTilePerm, restrictions::Restriction}}, CubeRotation, Face, RubiksState};
+use crate::core::cube::symmetries::CubeSymmetry;
+use crate::core::rubiks::grid::Grid;
+use crate::error::Error;
+use crate::{core::rubiks::{moves::{BasicMove, MiddleMove, RangeMove, SliceMove, WideMove}, tiles::restrictions::Restriction}, CubeRotation, Face, RubiksState, FACES};
 
 pub mod implementations;
 pub mod restrictions;
 pub mod partial;
+pub mod cycles;
+pub mod indexed;
+pub mod in_place;
+pub mod motion;
 
 #[cfg(test)]
 mod tests;
@@ -65,7 +86,7 @@ mod tests;
 ///
 /// These invariants are not enforced at the type level but must be maintained
 /// by construction to ensure correct behavior.
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct TilePos {
     /// The face this tile is located on
     pub face: Face,
@@ -75,6 +96,68 @@ pub struct TilePos {
     pub col: usize
 }
 
+impl TilePos {
+    /// Returns the position of this tile in the flat ordering of all 6·N² tiles.
+    ///
+    /// Tiles are numbered face by face in [`FACES`](crate::FACES) order, then row-major
+    /// within each face, so the index agrees with the derived [`Ord`] on `TilePos`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rubiks_cube_representation::Face;
+    /// use rubiks_cube_representation::core::rubiks::tiles::TilePos;
+    ///
+    /// let tile = TilePos { face: Face::Down, row: 1, col: 2 };
+    /// assert_eq!(tile.index::<3>(), 9 + 3 + 2);
+    /// assert_eq!(TilePos::from_index::<3>(14), tile);
+    /// ```
+    pub fn index<const N: usize>(self) -> usize {
+        (self.face as usize * N + self.row) * N + self.col
+    }
+
+    /// Inverse of [`TilePos::index`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index >= 6 * N * N`; see [`TilePos::try_from_index`].
+    pub fn from_index<const N: usize>(index: usize) -> Self {
+        assert!(index < 6 * N * N, "tile index {index} out of range for N = {N}");
+        TilePos { face: FACES[index / (N * N)], row: index / N % N, col: index % N }
+    }
+
+    /// Inverse of [`TilePos::index`], failing instead of panicking.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::IndexOutOfRange`] if `index >= 6 * N * N`.
+    pub fn try_from_index<const N: usize>(index: usize) -> Result<Self, Error> {
+        if index < 6 * N * N {
+            Ok(Self::from_index::<N>(index))
+        } else {
+            Err(Error::IndexOutOfRange { index, n: N })
+        }
+    }
+
+    /// Checks that this position lies on an N×N×N cube.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::TileOutOfRange`] if `row >= N` or `col >= N`.
+    pub fn check<const N: usize>(self) -> Result<Self, Error> {
+        if self.row < N && self.col < N {
+            Ok(self)
+        } else {
+            Err(Error::TileOutOfRange { tile: self, n: N })
+        }
+    }
+
+    /// Iterates over all 6·N² tile positions in index order.
+    pub fn all<const N: usize>() -> impl Iterator<Item = TilePos> {
+        (0..6 * N * N).map(Self::from_index::<N>)
+    }
+}
+
 /// Grid of tile positions representing how one face transforms under an operation.
 ///
 /// A `TileGrid<N>` is an N×N array where each entry specifies where the tile at
@@ -91,19 +174,89 @@ pub struct TilePos {
 /// # Usage
 ///
 /// `TileGrid` is primarily used as a component of [`TilePerm`], with one grid
-/// per face storing that face's tile permutation. It is rarely used in isolation.
+/// per face storing that face's tile permutation, held through a [`TileGridHandle`].
+/// It is rarely used in isolation.
 ///
 /// # Design Note
 ///
 /// This type intentionally does not implement `Copy` despite being semantically
 /// copyable, as large cube dimensions (e.g., 10×10 or larger) would make implicit
 /// copying expensive. Users should explicitly clone when needed.
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
 pub struct TileGrid<const N: usize> {
     /// 2D array mapping source positions to destination positions
     pub vals: [[TilePos;N];N]
 }
 
+impl<const N: usize> TileGrid<N> {
+    /// Views an array of destinations as a tile grid, without copying it.
+    fn from_vals(vals: &[[TilePos; N]; N]) -> &Self {
+        // SAFETY: `TileGrid` is `repr(transparent)` over `[[TilePos; N]; N]`, so both
+        // have the same layout, and the reference keeps the borrow's lifetime.
+        unsafe { &*std::ptr::from_ref(vals).cast::<Self>() }
+    }
+
+    /// Views an array of destinations as a mutable tile grid, without copying it.
+    fn from_vals_mut(vals: &mut [[TilePos; N]; N]) -> &mut Self {
+        // SAFETY: As in `from_vals`, and the reference is unique because `vals` is.
+        unsafe { &mut *std::ptr::from_mut(vals).cast::<Self>() }
+    }
+}
+
+/// A [`TileGrid<N>`] kept on the heap or in static memory.
+///
+/// [`TilePerm`] holds its six grids through this handle, so a permutation is a few
+/// pointers whatever N is; see the [`grid`](crate::core::rubiks::grid) module for the
+/// storage policy. It dereferences to [`TileGrid<N>`], so `perm.up.vals[row][col]`
+/// reads and writes the array as before.
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct TileGridHandle<const N: usize>(Grid<TilePos, N>);
+
+impl<const N: usize> TileGridHandle<N> {
+    /// Builds a grid on the heap from a function of `(row, col)`, filling it row by row.
+    pub fn from_fn(dest: impl FnMut(usize, usize) -> TilePos) -> Self {
+        TileGridHandle(Grid::from_fn(dest))
+    }
+
+    /// Wraps a grid in static memory without copying it; see [`Grid::from_static`].
+    pub const fn from_static(vals: &'static [[TilePos; N]; N]) -> Self {
+        TileGridHandle(Grid::from_static(vals))
+    }
+
+    /// Whether the grid is still borrowed from static memory.
+    pub fn is_static(&self) -> bool {
+        self.0.is_static()
+    }
+}
+
+impl<const N: usize> Deref for TileGridHandle<N> {
+    type Target = TileGrid<N>;
+
+    fn deref(&self) -> &TileGrid<N> {
+        TileGrid::from_vals(&self.0)
+    }
+}
+
+impl<const N: usize> DerefMut for TileGridHandle<N> {
+    /// Copies a static grid to the heap before handing out a mutable reference.
+    fn deref_mut(&mut self) -> &mut TileGrid<N> {
+        TileGrid::from_vals_mut(&mut self.0)
+    }
+}
+
+impl<const N: usize> From<TileGrid<N>> for TileGridHandle<N> {
+    fn from(grid: TileGrid<N>) -> Self {
+        TileGridHandle(Grid::from(grid.vals))
+    }
+}
+
+impl<const N: usize> fmt::Debug for TileGridHandle<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&**self, f)
+    }
+}
+
 /// Complete permutation of all tiles on an N×N×N Rubik's cube.
 ///
 /// A `TilePerm<N>` represents a bijective mapping from tile positions to tile positions,
@@ -152,54 +305,72 @@ pub struct TileGrid<const N: usize> {
 ///
 /// Like [`TileGrid`], this type does not implement `Copy` to avoid expensive implicit
 /// copies for large cubes. Most operations work with references (`&TilePerm<N>`).
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct TilePerm<const N: usize> {
     /// Permutation grid for the up face
-    pub up: TileGrid<N>,
+    pub up: TileGridHandle<N>,
     /// Permutation grid for the down face
-    pub down: TileGrid<N>,
+    pub down: TileGridHandle<N>,
     /// Permutation grid for the left face
-    pub left: TileGrid<N>,
+    pub left: TileGridHandle<N>,
     /// Permutation grid for the right face
-    pub right: TileGrid<N>,
+    pub right: TileGridHandle<N>,
     /// Permutation grid for the front face
-    pub front: TileGrid<N>,
+    pub front: TileGridHandle<N>,
     /// Permutation grid for the back face
-    pub back: TileGrid<N>
+    pub back: TileGridHandle<N>
 }
 
 impl<const N: usize> TilePerm<N> {
-    pub const ID: Self = {
-        let mut id = TilePerm {
-            up: TileGrid { vals: [[TilePos{ face: Face::Up, row: 0, col: 0 };N];N] },
-            down: TileGrid { vals: [[TilePos{ face: Face::Down, row: 0, col: 0 };N];N] },
-            left: TileGrid { vals: [[TilePos{ face: Face::Left, row: 0, col: 0 };N];N] },
-            right: TileGrid { vals: [[TilePos{ face: Face::Right, row: 0, col: 0 };N];N] },
-            front: TileGrid { vals: [[TilePos{ face: Face::Front, row: 0, col: 0 };N];N] },
-            back: TileGrid { vals: [[TilePos{ face: Face::Back, row: 0, col: 0 };N];N] },
-        };
-        let mut row = 0;
-        while row < N {
-            let mut col = 0;
-            while col < N {
-                id.up.vals[row][col].row = row;
-                id.up.vals[row][col].col = col;
-                id.down.vals[row][col].row = row;
-                id.down.vals[row][col].col = col;
-                id.left.vals[row][col].row = row;
-                id.left.vals[row][col].col = col;
-                id.right.vals[row][col].row = row;
-                id.right.vals[row][col].col = col;
-                id.front.vals[row][col].row = row;
-                id.front.vals[row][col].col = col;
-                id.back.vals[row][col].row = row;
-                id.back.vals[row][col].col = col;
-                col += 1;
-            }
-            row += 1
-        }
-        id
+    /// The identity permutation.
+    ///
+    /// Its grids live in static memory (see [`Grid::from_static`]), so using this
+    /// constant allocates nothing until the permutation is mutated.
+    pub const ID: Self = TilePerm {
+        up: TileGridHandle::from_static(&const { identity_grid(Face::Up) }),
+        down: TileGridHandle::from_static(&const { identity_grid(Face::Down) }),
+        left: TileGridHandle::from_static(&const { identity_grid(Face::Left) }),
+        right: TileGridHandle::from_static(&const { identity_grid(Face::Right) }),
+        front: TileGridHandle::from_static(&const { identity_grid(Face::Front) }),
+        back: TileGridHandle::from_static(&const { identity_grid(Face::Back) }),
     };
+
+    /// The identity permutation, equal to [`TilePerm::ID`] but with its grids on the
+    /// heap.
+    pub fn identity() -> Self {
+        TilePerm::from_fn(|tile| tile)
+    }
+
+    /// Builds a permutation from a function giving the destination of each tile.
+    ///
+    /// The function must be a bijection on the 6·N² tile positions; this is not checked.
+    pub(crate) fn from_fn(mut dest: impl FnMut(TilePos) -> TilePos) -> Self {
+        let mut grid = |face: Face| TileGridHandle::from_fn(|row, col| dest(TilePos { face, row, col }));
+        TilePerm {
+            up: grid(Face::Up),
+            down: grid(Face::Down),
+            left: grid(Face::Left),
+            right: grid(Face::Right),
+            front: grid(Face::Front),
+            back: grid(Face::Back),
+        }
+    }
+}
+
+/// The identity grid of a face, for [`TilePerm::ID`].
+const fn identity_grid<const N: usize>(face: Face) -> [[TilePos; N]; N] {
+    let mut vals = [[TilePos { face, row: 0, col: 0 }; N]; N];
+    let mut row = 0;
+    while row < N {
+        let mut col = 0;
+        while col < N {
+            vals[row][col].row = row;
+            vals[row][col].col = col;
+            col += 1;
+        }
+        row += 1;
+    }
+    vals
 }
 
 impl<const N: usize> Index<TilePos> for TilePerm<N> {
@@ -252,32 +423,7 @@ impl<const N: usize> Mul<&TilePerm<N>> for &TilePerm<N> {
     /// For large cubes, consider whether composition is necessary or if operations
     /// can be applied sequentially instead.
     fn mul(self, rhs: &TilePerm<N>) -> Self::Output {
-        TilePerm {
-            up: TileGrid { vals: from_fn(|row| from_fn(|col| {
-                let tile = TilePos { face: Face::Up, row, col };
-                rhs[self[tile]]
-            })) },
-            down: TileGrid { vals: from_fn(|row| from_fn(|col| {
-                let tile = TilePos { face: Face::Down, row, col };
-                rhs[self[tile]]
-            })) },
-            left: TileGrid { vals: from_fn(|row| from_fn(|col| {
-                let tile = TilePos { face: Face::Left, row, col };
-                rhs[self[tile]]
-            })) },
-            right: TileGrid { vals: from_fn(|row| from_fn(|col| {
-                let tile = TilePos { face: Face::Right, row, col };
-                rhs[self[tile]]
-            })) },
-            front: TileGrid { vals: from_fn(|row| from_fn(|col| {
-                let tile = TilePos { face: Face::Front, row, col };
-                rhs[self[tile]]
-            })) },
-            back: TileGrid { vals: from_fn(|row| from_fn(|col| {
-                let tile = TilePos { face: Face::Back, row, col };
-                rhs[self[tile]]
-            })) },
-        }
+        TilePerm::from_fn(|tile| rhs[self[tile]])
     }
 }
 
@@ -355,14 +501,7 @@ impl<const N: usize> TilePerm<N> {
     ///
     /// Computing the inverse is O(N²), requiring a full traversal of all tiles.
     pub fn inverse(&self) -> Self {
-        let mut result = TilePerm {
-            up: TileGrid { vals: from_fn(|row| from_fn(|col| TilePos { face: Face::Up, row, col })) },
-            down: TileGrid { vals: from_fn(|row| from_fn(|col| TilePos { face: Face::Down, row, col })) },
-            left: TileGrid { vals: from_fn(|row| from_fn(|col| TilePos { face: Face::Left, row, col })) },
-            right: TileGrid { vals: from_fn(|row| from_fn(|col| TilePos { face: Face::Right, row, col })) },
-            front: TileGrid { vals: from_fn(|row| from_fn(|col| TilePos { face: Face::Front, row, col })) },
-            back: TileGrid { vals: from_fn(|row| from_fn(|col| TilePos { face: Face::Back, row, col })) },
-        };
+        let mut result = Self::identity();
 
         for face in [Face::Up, Face::Down, Face::Left, Face::Right, Face::Front, Face::Back] {
             for row in 0..N {
@@ -428,6 +567,11 @@ pub(crate) trait CubeOperation<const N: usize>: Into<TilePerm<N>> {
     fn on(self, cube: &RubiksState<N>) -> RubiksState<N>;
 }
 
+// Every reference convertible into a `TilePerm` is a cube operation. Types with their
+// own way of acting on a state, such as `IndexPerm` and `PartialTilePerm`, therefore
+// only convert into `TilePerm` by value: a `From<&T>` would bring `&T` under this impl,
+// and the blanket `Mul` for `&RubiksState` below would then conflict with their own.
+// They offer `to_tile_perm` for converting by reference instead.
 impl<'a, const N: usize, Operation> CubeOperation<N> for &'a Operation 
 where
     &'a Operation: Into<TilePerm<N>>
@@ -441,80 +585,7 @@ where
 impl<const N: usize> CubeOperation<N> for TilePerm<N> {
     fn on(self, cube: &RubiksState<N>) -> RubiksState<N> {
         let perm_inverse = self.inverse();
-        RubiksState { 
-            up: super::FaceState { 
-                vals: 
-                from_fn(|row| 
-                    from_fn(|col| 
-                        cube[
-                            perm_inverse[
-                                TilePos{face: Face::Up, row, col}
-                            ]
-                        ]
-                    )
-                ) 
-            }, 
-            down: super::FaceState {
-                vals:
-                from_fn(|row|
-                    from_fn(|col|
-                        cube[
-                            perm_inverse[
-                                TilePos{face: Face::Down, row, col}
-                            ]
-                        ]
-                    )
-                )
-            }, 
-            left: super::FaceState {
-                vals:
-                from_fn(|row|
-                    from_fn(|col|
-                        cube[
-                            perm_inverse[
-                                TilePos{face: Face::Left, row, col}
-                            ]
-                        ]
-                    )
-                )
-            }, 
-            right: super::FaceState {
-                vals:
-                from_fn(|row|
-                    from_fn(|col|
-                        cube[
-                            perm_inverse[
-                                TilePos{face: Face::Right, row, col}
-                            ]
-                        ]
-                    )
-                )
-            }, 
-            front: super::FaceState {
-                vals:
-                from_fn(|row|
-                    from_fn(|col|
-                        cube[
-                            perm_inverse[
-                                TilePos{face: Face::Front, row, col}
-                            ]
-                        ]
-                    )
-                )
-            }, 
-            back: super::FaceState {
-                vals:
-                from_fn(|row|
-                    from_fn(|col|
-                        cube[
-                            perm_inverse[
-                                TilePos{face: Face::Back, row, col}
-                            ]
-                        ]
-                    )
-                )
-            },
-        }
+        RubiksState::from_fn(|tile| cube[perm_inverse[tile]])
     }
 }
 
@@ -558,6 +629,8 @@ impl<const N: usize, T: CubeOperation<N>> Mul<T> for RubiksState<N> {
 /// - [`RangeMove<N>`](crate::core::rubiks::moves::RangeMove)
 /// - [`MiddleMove<N>`](crate::core::rubiks::moves::MiddleMove)
 /// - [`CubeRotation`](crate::core::cube::rotations::CubeRotation)
+/// - [`CubeSymmetry`](crate::core::cube::symmetries::CubeSymmetry)
+/// - [`Alg<M>`](crate::core::rubiks::algs::Alg) over any of the move types
 ///
 /// Notably, [`TilePerm<N>`] itself does **not** implement this trait.
 ///
@@ -574,55 +647,7 @@ impl<const N: usize> NonTilePermOperation<N> for SliceMove<N> {}
 impl<const N: usize> NonTilePermOperation<N> for RangeMove<N> {}
 impl<const N: usize> NonTilePermOperation<N> for MiddleMove<N> {}
 impl<const N: usize> NonTilePermOperation<N> for CubeRotation {}
-
-impl<'a, const N: usize> From<&'a PartialTilePerm<N>> for TilePerm<N> {
-    fn from(value: &'a PartialTilePerm<N>) -> Self {
-        Self {
-            up: TileGrid { vals: from_fn(|row| from_fn(|col| {
-                let tile = TilePos { face: Face::Up, row, col };
-                match value.0.get(&tile) {
-                    Some(&x) => x,
-                    None => tile,
-                }
-            }))},
-            down: TileGrid { vals: from_fn(|row| from_fn(|col| {
-                let tile = TilePos { face: Face::Down, row, col };
-                match value.0.get(&tile) {
-                    Some(&x) => x,
-                    None => tile,
-                }
-            }))},
-            left: TileGrid { vals: from_fn(|row| from_fn(|col| {
-                let tile = TilePos { face: Face::Left, row, col };
-                match value.0.get(&tile) {
-                    Some(&x) => x,
-                    None => tile,
-                }
-            }))},
-            right: TileGrid { vals: from_fn(|row| from_fn(|col| {
-                let tile = TilePos { face: Face::Right, row, col };
-                match value.0.get(&tile) {
-                    Some(&x) => x,
-                    None => tile,
-                }
-            }))},
-            front: TileGrid { vals: from_fn(|row| from_fn(|col| {
-                let tile = TilePos { face: Face::Front, row, col };
-                match value.0.get(&tile) {
-                    Some(&x) => x,
-                    None => tile,
-                }
-            }))},
-            back: TileGrid { vals: from_fn(|row| from_fn(|col| {
-                let tile = TilePos { face: Face::Back, row, col };
-                match value.0.get(&tile) {
-                    Some(&x) => x,
-                    None => tile,
-                }
-            }))},
-        }
-    }
-}
+impl<const N: usize> NonTilePermOperation<N> for CubeSymmetry {}
 
 impl<const N: usize> TilePerm<N> {
     /// Checks if two tile permutations agree on all positions within a restriction.
@@ -0,0 +1,370 @@
+//! Tile motions of moves and rotations, computed by `const fn` arithmetic.
+//!
+//! This module computes where a move or rotation sends each tile directly from the
+//! cube's geometry, with `const fn`s that neither allocate nor hash, and uses them for
+//! every conversion to a [`TilePerm`] and for the
+//! [`PartialTilePerm`] of every move.
+//!
+//! # Algorithm
+//!
+//! Tile centres are kept in *doubled* coordinates, so that every centre of an N×N×N
+//! cube centred on the origin has integer coordinates: a tile on face `F` has
+//! coordinate `±N` along `F`'s normal and odd coordinates in `-(N-1)..=N-1` along the
+//! other two axes.
+//!
+//! - A **layer turn** rotates the centres of the tiles in its layers a
+//!   quarter turn at a time about the turned face's normal, and reads the destination
+//!   tile off the rotated point. A tile is in layer `k` (counted from the turned face)
+//!   when the centre of its cubie is at depth `2k - 1` below that face.
+//! - A **rotation** multiplies every centre by [`CubeRotation::matrix`].
+//!
+//! # Compile-Time Tables
+//!
+//! For cubes up to [`MAX_TABLE_N`] the permutations of the 18 basic moves and the 24
+//! rotations are evaluated at compile time into static tables, so
+//! [`BasicMove::tile_perm`] and [`CubeRotation::tile_perm`] are `const fn`s and the
+//! `From` conversions of those operations copy nothing: the grids of the resulting
+//! [`TilePerm`] borrow the tables. Larger cubes, and the other move families, build
+//! their permutations from the same arithmetic at run time.
+//!
+//! # Example
+//!
+//! ```
+//! use rubiks_cube_representation::core::rubiks::moves::BasicMove;
+//! use rubiks_cube_representation::core::rubiks::tiles::TilePerm;
+//! use rubiks_cube_representation::X;
+//!
+//! const U: TilePerm<3> = BasicMove::<3>::U.tile_perm();
+//! const TURNED: TilePerm<3> = X.tile_perm();
+//! assert_eq!(U, TilePerm::from(&BasicMove::<3>::U));
+//! assert_eq!(TURNED, TilePerm::<3>::from(&X));
+//!
+//! // Large cubes compute the same permutations at run time: U moves the 20² Up tiles
+//! // and a ring of 4·20 side tiles, all in 4-cycles
+//! let large = TilePerm::<20>::from(&BasicMove::<20>::U);
+//! assert!(large.cycles().iter().all(|cycle| cycle.len() == 4));
+//! assert_eq!(large.cycles().len(), (20 * 20 + 4 * 20) / 4);
+//! ```
+
+use crate::core::cube::geometry::{Face, FACES};
+use crate::core::cube::rotations::CubeRotation;
+use crate::core::rubiks::dynamic::DynMove;
+use crate::core::rubiks::moves::{BasicMove, MiddleMove, RangeMove, SliceMove, WideMove};
+use crate::core::Angle;
+
+use crate::error::Error;
+
+use super::{partial::PartialTilePerm, TileGridHandle, TilePerm, TilePos};
+
+#[cfg(test)]
+mod tests;
+
+/// The largest cube size whose basic move and rotation permutations are tabulated at
+/// compile time.
+pub const MAX_TABLE_N: usize = 7;
+
+/// The destination grids of the six faces, in [`FACES`] order.
+type FaceGrids<const N: usize> = [[[TilePos; N]; N]; 6];
+
+/// The number of clockwise quarter turns in an angle.
+pub(crate) const fn quarter_turns(amount: Angle) -> usize {
+    match amount {
+        Angle::Zero => 0,
+        Angle::CWQuarter => 1,
+        Angle::Half => 2,
+        Angle::ACWQuarter => 3,
+    }
+}
+
+/// The sum of the products of two vectors' coordinates.
+const fn dot(a: [i64; 3], b: [i32; 3]) -> i64 {
+    a[0] * b[0] as i64 + a[1] * b[1] as i64 + a[2] * b[2] as i64
+}
+
+/// The centre of a tile of an N×N×N cube in doubled coordinates.
+pub(crate) const fn doubled_centre(tile: TilePos, n: usize) -> [i64; 3] {
+    let corner = tile.face.principal_corner().signs();
+    let (rows, cols) = (tile.face.row_direction(), tile.face.col_direction());
+    let (n, row, col) = (n as i64, 2 * tile.row as i64 + 1, 2 * tile.col as i64 + 1);
+    [
+        corner[0] as i64 * n + rows[0] as i64 * row + cols[0] as i64 * col,
+        corner[1] as i64 * n + rows[1] as i64 * row + cols[1] as i64 * col,
+        corner[2] as i64 * n + rows[2] as i64 * row + cols[2] as i64 * col,
+    ]
+}
+
+/// The tile of an N×N×N cube whose centre is at a point in doubled coordinates, the
+/// inverse of [`doubled_centre`].
+pub(crate) const fn tile_at_doubled(point: [i64; 3], n: usize) -> TilePos {
+    let n = n as i64;
+    let face = match point {
+        [x, _, _] if x == n => Face::Right,
+        [x, _, _] if x == -n => Face::Left,
+        [_, y, _] if y == n => Face::Up,
+        [_, y, _] if y == -n => Face::Down,
+        [_, _, z] if z == n => Face::Front,
+        [_, _, z] if z == -n => Face::Back,
+        _ => panic!("tile centres lie on the surface"),
+    };
+    let corner = face.principal_corner().signs();
+    let offset = [point[0] - corner[0] as i64 * n, point[1] - corner[1] as i64 * n, point[2] - corner[2] as i64 * n];
+    let (row, col) = (dot(offset, face.row_direction()), dot(offset, face.col_direction()));
+    TilePos { face, row: (row as usize - 1) / 2, col: (col as usize - 1) / 2 }
+}
+
+/// Rotates a point by `turns` clockwise quarter turns about a face normal, as seen
+/// looking at that face.
+const fn turn_point(point: [i64; 3], axis: [i32; 3], turns: usize) -> [i64; 3] {
+    let a = [axis[0] as i64, axis[1] as i64, axis[2] as i64];
+    let mut p = point;
+    let mut turn = 0;
+    while turn < turns {
+        let along = dot(p, axis);
+        let cross = [a[1] * p[2] - a[2] * p[1], a[2] * p[0] - a[0] * p[2], a[0] * p[1] - a[1] * p[0]];
+        // A clockwise quarter turn is -90° about the outward normal
+        p = [a[0] * along - cross[0], a[1] * along - cross[1], a[2] * along - cross[2]];
+        turn += 1;
+    }
+    p
+}
+
+/// Where a rotation with the given matrix sends a tile of an N×N×N cube.
+pub(crate) const fn rotated_tile(tile: TilePos, n: usize, matrix: [[i32; 3]; 3]) -> TilePos {
+    let p = doubled_centre(tile, n);
+    tile_at_doubled([dot(p, matrix[0]), dot(p, matrix[1]), dot(p, matrix[2])], n)
+}
+
+/// A turn of the consecutive layers `start..=end` (1-based) counted from `face`.
+///
+/// Every move family is a layer turn: basic moves turn layer 1, wide moves layers
+/// `1..=depth`, slice moves a single layer, range moves a range, and middle moves the
+/// central layer counted from their reference face.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct LayerTurn {
+    pub(crate) face: Face,
+    pub(crate) amount: Angle,
+    pub(crate) start: usize,
+    pub(crate) end: usize,
+}
+
+impl LayerTurn {
+    /// The turn a move performs on an N×N×N cube.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the move's layers do not fit the cube; see
+    /// [`DynMove::layers`], the one place layers are checked.
+    pub(crate) fn try_new(mv: DynMove, n: usize) -> Result<Self, Error> {
+        let (start, end) = mv.layers(n)?;
+        Ok(LayerTurn { face: mv.face(), amount: mv.amount(), start, end })
+    }
+
+    /// As [`LayerTurn::try_new`], for the conversions that cannot fail.
+    ///
+    /// # Panics
+    ///
+    /// Panics with the error's message if the move's layers do not fit the cube.
+    pub(crate) fn new(mv: DynMove, n: usize) -> Self {
+        Self::try_new(mv, n).unwrap_or_else(|error| panic!("{error}"))
+    }
+
+    /// The layer of an N×N×N cube, counted from the turned face, that a tile's cubie
+    /// lies in.
+    pub(crate) const fn layer_of(self, tile: TilePos, n: usize) -> usize {
+        let p = doubled_centre(tile, n);
+        let (inward, normal) = (tile.face.normal(), self.face.normal());
+        // Step from the tile's centre to its cubie's centre before measuring the depth
+        let cubie = [p[0] - inward[0] as i64, p[1] - inward[1] as i64, p[2] - inward[2] as i64];
+        (n as i64 - dot(cubie, normal)) as usize / 2 + 1
+    }
+
+    /// Where this turn sends a tile of an N×N×N cube.
+    pub(crate) const fn image(self, tile: TilePos, n: usize) -> TilePos {
+        let layer = self.layer_of(tile, n);
+        if layer < self.start || layer > self.end {
+            return tile;
+        }
+        let p = turn_point(doubled_centre(tile, n), self.face.normal(), quarter_turns(self.amount));
+        tile_at_doubled(p, n)
+    }
+
+    /// The tiles of an N×N×N cube in the turned layers, without visiting the others.
+    pub(crate) fn moved_tiles(self, n: usize) -> impl Iterator<Item = TilePos> {
+        let whole = move |face: Face| (0..n * n).map(move |i| TilePos { face, row: i / n, col: i % n });
+        FACES.into_iter().flat_map(move |face| -> Box<dyn Iterator<Item = TilePos>> {
+            if face == self.face {
+                return Box::new(whole(face).filter(move |_| self.start == 1));
+            }
+            if face == self.face.opposite() {
+                return Box::new(whole(face).filter(move |_| self.end == n));
+            }
+            // On a side face the layer varies along exactly one of the row and column
+            let by_row = self.face.normal() == face.row_direction()
+                || self.face.opposite().normal() == face.row_direction();
+            let line = move |i: usize, j: usize| if by_row { TilePos { face, row: i, col: j } } else { TilePos { face, row: j, col: i } };
+            Box::new(
+                (0..n)
+                    .filter(move |&i| (self.start..=self.end).contains(&self.layer_of(line(i, 0), n)))
+                    .flat_map(move |i| (0..n).map(move |j| line(i, j))),
+            )
+        })
+    }
+
+    /// The full permutation of this turn, computed at run time.
+    pub(crate) fn tile_perm<const N: usize>(self) -> TilePerm<N> {
+        TilePerm::from_fn(|tile| self.image(tile, N))
+    }
+
+    /// The sparse permutation of the tiles in the turned layers.
+    pub(crate) fn partial_perm<const N: usize>(self) -> PartialTilePerm<N> {
+        PartialTilePerm(self.moved_tiles(N).map(|tile| (tile, self.image(tile, N))).collect())
+    }
+}
+
+impl<const N: usize> From<BasicMove<N>> for LayerTurn {
+    fn from(mv: BasicMove<N>) -> Self {
+        LayerTurn::new(mv.into(), N)
+    }
+}
+
+impl<const N: usize> From<WideMove<N>> for LayerTurn {
+    fn from(mv: WideMove<N>) -> Self {
+        LayerTurn::new(mv.into(), N)
+    }
+}
+
+impl<const N: usize> From<SliceMove<N>> for LayerTurn {
+    fn from(mv: SliceMove<N>) -> Self {
+        LayerTurn::new(mv.into(), N)
+    }
+}
+
+impl<const N: usize> From<RangeMove<N>> for LayerTurn {
+    fn from(mv: RangeMove<N>) -> Self {
+        LayerTurn::new(mv.into(), N)
+    }
+}
+
+impl<const N: usize> From<MiddleMove<N>> for LayerTurn {
+    fn from(mv: MiddleMove<N>) -> Self {
+        LayerTurn::new(mv.into(), N)
+    }
+}
+
+/// Fills the grids of the six faces from a tile motion.
+const fn grids_of<const N: usize>(turn: Option<LayerTurn>, matrix: [[i32; 3]; 3]) -> FaceGrids<N> {
+    let mut grids = [[[TilePos { face: Face::Up, row: 0, col: 0 }; N]; N]; 6];
+    let mut f = 0;
+    while f < 6 {
+        let mut row = 0;
+        while row < N {
+            let mut col = 0;
+            while col < N {
+                let tile = TilePos { face: FACES[f], row, col };
+                grids[f][row][col] = match turn {
+                    Some(turn) => turn.image(tile, N),
+                    None => rotated_tile(tile, N, matrix),
+                };
+                col += 1;
+            }
+            row += 1;
+        }
+        f += 1;
+    }
+    grids
+}
+
+/// The grids of the 18 basic moves, in [`BasicMove::ALL`] order.
+const fn basic_table<const N: usize>() -> [FaceGrids<N>; 18] {
+    const AMOUNTS: [Angle; 3] = [Angle::CWQuarter, Angle::Half, Angle::ACWQuarter];
+    let mut table = [[[[TilePos { face: Face::Up, row: 0, col: 0 }; N]; N]; 6]; 18];
+    let mut i = 0;
+    while i < 18 {
+        let turn = LayerTurn { face: FACES[i / 3], amount: AMOUNTS[i % 3], start: 1, end: 1 };
+        table[i] = grids_of(Some(turn), [[0; 3]; 3]);
+        i += 1;
+    }
+    table
+}
+
+/// The grids of the 24 rotations, in [`CubeRotation::all`] order.
+const fn rotation_table<const N: usize>() -> [FaceGrids<N>; 24] {
+    let mut table = [[[[TilePos { face: Face::Up, row: 0, col: 0 }; N]; N]; 6]; 24];
+    let mut i = 0;
+    while i < 24 {
+        table[i] = grids_of(None, CubeRotation::from_rank(i).matrix());
+        i += 1;
+    }
+    table
+}
+
+/// The compile-time tables for one cube size, empty above [`MAX_TABLE_N`].
+struct Tables<const N: usize>;
+
+impl<const N: usize> Tables<N> {
+    // Every constant a function mentions is evaluated when it is instantiated, so
+    // large N must get cheap empty tables rather than skip an expensive branch
+    const BASIC: &'static [FaceGrids<N>] = if N <= MAX_TABLE_N { &basic_table::<N>() } else { &[] };
+    const ROTATIONS: &'static [FaceGrids<N>] = if N <= MAX_TABLE_N { &rotation_table::<N>() } else { &[] };
+}
+
+/// Builds a permutation whose grids borrow a static table entry.
+const fn from_table<const N: usize>(grids: &'static FaceGrids<N>) -> TilePerm<N> {
+    TilePerm {
+        up: TileGridHandle::from_static(&grids[0]),
+        down: TileGridHandle::from_static(&grids[1]),
+        left: TileGridHandle::from_static(&grids[2]),
+        right: TileGridHandle::from_static(&grids[3]),
+        front: TileGridHandle::from_static(&grids[4]),
+        back: TileGridHandle::from_static(&grids[5]),
+    }
+}
+
+impl<const N: usize> BasicMove<N> {
+    /// The tile permutation of this move, read from a table evaluated at compile time.
+    ///
+    /// This is a `const fn`, so `const U: TilePerm<3> = BasicMove::U.tile_perm();` is a
+    /// compile-time constant. Its grids are static, so it allocates nothing until it is
+    /// mutated. `TilePerm::from(&mv)` gives the same permutation for every N.
+    ///
+    /// Fails to compile for N above [`MAX_TABLE_N`].
+    pub const fn tile_perm(self) -> TilePerm<N> {
+        const { assert!(N <= MAX_TABLE_N, "basic move tables are only built up to MAX_TABLE_N") };
+        from_table(&Tables::<N>::BASIC[self as usize])
+    }
+}
+
+impl CubeRotation {
+    /// The tile permutation of this rotation, read from a table evaluated at compile
+    /// time.
+    ///
+    /// This is a `const fn`, like [`BasicMove::tile_perm`]. `TilePerm::from(&rotation)`
+    /// gives the same permutation for every N.
+    ///
+    /// Fails to compile for N above [`MAX_TABLE_N`].
+    pub const fn tile_perm<const N: usize>(self) -> TilePerm<N> {
+        const { assert!(N <= MAX_TABLE_N, "rotation tables are only built up to MAX_TABLE_N") };
+        from_table(&Tables::<N>::ROTATIONS[self.rank()])
+    }
+}
+
+/// The permutation of a basic move, borrowing the compile-time table when N is at
+/// most [`MAX_TABLE_N`].
+pub(crate) fn basic_move_perm<const N: usize>(mv: BasicMove<N>) -> TilePerm<N> {
+    match Tables::<N>::BASIC.get(mv as usize) {
+        Some(grids) => from_table(grids),
+        None => LayerTurn::from(mv).tile_perm(),
+    }
+}
+
+/// The permutation of a rotation, borrowing the compile-time table when N is at most
+/// [`MAX_TABLE_N`].
+pub(crate) fn rotation_perm<const N: usize>(rotation: CubeRotation) -> TilePerm<N> {
+    match Tables::<N>::ROTATIONS.get(rotation.rank()) {
+        Some(grids) => from_table(grids),
+        None => {
+            let matrix = rotation.matrix();
+            TilePerm::from_fn(|tile| rotated_tile(tile, N, matrix))
+        }
+    }
+}
@@ -0,0 +1,117 @@
+use std::collections::HashSet;
+
+use super::*;
+use crate::core::cube::rotations::{X, Y, Z};
+use crate::core::rubiks::test_support::{check_all_moves, reference_rotation, reference_turn, Layers, Move, MoveCheck};
+use crate::core::rubiks::tiles::partial::PartialTilePerm;
+
+/// Checks a move's permutation against the reference permutation of its layers, and
+/// that its moved tiles are exactly the tiles of those layers.
+struct MatchesReference;
+
+impl<const N: usize> MoveCheck<N> for MatchesReference {
+    fn check<M: Move<N>>(&mut self, mv: M, layers: Layers) {
+        let perm: TilePerm<N> = mv.into();
+        assert_eq!(perm, reference_turn(layers), "{mv:?} on N = {N}");
+
+        let turn: LayerTurn = mv.into();
+        let moved: Vec<_> = turn.moved_tiles(N).collect();
+        let set: HashSet<_> = moved.iter().copied().collect();
+        assert_eq!(set.len(), moved.len(), "{mv:?} visits a tile twice");
+        let Layers { face, amount, start, end } = layers;
+        let expected = LayerTurn { face, amount, start, end };
+        for tile in TilePos::all::<N>() {
+            let layer = expected.layer_of(tile, N);
+            assert_eq!(set.contains(&tile), (start..=end).contains(&layer), "{tile:?} under {mv:?}");
+        }
+    }
+}
+
+#[test]
+fn test_moves_match_reference() {
+    check_all_moves::<1>(&mut MatchesReference);
+    check_all_moves::<2>(&mut MatchesReference);
+    check_all_moves::<3>(&mut MatchesReference);
+    check_all_moves::<4>(&mut MatchesReference);
+    check_all_moves::<5>(&mut MatchesReference);
+}
+
+/// Checks the tables against the run-time arithmetic, and the rotations against the
+/// reference and whole cube turns.
+fn assert_tables_match<const N: usize>() {
+    for mv in BasicMove::<N>::ALL {
+        assert_eq!(TilePerm::from(&mv), LayerTurn::from(mv).tile_perm(), "{mv:?}");
+        assert_eq!(TilePerm::from(&mv), PartialTilePerm::from(&mv).to_tile_perm(), "{mv:?}");
+    }
+    for rotation in CubeRotation::all() {
+        assert_eq!(TilePerm::<N>::from(&rotation), reference_rotation(rotation), "{rotation:?}");
+    }
+    let whole = |face| TilePerm::<N>::from(&RangeMove::<N>::from_parts(face, Angle::CWQuarter, 1, N));
+    assert_eq!(TilePerm::<N>::from(&X), whole(Face::Right));
+    assert_eq!(TilePerm::<N>::from(&Y), whole(Face::Up));
+    assert_eq!(TilePerm::<N>::from(&Z), whole(Face::Front));
+}
+
+#[test]
+fn test_tables_match_arithmetic() {
+    assert_tables_match::<1>();
+    assert_tables_match::<2>();
+    assert_tables_match::<3>();
+    assert_tables_match::<4>();
+    assert_tables_match::<MAX_TABLE_N>();
+    // Past the tables, conversions fall back to run-time arithmetic
+    assert_tables_match::<{ MAX_TABLE_N + 1 }>();
+    assert!(Tables::<{ MAX_TABLE_N + 1 }>::BASIC.is_empty());
+}
+
+#[test]
+fn test_rotations_are_a_homomorphism() {
+    for a in CubeRotation::all() {
+        for b in CubeRotation::all() {
+            assert_eq!(TilePerm::<3>::from(&(a * b)), TilePerm::from(&a) * TilePerm::from(&b), "{a:?} * {b:?}");
+        }
+    }
+}
+
+#[test]
+fn test_const_permutations() {
+    const U: TilePerm<3> = BasicMove::<3>::U.tile_perm();
+    const R3: TilePerm<4> = BasicMove::<4>::R3.tile_perm();
+    const TURNED: TilePerm<5> = Y.tile_perm();
+    assert_eq!(U, TilePerm::from(&BasicMove::<3>::U));
+    assert_eq!(R3, TilePerm::from(&BasicMove::<4>::R3));
+    assert_eq!(TURNED, TilePerm::<5>::from(&Y));
+    // Table-backed permutations borrow their grids until they are changed
+    assert!(U.up.is_static() && TilePerm::<3>::from(&BasicMove::<3>::U).front.is_static());
+    assert!(!TilePerm::<8>::from(&BasicMove::<8>::U).front.is_static());
+}
+
+#[test]
+#[should_panic(expected = "layer 5 is outside a 3×3×3 cube")]
+fn test_out_of_range_layer_panics() {
+    let _ = TilePerm::<3>::from(&SliceMove::<3>::Rs(5));
+}
+
+#[test]
+#[should_panic(expected = "layer 5 is outside a 3×3×3 cube")]
+fn test_out_of_range_layer_panics_sparsely() {
+    let _ = PartialTilePerm::<3>::from(&SliceMove::<3>::Rs(5));
+}
+
+#[test]
+#[should_panic(expected = "layer 0 is outside a 3×3×3 cube")]
+fn test_layer_zero_panics_in_place() {
+    crate::RubiksState::<3>::solved_in(crate::Western).apply(SliceMove::<3>::Rs(0));
+}
+
+#[test]
+#[should_panic(expected = "the layer range 3-2 is reversed")]
+fn test_reversed_range_panics_sparsely() {
+    let _ = PartialTilePerm::<4>::from(&RangeMove::<4>::Rr(3, 2));
+}
+
+#[test]
+#[should_panic(expected = "a 4×4×4 cube has no middle layer")]
+fn test_even_middle_move_panics() {
+    let _ = TilePerm::<4>::from(&MiddleMove::<4>::M);
+}
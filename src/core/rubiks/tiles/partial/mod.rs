@@ -1,47 +1,26 @@
-//! Sparse tile permutations and move construction primitives.
+//! Sparse tile permutations.
 //!
-//! This module provides the building blocks for constructing move permutations through
-//! compositional assembly of partial permutations. The core abstraction is [`PartialTilePerm`],
-//! which represents a permutation defined only on a subset of tile positions.
+//! The core abstraction is [`PartialTilePerm`], which represents a permutation
+//! defined only on a subset of tile positions. Any tiles not explicitly mapped are
+//! implicitly fixed (identity mapping).
 //!
-//! # Core Concept
+//! # Construction
 //!
-//! Move permutations are built by composing two fundamental operations:
-//! 1. **Face rotation**: Rotating tiles on a single face ([`rotate_face_only`])
-//! 2. **Edge cycling**: Cycling tiles along the edges of adjacent faces ([`rotate_outside_of_slice`])
-//!
-//! These operations return [`PartialTilePerm`] instances that can be composed via multiplication
-//! to build complete move permutations. Any tiles not explicitly mapped are implicitly fixed
-//! (identity mapping).
-//!
-//! # Key Types and Functions
-//!
-//! - [`PartialTilePerm`]: Sparse permutation using HashMap representation
-//! - [`rotate_face_only`]: Creates a partial permutation rotating one face's tiles
-//! - [`rotate_outside_of_slice`]: Creates a partial permutation cycling edge tiles around a slice
-//! - [`RestrictionToPartial`]: Trait for converting restrictions to identity partial permutations
-//!
-//! # Construction Pattern
-//!
-//! All move types follow the same construction pattern:
+//! Every move converts to the partial permutation of the tiles in the layers it turns,
+//! with each tile mapped to where the turn sends it, as computed by the
+//! [`motion`](super::motion) module:
 //!
 //! ```ignore
 //! // Example: Basic U move
-//! let face_perm = rotate_face_only::<N>(Face::Up, Angle::CWQuarter);
-//! let edge_perm = rotate_outside_of_slice::<N>(
-//!     Slice { face: Face::Up, slice_index: 0 },
-//!     Angle::CWQuarter
-//! );
-//! let u_move_partial = &face_perm * &edge_perm;
-//! let u_move_full = TilePerm::from(&u_move_partial);
+//! let turn = LayerTurn::from(BasicMove::<N>::U);
+//! let u_move_partial = PartialTilePerm(turn.moved_tiles(N).map(|t| (t, turn.image(t, N))).collect());
+//! let u_move_full = u_move_partial.to_tile_perm();
 //! ```
 //!
-//! This pattern extends to all move types:
-//! - **Basic moves**: One face + one slice edge cycle
-//! - **Wide moves**: Multiple faces + multiple slice edge cycles
-//! - **Slice moves**: Conditional face (if end slice) + one slice edge cycle
-//! - **Range moves**: Multiple conditional faces + multiple slice edge cycles
-//! - **Middle moves**: Only edge cycle (no face rotation)
+//! The tiles in the turned layers are found without visiting the others, so a move's
+//! partial permutation costs O(moved tiles) to build, and it agrees with the move's
+//! [`TilePerm`] by construction. Partial permutations can also be assembled by hand
+//! with [`PartialTilePerm::from_pairs`].
 //!
 //! # Composition Through Multiplication
 //!
@@ -55,25 +34,48 @@
 //!
 //! # Conversion to Full Permutations
 //!
-//! [`PartialTilePerm`] implements `Into<TilePerm<N>>` by extending the partial mapping
-//! with the identity for all unmapped positions. This conversion happens at the final
-//! step after all partial permutations have been composed.
+//! [`PartialTilePerm::to_tile_perm`] extends the partial mapping with the identity for
+//! all unmapped positions.
 //!
-//! # Design Rationale
+//! # Sparse Application
+//!
+//! A partial permutation is also a public operation in its own right. A basic move of a
+//! 7×7×7 cube moves 4·7 + 49 of its 294 tiles, so keeping a move as a
+//! `PartialTilePerm` lets every use of it cost O(moved tiles) rather than O(6N²):
+//!
+//! - `state * &partial` on an owned [`RubiksState`] and [`RubiksState::apply`] rewrite
+//!   only the moved stickers
+//! - `&partial * &perm` copies `perm` and patches only the moved tiles' entries
+//! - `&partial * &partial` touches only the two domains
+//!
+//! ```
+//! use rubiks_cube_representation::core::rubiks::moves::BasicMove;
+//! use rubiks_cube_representation::core::rubiks::tiles::partial::PartialTilePerm;
+//! use rubiks_cube_representation::core::rubiks::tiles::TilePerm;
+//! use rubiks_cube_representation::{RubiksState, Western};
+//!
+//! let r = PartialTilePerm::<7>::from(&BasicMove::R);
+//! assert_eq!(r.len(), 4 * 7 + 49);
 //!
-//! Using partial permutations keeps move construction clean and modular:
-//! - Each operation (face rotation, edge cycling) is implemented independently
-//! - Operations compose naturally through multiplication
-//! - No need to manually track which tiles are affected
-//! - HashMap provides efficient sparse representation
+//! let cube = RubiksState::<7>::solved_in(Western);
+//! let turned = cube.clone() * &r;
+//! assert_eq!(turned, &cube * &BasicMove::R);
 //!
-//! The two helper functions ([`rotate_face_only`], [`rotate_outside_of_slice`]) provide
-//! the only geometric logic needed. All move types are then simple compositions of these
-//! primitives, with the complexity isolated to these two well-tested functions.
+//! // Four turns are the identity, which is empty once fixed tiles are dropped
+//! let twice = &r * &r;
+//! assert!((&twice * &twice).without_fixed().is_empty());
+//! assert_eq!(&r * &TilePerm::from(&BasicMove::<7>::R3), TilePerm::ID);
+//! ```
+//!
+//! # Design Rationale
+//!
+//! A `HashMap` keyed by tile keeps the representation sparse, so composing, inverting
+//! and applying a move cost time in proportion to the tiles it moves rather than to the
+//! whole cube.
 
-use std::{collections::{hash_set, HashMap, HashSet}, ops::Mul};
+use std::{collections::HashMap, ops::Mul};
 
-use crate::{core::{cube::geometry::FACE_SIDES, rubiks::tiles::{restrictions::{Restriction, Slice}, TilePos}, Angle}, Face};
+use crate::{core::rubiks::{tiles::{restrictions::TileSet, TilePerm, TilePos}, RubiksState}, error::Error, Face};
 
 #[cfg(test)]
 mod tests;
@@ -105,28 +107,86 @@ mod tests;
 ///
 /// # Conversion to Full Permutation
 ///
-/// `PartialTilePerm<N>` implements `Into<TilePerm<N>>` by extending the partial
-/// permutation with the identity for all unmapped positions. This conversion is
-/// used when applying the permutation to cube state.
+/// [`to_tile_perm`](PartialTilePerm::to_tile_perm) extends the partial permutation
+/// with the identity for all unmapped positions.
 ///
 /// # Usage
 ///
-/// Partial permutations are used to build up complete move permutations through
-/// composition:
-/// 1. `rotate_face_only` creates a partial permutation rotating one face
-/// 2. `rotate_outside_of_slice` creates a partial permutation for edge tiles
-/// 3. These are composed via multiplication to build the complete move permutation
-///
-/// This compositional approach keeps move implementations clean and modular.
+/// Every move converts to the partial permutation of the tiles it turns, with `From`
+/// or [`try_from_move`](PartialTilePerm::try_from_move), and partial permutations of
+/// any shape can be built with [`from_pairs`](PartialTilePerm::from_pairs).
 ///
 /// # Operations
 ///
-/// - **Multiplication** (`*`): Composes partial permutations
+/// - **Multiplication** (`*`): Composes with partial permutations and full
+///   [`TilePerm`]s, and applies to [`RubiksState`]s
 /// - **Inverse** ([`inverse`](PartialTilePerm::inverse)): Computes the inverse partial permutation
-#[derive(Clone, Debug, PartialEq, Eq)]
-pub(crate) struct PartialTilePerm<const N: usize>(pub HashMap<TilePos,TilePos>);
+///
+/// See the [module documentation](self) for the cost of each operation.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PartialTilePerm<const N: usize>(pub(crate) HashMap<TilePos,TilePos>);
 
 impl<const N: usize> PartialTilePerm<N> {
+    /// The empty partial permutation, which fixes every tile.
+    pub fn new() -> Self {
+        Self(HashMap::new())
+    }
+
+    /// Builds a partial permutation from `(tile, image)` pairs.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::TileOutOfRange`] if a tile lies off the N×N×N cube,
+    /// [`Error::ConflictingImages`] if a tile is given two images, or
+    /// [`Error::NotClosed`] if the images are not a rearrangement of the tiles, so that
+    /// the result would not be a permutation of its domain.
+    pub fn from_pairs(pairs: impl IntoIterator<Item = (TilePos, TilePos)>) -> Result<Self, Error> {
+        let mut map = HashMap::new();
+        for (from, to) in pairs {
+            from.check::<N>()?;
+            to.check::<N>()?;
+            if map.insert(from, to).is_some_and(|other| other != to) {
+                return Err(Error::ConflictingImages { tile: from });
+            }
+        }
+        let images: TileSet<N> = map.values().copied().collect();
+        if images.len() != map.len() || map.keys().any(|tile| !images.contains(tile)) {
+            return Err(Error::NotClosed);
+        }
+        Ok(Self(map))
+    }
+
+    /// The number of tiles the permutation is defined on, including any it fixes.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Whether the permutation is defined on no tiles.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// The tile that `tile` moves to, which is `tile` itself outside the domain.
+    pub fn image(&self, tile: TilePos) -> TilePos {
+        self.0.get(&tile).copied().unwrap_or(tile)
+    }
+
+    /// The `(tile, image)` pairs of the domain, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = (TilePos, TilePos)> + '_ {
+        self.0.iter().map(|(&from, &to)| (from, to))
+    }
+
+    /// The tiles the permutation is defined on.
+    pub fn domain(&self) -> TileSet<N> {
+        self.0.keys().copied().collect()
+    }
+
+    /// The same permutation with the tiles it fixes removed from its domain.
+    pub fn without_fixed(&self) -> Self {
+        Self(self.0.iter().filter(|(from, to)| from != to).map(|(&from, &to)| (from, to)).collect())
+    }
+
+    /// Computes the inverse partial permutation.
     pub fn inverse(&self) -> Self {
         Self(
             self
@@ -136,6 +196,11 @@ impl<const N: usize> PartialTilePerm<N> {
             .collect()
         )
     }
+
+    /// Extends the permutation with the identity to a full [`TilePerm`].
+    pub fn to_tile_perm(&self) -> TilePerm<N> {
+        TilePerm::from_fn(|tile| self.image(tile))
+    }
 }
 
 impl<'b, const N: usize> Mul<&'b PartialTilePerm<N>> for &PartialTilePerm<N> {
@@ -162,141 +227,65 @@ impl<'b, const N: usize> Mul<&'b PartialTilePerm<N>> for &PartialTilePerm<N> {
     }
 }
 
-pub(crate) trait RestrictionToPartial<const N: usize> : Restriction<N> {
-    fn partial_identity(&self) -> PartialTilePerm<N> {
-        PartialTilePerm(
-            self
-            .restricted_positions()
-            .map(|x|(x,x))
-            .collect()
-        )
+impl<const N: usize> Mul<&TilePerm<N>> for &PartialTilePerm<N> {
+    type Output = TilePerm<N>;
+
+    /// Applies `self`, then `rhs`: a copy of `rhs` with only the entries of `self`'s
+    /// domain changed.
+    fn mul(self, rhs: &TilePerm<N>) -> TilePerm<N> {
+        let mut out = rhs.clone();
+        for (&from, &to) in &self.0 {
+            *tile_mut(&mut out, from) = rhs[to];
+        }
+        out
     }
 }
 
-impl<const N: usize> PartialTilePerm<N> {
-    pub(crate) fn restriction_domain(&self) -> PartialTileSet<N> {
-        PartialTileSet(self.0.keys().cloned().collect())
+impl<const N: usize> Mul<&PartialTilePerm<N>> for &TilePerm<N> {
+    type Output = TilePerm<N>;
+
+    /// Applies `self`, then `rhs`.
+    fn mul(self, rhs: &PartialTilePerm<N>) -> TilePerm<N> {
+        TilePerm::from_fn(|tile| rhs.image(self[tile]))
     }
 }
 
-pub(crate) struct PartialTileSet<const N: usize>(HashSet<TilePos>);
+impl<const N: usize> Mul<&PartialTilePerm<N>> for RubiksState<N> {
+    type Output = RubiksState<N>;
 
-impl<const N: usize> Restriction<N> for PartialTileSet<N> 
-{
-    type Iter = hash_set::IntoIter<TilePos>;
-
-    fn restricted_positions(&self) -> Self::Iter 
-    {
+    /// Moves the stickers of the permutation's domain, leaving the rest of the state
+    /// untouched, in O(domain) time.
+    fn mul(mut self, perm: &PartialTilePerm<N>) -> RubiksState<N> {
+        self.apply(perm);
         self
-        .0
-        .clone()
-        .into_iter()
     }
 }
 
-impl<const N: usize, T: Restriction<N>> RestrictionToPartial<N> for T {}
+impl<const N: usize> Mul<&PartialTilePerm<N>> for &RubiksState<N> {
+    type Output = RubiksState<N>;
 
-/// Creates a partial permutation that rotates edge tiles around a slice.
-///
-/// This function generates a permutation for the tiles on the edges of adjacent faces
-/// at a specific slice depth. It handles the cyclic rotation of tiles around the four
-/// edges surrounding the slice, implementing the edge-movement portion of slice-based moves.
-///
-/// # Algorithm
-///
-/// For a slice at depth `d` from a reference face:
-/// 1. Get the four adjacent faces (North, East, South, West edges)
-/// 2. For each edge and each position index `i` along that edge:
-///    - Find source position at depth `d` on current edge
-///    - Find destination position at depth `d` on the edge rotated by `angle`
-///    - Map source → destination
-/// 3. This creates a cyclic permutation: North → East → South → West → North (for CW)
-///
-/// The key insight is using `side * angle` to determine which edge receives tiles
-/// from the current edge, and `adjacencies.on_side()` to get position information.
-///
-/// # Usage in Move Implementation
-///
-/// This is the second building block for move permutations (along with `rotate_face_only`).
-/// It handles how edge tiles cycle around when a slice rotates. Every move type uses this:
-/// - **Basic moves**: Slice 0 edges cycle around the face
-/// - **Slice moves**: Internal slice edges cycle at specified depth
-/// - **Wide/Range moves**: Multiple slices each get their edges cycled
-///
-/// # Parameters
-///
-/// - `N`: Cube dimension (compile-time constant)
-/// - `slice`: The [`Slice`] defining which edges to rotate
-/// - `angle`: Rotation angle determining the cyclic shift direction
-///
-/// # Returns
-///
-/// A [`PartialTilePerm<N>`] mapping edge tiles to their rotated positions.
-pub(crate) fn rotate_outside_of_slice<const N: usize>(slice: Slice, angle: Angle) -> PartialTilePerm<N> {
-    let Slice { face, slice_index } = slice;
-    let adjacencies = face.adjacencies();
-    let map = FACE_SIDES
-        .iter()
-        .flat_map(move |&side| {
-            (0..N)
-            .map(move |index| (adjacencies.on_side(side).side_pos_at_depth::<N>(index, slice_index),adjacencies.on_side(side*angle).side_pos_at_depth::<N>(index, slice_index)))
-        })
-        .collect();
-    PartialTilePerm(map)
+    fn mul(self, perm: &PartialTilePerm<N>) -> RubiksState<N> {
+        self.clone() * perm
+    }
 }
 
-/// Creates a partial permutation that rotates tiles on a single face.
-///
-/// This function generates a permutation affecting only the N² tiles on the specified
-/// face, rotating them by the given angle. All tiles not on this face are implicitly
-/// fixed (identity mapping).
-///
-/// # Algorithm
-///
-/// For each tile at position `(row, col)` on the face:
-/// 1. Apply [`Angle::rotate_indices`] to compute new position `(new_row, new_col)`
-/// 2. Map `(face, row, col)` → `(face, new_row, new_col)`
-///
-/// This implements the geometric rotation of the face's 2D grid while keeping tiles
-/// on the same face.
-///
-/// # Usage in Move Implementation
-///
-/// This is one of two building blocks for move permutations:
-/// - `rotate_face_only`: Handles the face tiles themselves
-/// - `rotate_outside_of_slice`: Handles the edge tiles on adjacent faces
-///
-/// These are composed via multiplication to build complete move permutations. For example,
-/// a basic U move composes:
-/// ```ignore
-/// let face_perm = rotate_face_only::<N>(Face::Up, Angle::CWQuarter);
-/// let edge_perm = rotate_outside_of_slice::<N>(
-///     Slice { face: Face::Up, slice_index: 0 },
-///     Angle::CWQuarter
-/// );
-/// let u_move = &face_perm * &edge_perm;
-/// ```
-///
-/// # Parameters
-///
-/// - `N`: Cube dimension (compile-time constant)
-/// - `face`: The face to rotate
-/// - `angle`: Rotation angle to apply
-///
-/// # Returns
-///
-/// A [`PartialTilePerm<N>`] defining the rotation on the face tiles.
-pub(crate) fn rotate_face_only<const N: usize>(face: Face, angle: Angle) -> PartialTilePerm<N> {
-    let map = (0..N)
-        .flat_map(|row|
-            (0..N)
-            .map(move |col| {
-                let base = TilePos { face, row, col } ;
-                let (new_row, new_col) = angle.rotate_indices::<N>(row, col);
-                let new = TilePos {face, row: new_row, col: new_col };
-                (base, new)
-            })
-        )
-        .collect();
-   PartialTilePerm(map)
+// Owned only; see the blanket `CubeOperation` impl in `tiles/mod.rs`
+impl<const N: usize> From<PartialTilePerm<N>> for TilePerm<N> {
+    fn from(perm: PartialTilePerm<N>) -> Self {
+        perm.to_tile_perm()
+    }
+}
+
+/// The entry of a tile in a full permutation, for patching it in place.
+fn tile_mut<const N: usize>(perm: &mut TilePerm<N>, tile: TilePos) -> &mut TilePos {
+    let TilePos { face, row, col } = tile;
+    let grid = match face {
+        Face::Up => &mut perm.up,
+        Face::Down => &mut perm.down,
+        Face::Left => &mut perm.left,
+        Face::Right => &mut perm.right,
+        Face::Front => &mut perm.front,
+        Face::Back => &mut perm.back,
+    };
+    &mut grid.vals[row][col]
 }
@@ -0,0 +1,53 @@
+use super::*;
+use crate::core::rubiks::moves::{BasicMove, SliceMove};
+use crate::core::rubiks::test_support::scrambled;
+use crate::core::Angle;
+use crate::Face;
+
+fn tile(face: Face, row: usize, col: usize) -> TilePos {
+    TilePos { face, row, col }
+}
+
+#[test]
+fn test_from_pairs() {
+    let a = tile(Face::Up, 0, 0);
+    let b = tile(Face::Front, 1, 2);
+    let c = tile(Face::Back, 2, 2);
+    let cycle = PartialTilePerm::<3>::from_pairs([(a, b), (b, c), (c, a)]).unwrap();
+    assert_eq!((cycle.len(), cycle.image(a), cycle.image(tile(Face::Down, 1, 1))), (3, b, tile(Face::Down, 1, 1)));
+    assert_eq!(cycle.domain().len(), 3);
+    assert_eq!(&cycle * &cycle.inverse(), PartialTilePerm::from_pairs([(a, a), (b, b), (c, c)]).unwrap());
+    assert!((&cycle * &cycle.inverse()).without_fixed().is_empty());
+
+    assert!(PartialTilePerm::<3>::from_pairs([(a, b), (b, a), (a, c)]).is_err());
+    assert!(PartialTilePerm::<3>::from_pairs([(a, b), (b, c)]).is_err());
+    assert!(PartialTilePerm::<2>::from_pairs([(a, c), (c, a)]).is_err());
+    assert_eq!(PartialTilePerm::<2>::from_pairs([]), Ok(PartialTilePerm::new()));
+}
+
+/// Checks sparse application and composition against the full permutations.
+fn assert_sparse_matches<const N: usize, M>(mv: M, other: &TilePerm<N>)
+where
+    for<'a> &'a M: Into<PartialTilePerm<N>> + Into<TilePerm<N>>,
+{
+    let partial: PartialTilePerm<N> = (&mv).into();
+    let full: TilePerm<N> = (&mv).into();
+    let cube = scrambled::<N>();
+    assert_eq!(cube.clone() * &partial, &cube * full.clone());
+    assert_eq!(&cube * &partial, &cube * full.clone());
+    assert_eq!(&partial * other, &full * other);
+    assert_eq!(other * &partial, other * &full);
+    assert_eq!((&partial * &partial).to_tile_perm(), &full * &full);
+    assert_eq!(TilePerm::from(partial.inverse()), full.inverse());
+}
+
+#[test]
+fn test_sparse_operations_match_full() {
+    let scramble = TilePerm::<4>::from(&BasicMove::R) * &TilePerm::from(&BasicMove::F2) * &TilePerm::from(&BasicMove::U3);
+    for mv in BasicMove::<4>::ALL {
+        assert_sparse_matches(mv, &scramble);
+    }
+    for layer in 1..=4 {
+        assert_sparse_matches(SliceMove::<4>::from_parts(Face::Left, Angle::CWQuarter, layer), &scramble);
+    }
+}
@@ -25,6 +25,7 @@
 //! - [`SliceIter`]: Iterator handling end slices (with face) vs middle slices (edges only)
 //! - [`SliceRange`]: Multiple consecutive slices as a single restriction
 //! - [`SliceRangeIter`]: Iterator chaining multiple slice iterators together
+//! - [`TileSet`]: An explicit, ordered set of positions, e.g. the support of a permutation or an orbit
 //!
 //! # Design Philosophy
 //!
@@ -85,6 +86,8 @@
 //! - Ensure proper termination through state machine progression
 //! - Use `Box<dyn Iterator>` in `SliceRangeIter` to work around type inference limitations
 
+use std::collections::{btree_set, BTreeSet};
+
 use crate::{core::{cube::geometry::{Adjacencies, FaceSide}, rubiks::tiles::TilePos}, Face};
 
 #[cfg(test)]
@@ -114,6 +117,7 @@ mod tests;
 /// - [`Slice`]: A single horizontal slice through the cube at a given depth
 /// - `SliceRange`: Multiple consecutive slices (internal type)
 /// - `CombinedRestriction`: Union of two restrictions (internal type)
+/// - [`TileSet`]: An explicit set of positions
 ///
 /// # Iterator Requirements
 ///
@@ -537,4 +541,95 @@ impl<const N: usize> Restriction<N> for SliceRange {
     fn restricted_positions(&self) -> Self::Iter {
         slice_range(self.face,self.start_slice_index,self.end_slice_index)
     }
-}
\ No newline at end of file
+}
+/// An explicit set of tile positions.
+///
+/// Unlike [`Slice`] and [`SliceRange`], which describe their tiles geometrically,
+/// a `TileSet<N>` stores arbitrary positions. It is the natural result of analyses that
+/// discover a set of tiles, such as the support of a permutation, and plugs into
+/// [`TilePerm::agree_on`](super::TilePerm::agree_on) like any other restriction.
+///
+/// Positions are kept in a [`BTreeSet`], so iteration follows the derived order of
+/// [`TilePos`] (face, then row, then column) and is deterministic.
+///
+/// # Examples
+///
+/// ```
+/// use rubiks_cube_representation::core::rubiks::tiles::restrictions::{Restriction, Slice, TileSet};
+/// use rubiks_cube_representation::Face;
+///
+/// let top: TileSet<3> = TileSet::from_restriction(&Slice { face: Face::Up, slice_index: 0 });
+/// assert_eq!(top.len(), 9 + 12);
+/// assert_eq!(top.restricted_positions().count(), 21);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct TileSet<const N: usize>(BTreeSet<TilePos>);
+
+impl<const N: usize> TileSet<N> {
+    /// Creates an empty set.
+    pub fn new() -> Self {
+        Self(BTreeSet::new())
+    }
+
+    /// Collects the positions of any restriction into a set.
+    pub fn from_restriction<R: Restriction<N>>(restriction: &R) -> Self {
+        restriction.restricted_positions().collect()
+    }
+
+    /// Adds a position, returning whether it was newly inserted.
+    pub fn insert(&mut self, pos: TilePos) -> bool {
+        self.0.insert(pos)
+    }
+
+    /// Returns whether the set contains the position.
+    pub fn contains(&self, pos: &TilePos) -> bool {
+        self.0.contains(pos)
+    }
+
+    /// Returns the number of positions in the set.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns whether the set is empty.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Iterates over the positions in order.
+    pub fn iter(&self) -> btree_set::Iter<'_, TilePos> {
+        self.0.iter()
+    }
+}
+
+impl<const N: usize> FromIterator<TilePos> for TileSet<N> {
+    fn from_iter<I: IntoIterator<Item = TilePos>>(iter: I) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
+impl<const N: usize> IntoIterator for TileSet<N> {
+    type Item = TilePos;
+    type IntoIter = btree_set::IntoIter<TilePos>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<'a, const N: usize> IntoIterator for &'a TileSet<N> {
+    type Item = &'a TilePos;
+    type IntoIter = btree_set::Iter<'a, TilePos>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+impl<const N: usize> Restriction<N> for TileSet<N> {
+    type Iter = btree_set::IntoIter<TilePos>;
+
+    fn restricted_positions(&self) -> Self::Iter {
+        self.0.clone().into_iter()
+    }
+}
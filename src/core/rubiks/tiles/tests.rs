@@ -104,3 +104,46 @@ fn test_basic_moves_affect_only_their_face_slice() {
         }
     }
 }
+
+#[test]
+fn test_tile_index_round_trip() {
+    let tiles: Vec<TilePos> = TilePos::all::<4>().collect();
+    assert_eq!(tiles.len(), 96);
+    for (i, tile) in tiles.iter().enumerate() {
+        assert_eq!(tile.index::<4>(), i);
+        assert_eq!(TilePos::from_index::<4>(i), *tile);
+    }
+    // Index order agrees with the derived ordering
+    assert!(tiles.windows(2).all(|w| w[0] < w[1]));
+}
+
+#[test]
+fn test_large_cube_fits_small_stack() {
+    // Inline grids would need about 1.5 MB per permutation at this size, while a state
+    // of one-byte colours is about 60 kB
+    let worker = std::thread::Builder::new().stack_size(1024 * 1024).spawn(|| {
+        let r = TilePerm::<101>::from(&BasicMove::<101>::R);
+        let r_inverse = r.inverse();
+        assert_eq!(&r * &r_inverse, TilePerm::identity());
+        assert!(!r.up.is_static() && TilePerm::<101>::ID.up.is_static());
+
+        let cube = crate::RubiksState::<101>::solved_in(crate::Western);
+        let turned = &cube * r.clone();
+        assert!(!turned.is_solved());
+        assert!((&turned * r_inverse).is_solved());
+    });
+    worker.unwrap().join().unwrap();
+}
+
+#[test]
+fn test_tile_grid_literal_through_handle() {
+    let mut perm = TilePerm::<1>::ID;
+    let left = TilePos { face: Face::Left, row: 0, col: 0 };
+    let right = TilePos { face: Face::Right, row: 0, col: 0 };
+    perm.left = TileGrid { vals: [[right]] }.into();
+    perm.right.vals[0][0] = left;
+    assert_eq!(perm[left], right);
+    assert_eq!(perm.inverse()[right], left);
+    let grid: &TileGrid<1> = &perm.left;
+    assert_eq!(grid, &TileGrid { vals: [[right]] });
+}
@@ -0,0 +1,140 @@
+//! The crate-wide error type.
+//!
+//! Every fallible operation in the crate reports failure as an [`Error`], so callers
+//! can match on what went wrong instead of parsing messages, and can propagate it with
+//! `?` into anything that accepts a [`std::error::Error`]. Text that does not parse as
+//! a move or an algorithm is reported as an [`Error::Parse`] with the position and
+//! token at fault, while well-formed moves that do not fit the cube are reported as
+//! they are by the moves' `checked` methods.
+//!
+//! # Fallible Variants
+//!
+//! APIs that panic on invalid input have `try_` counterparts returning an [`Error`]
+//! instead:
+//!
+//! | Panicking                          | Fallible                           |
+//! |------------------------------------|------------------------------------|
+//! | [`TilePos::from_index`]            | [`TilePos::try_from_index`]        |
+//! | `RubiksState[tile]`                | [`RubiksState::try_get`]           |
+//! | `&mut RubiksState[tile]`           | [`RubiksState::try_get_mut`]       |
+//! | `DynRubiksState[tile]`             | [`DynRubiksState::try_get`]        |
+//! | `DynRubiksState * DynTilePerm`     | [`DynRubiksState::try_permute`]    |
+//! | `ColourPerm::from(&map)`           | [`ColourPerm::try_from_map`]       |
+//! | `TilePerm::from(&mv)`              | [`TilePerm::try_from_move`]        |
+//! | `PartialTilePerm::from(&mv)`       | [`PartialTilePerm::try_from_move`] |
+//!
+//! Moves whose layers do not fit the cube panic when converted or applied, with the
+//! message of the [`Error`] that the `try_from_move` conversions return instead, and
+//! that the `checked` methods of the layered move types, such as
+//! [`SliceMove::checked`], report up front.
+//!
+//! # Example
+//!
+//! ```
+//! use rubiks_cube_representation::{Colour, ColourScheme, Error, Face, Western, Y};
+//!
+//! let mut scheme = Western.rotated(Y);
+//! assert_eq!(scheme.get_face(Colour::Red), Ok(Face::Front));
+//!
+//! scheme.front = Colour::White;
+//! assert_eq!(scheme.get_face(Colour::Red), Err(Error::UnknownColour(Colour::Red)));
+//! assert_eq!(scheme.check(), Err(Error::DuplicateColour(Colour::White)));
+//! assert_eq!(Error::UnknownColour(Colour::Red).to_string(), "colour Red is not in the scheme");
+//! ```
+//!
+//! [`TilePos::from_index`]: crate::core::rubiks::tiles::TilePos::from_index
+//! [`TilePos::try_from_index`]: crate::core::rubiks::tiles::TilePos::try_from_index
+//! [`RubiksState::try_get`]: crate::RubiksState::try_get
+//! [`RubiksState::try_get_mut`]: crate::RubiksState::try_get_mut
+//! [`DynRubiksState::try_get`]: crate::core::rubiks::dynamic::DynRubiksState::try_get
+//! [`DynRubiksState::try_permute`]: crate::core::rubiks::dynamic::DynRubiksState::try_permute
+//! [`ColourPerm::try_from_map`]: crate::ColourPerm::try_from_map
+//! [`TilePerm::try_from_move`]: crate::core::rubiks::tiles::TilePerm::try_from_move
+//! [`PartialTilePerm::try_from_move`]: crate::core::rubiks::tiles::partial::PartialTilePerm::try_from_move
+//! [`SliceMove::checked`]: crate::core::rubiks::moves::SliceMove::checked
+
+use std::fmt;
+
+use crate::core::rubiks::dynamic::DynMove;
+use crate::core::rubiks::tiles::TilePos;
+use crate::core::Colour;
+use crate::Face;
+
+#[cfg(test)]
+mod tests;
+
+/// What went wrong in a fallible operation of this crate.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// A colour that no face of a colour scheme shows
+    UnknownColour(Colour),
+    /// A colour scheme showing one colour on two faces
+    DuplicateColour(Colour),
+    /// A face missing from a face-to-colour map
+    MissingFace(Face),
+    /// A flat tile index of at least 6·N² on an N×N×N cube
+    IndexOutOfRange { index: usize, n: usize },
+    /// A tile whose row or column is at least N on an N×N×N cube
+    TileOutOfRange { tile: TilePos, n: usize },
+    /// A layer number outside `1..=n`
+    InvalidLayer { layer: usize, n: usize },
+    /// A layer range whose start lies beyond its end
+    ReversedLayers { start: usize, end: usize },
+    /// A middle move on an N×N×N cube with N even
+    NoMiddleLayer { n: usize },
+    /// A value for one cube size used where another size is expected
+    SizeMismatch { expected: usize, found: usize },
+    /// A full permutation built from a number of tile images other than 6·N²
+    WrongImageCount { expected: usize, found: usize },
+    /// A flat tile index that is the image of two tiles
+    DuplicateImage { index: usize },
+    /// A tile given two different images
+    ConflictingImages { tile: TilePos },
+    /// Partial tile images that are not a rearrangement of the tiles they are given for
+    NotClosed,
+    /// A tile permutation that does not carry the piece holding `tile` rigidly onto a
+    /// piece of the same kind
+    NotRigid { tile: TilePos },
+    /// A move converted to a move family it does not belong to
+    WrongMoveFamily { mv: DynMove, family: &'static str },
+    /// A turn by [`Angle::Zero`](crate::core::Angle::Zero) converted to a move type,
+    /// none of which turns by zero
+    ZeroTurn { mv: DynMove },
+    /// A group generator whose order is too large to write words with
+    OrderTooLarge { generator: usize },
+    /// Text that does not parse, with the byte position of the token at fault, which is
+    /// empty at the end of the text
+    Parse { position: usize, token: String },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::UnknownColour(colour) => write!(f, "colour {colour:?} is not in the scheme"),
+            Error::DuplicateColour(colour) => write!(f, "colour {colour:?} is on more than one face of the scheme"),
+            Error::MissingFace(face) => write!(f, "no colour is given for the {face:?} face"),
+            Error::IndexOutOfRange { index, n } => write!(f, "tile index {index} is out of range for N = {n}"),
+            Error::TileOutOfRange { tile, n } => write!(f, "{tile:?} is not a tile of a {n}×{n}×{n} cube"),
+            Error::InvalidLayer { layer, n } => write!(f, "layer {layer} is outside a {n}×{n}×{n} cube"),
+            Error::ReversedLayers { start, end } => write!(f, "the layer range {start}-{end} is reversed"),
+            Error::NoMiddleLayer { n } => write!(f, "a {n}×{n}×{n} cube has no middle layer"),
+            Error::SizeMismatch { expected, found } => {
+                write!(f, "expected a {expected}×{expected}×{expected} cube, found a {found}×{found}×{found} one")
+            }
+            Error::WrongImageCount { expected, found } => write!(f, "expected {expected} tile images, found {found}"),
+            Error::DuplicateImage { index } => write!(f, "tile index {index} is the image of two tiles"),
+            Error::ConflictingImages { tile } => write!(f, "{tile:?} is given two images"),
+            Error::NotClosed => write!(f, "the images are not a rearrangement of the tiles"),
+            Error::NotRigid { tile } => write!(f, "the piece holding {tile:?} is not moved rigidly"),
+            Error::WrongMoveFamily { mv, family } => write!(f, "`{mv}` is not a {family} move"),
+            Error::ZeroTurn { mv } => write!(f, "`{mv}` turns by zero, which no move type represents"),
+            Error::OrderTooLarge { generator } => write!(f, "generator {generator} has an order above u32::MAX"),
+            Error::Parse { position, token } if token.is_empty() => {
+                write!(f, "unexpected end of text at position {position}")
+            }
+            Error::Parse { position, token } => write!(f, "unexpected `{token}` at position {position}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
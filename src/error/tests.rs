@@ -0,0 +1,91 @@
+use std::collections::HashMap;
+
+use super::*;
+use crate::core::rubiks::dynamic::{DynRubiksState, DynTilePerm};
+use crate::core::rubiks::moves::{MiddleMove, RangeMove, SliceMove, WideMove};
+use crate::core::rubiks::tiles::indexed::IndexPerm;
+use crate::core::rubiks::tiles::partial::PartialTilePerm;
+use crate::core::rubiks::tiles::TilePerm;
+use crate::{ColourPerm, ColourScheme, RubiksState, Western, FACES};
+
+#[test]
+fn test_colour_scheme_errors() {
+    assert_eq!(Western.get_face(Colour::Green), Ok(Face::Front));
+    let mut map: HashMap<Face, Colour> = FACES.into_iter().map(|face| (face, Western.from_face(face))).collect();
+    assert!(ColourPerm::try_from_map(&map).is_ok());
+
+    map.insert(Face::Back, Colour::Green);
+    assert_eq!(ColourPerm::try_from_map(&map).map(|_| ()), Err(Error::DuplicateColour(Colour::Green)));
+    assert_eq!(ColourPerm::from(&map).get_face(Colour::Blue), Err(Error::UnknownColour(Colour::Blue)));
+
+    map.remove(&Face::Left);
+    assert_eq!(ColourPerm::try_from_map(&map).map(|_| ()), Err(Error::MissingFace(Face::Left)));
+}
+
+#[test]
+fn test_tile_errors() {
+    let off = TilePos { face: Face::Up, row: 3, col: 0 };
+    assert_eq!(TilePos::try_from_index::<3>(53), Ok(TilePos { face: Face::Back, row: 2, col: 2 }));
+    assert_eq!(TilePos::try_from_index::<3>(54), Err(Error::IndexOutOfRange { index: 54, n: 3 }));
+
+    let mut cube = RubiksState::<3>::solved_in(Western);
+    assert_eq!(cube.try_get(TilePos { face: Face::Down, row: 2, col: 1 }), Ok(Colour::Yellow));
+    assert_eq!(cube.try_get(off), Err(Error::TileOutOfRange { tile: off, n: 3 }));
+    assert!(cube.try_get_mut(off).is_err());
+    *cube.try_get_mut(TilePos { face: Face::Up, row: 0, col: 0 }).unwrap() = Colour::Red;
+    assert_eq!(cube.up.vals[0][0], Colour::Red);
+
+    let dyn_cube = DynRubiksState::solved_in(3, Western);
+    assert_eq!(dyn_cube.try_get(off), Err(Error::TileOutOfRange { tile: off, n: 3 }));
+    assert_eq!(dyn_cube.try_permute(&DynTilePerm::identity(4)), Err(Error::SizeMismatch { expected: 3, found: 4 }));
+    assert_eq!(dyn_cube.try_permute(&DynTilePerm::identity(3)).as_ref(), Ok(&dyn_cube));
+    assert_eq!(RubiksState::<4>::try_from(&dyn_cube), Err(Error::SizeMismatch { expected: 4, found: 3 }));
+}
+
+#[test]
+fn test_layer_errors() {
+    assert_eq!(WideMove::<3>::Rw(3).checked(), Ok(WideMove::Rw(3)));
+    assert_eq!(WideMove::<3>::Rw(4).checked(), Err(Error::InvalidLayer { layer: 4, n: 3 }));
+    assert_eq!(SliceMove::<5>::Us(0).checked(), Err(Error::InvalidLayer { layer: 0, n: 5 }));
+    assert_eq!(RangeMove::<5>::Fr(3, 2).checked(), Err(Error::ReversedLayers { start: 3, end: 2 }));
+    assert_eq!(MiddleMove::<4>::M.checked(), Err(Error::NoMiddleLayer { n: 4 }));
+    assert_eq!(MiddleMove::<5>::M.checked(), Ok(MiddleMove::M));
+
+    assert_eq!(TilePerm::<3>::try_from_move(SliceMove::<3>::Rs(4)), Err(Error::InvalidLayer { layer: 4, n: 3 }));
+    assert_eq!(TilePerm::try_from_move(WideMove::<3>::Rw(3)), Ok(TilePerm::from(&WideMove::<3>::Rw(3))));
+    assert_eq!(PartialTilePerm::<4>::try_from_move(MiddleMove::<4>::M), Err(Error::NoMiddleLayer { n: 4 }));
+    assert_eq!(PartialTilePerm::try_from_move(RangeMove::<5>::Fr(3, 2)), Err(Error::ReversedLayers { start: 3, end: 2 }));
+    assert_eq!(PartialTilePerm::try_from_move(SliceMove::<5>::Us(3)), Ok(PartialTilePerm::from(&SliceMove::<5>::Us(3))));
+}
+
+#[test]
+fn test_permutation_errors() {
+    assert_eq!(IndexPerm::<1>::from_images(vec![0, 1, 2, 3, 4, 4]), Err(Error::DuplicateImage { index: 4 }));
+    assert_eq!(IndexPerm::<1>::from_images(vec![0, 1, 2]), Err(Error::WrongImageCount { expected: 6, found: 3 }));
+    assert_eq!(IndexPerm::<1>::from_images(vec![0, 1, 2, 3, 4, 9]), Err(Error::IndexOutOfRange { index: 9, n: 1 }));
+    let swap = TilePerm::<3>::from_fn(|tile| match (tile.row, tile.col) {
+        (0, 0) => TilePos { row: 0, col: 1, ..tile },
+        (0, 1) => TilePos { row: 0, col: 0, ..tile },
+        _ => tile,
+    });
+    assert_eq!(swap.piece_cycles(), Err(Error::NotRigid { tile: TilePos { face: Face::Back, row: 0, col: 0 } }));
+
+    let up = |col| TilePos { face: Face::Up, row: 0, col };
+    assert_eq!(PartialTilePerm::<3>::from_pairs([(up(0), up(1)), (up(0), up(2))]), Err(Error::ConflictingImages { tile: up(0) }));
+    assert_eq!(PartialTilePerm::<3>::from_pairs([(up(0), up(1))]), Err(Error::NotClosed));
+    let images = vec![up(0); 6];
+    assert_eq!(DynTilePerm::from_images(1, images), Err(Error::DuplicateImage { index: 0 }));
+}
+
+#[test]
+fn test_display() {
+    let error: Box<dyn std::error::Error> = Box::new(Error::InvalidLayer { layer: 4, n: 3 });
+    assert_eq!(error.to_string(), "layer 4 is outside a 3×3×3 cube");
+    assert_eq!(Error::ReversedLayers { start: 3, end: 2 }.to_string(), "the layer range 3-2 is reversed");
+    let mv = "Rw".parse().unwrap();
+    assert_eq!(Error::WrongMoveFamily { mv, family: "slice" }.to_string(), "`Rw` is not a slice move");
+    let mv = "R0".parse().unwrap();
+    assert_eq!(Error::ZeroTurn { mv }.to_string(), "`R0` turns by zero, which no move type represents");
+    assert_eq!(Error::Parse { position: 4, token: "]".to_string() }.to_string(), "unexpected `]` at position 4");
+    assert_eq!(Error::Parse { position: 5, token: String::new() }.to_string(), "unexpected end of text at position 5");
+}
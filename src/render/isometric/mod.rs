@@ -0,0 +1,215 @@
+//! Three-face isometric rendering of cube states.
+//!
+//! An [`IsometricView`] draws the Up, Front and Right faces of a cube the way a
+//! competitor sees it when holding the cube slightly below eye level: the Up face as a
+//! rhombus on top, the Front face on the lower left and the Right face on the lower
+//! right. Any other trio of faces can be shown by choosing a viewing
+//! [`CubeRotation`], which is applied to the state before drawing.
+//!
+//! # Projection
+//!
+//! With `x` to the right, `y` up and `z` towards the viewer, a 3D point is projected as
+//!
+//! ```text
+//! screen_x = (x - z) · cos 30°
+//! screen_y = (x + z) · sin 30° - y
+//! ```
+//!
+//! scaled by the tile size. Sticker `(row, col)` of each face is placed according to
+//! that face's [`principal_corner`](crate::core::cube::geometry::Face::principal_corner),
+//! so the picture agrees with the `TilePos` coordinate system used everywhere else.
+//!
+//! # Output
+//!
+//! - [`IsometricView::svg`]: SVG document, one `<polygon>` per sticker tagged with its
+//!   face, row and column
+//! - [`IsometricView::raster`]: [`RgbImage`] that can be saved as PPM or PNG
+//!
+//! # Example
+//!
+//! ```
+//! use rubiks_cube_representation::{RubiksState, Western, Y};
+//! use rubiks_cube_representation::render::isometric::IsometricView;
+//!
+//! let cube = RubiksState::<3>::solved_in(Western);
+//! let svg = IsometricView::default().svg(&cube);
+//! assert!(svg.starts_with("<svg"));
+//!
+//! // Look at the cube from a different side
+//! let png = IsometricView { view: Y, ..IsometricView::default() }.raster(&cube).to_png();
+//! ```
+
+use std::fmt::Write;
+
+use crate::core::cube::rotations::CubeRotation;
+use crate::core::rubiks::tiles::TilePos;
+use crate::core::rubiks::RubiksState;
+use crate::Face;
+
+use super::{face_corners, fmt_num, hex, rgb, tile_corners, RgbImage, BODY};
+
+#[cfg(test)]
+mod tests;
+
+/// Faces drawn by the isometric view, in drawing order.
+pub const VISIBLE_FACES: [Face; 3] = [Face::Up, Face::Front, Face::Right];
+
+/// Background colour of raster output.
+const BACKGROUND: [u8; 3] = [255, 255, 255];
+
+/// Settings for drawing an isometric picture of a cube.
+///
+/// # Fields
+///
+/// - **view**: Rotation applied to the state before drawing; the Up, Front and Right
+///   faces of the rotated cube are the ones shown
+/// - **tile_size**: Length of one sticker edge in pixels (SVG user units)
+/// - **sticker_gap**: Fraction of each tile left as a dark border around the sticker,
+///   from `0.0` (stickers touch) to just below `0.5`
+/// - **margin**: Empty space around the drawing in pixels
+///
+/// The drawing is `2·N·tile_size·cos 30° + 2·margin` wide and
+/// `2·N·tile_size + 2·margin` tall for an N×N×N cube.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct IsometricView {
+    /// Rotation applied to the cube before the Up, Front and Right faces are drawn
+    pub view: CubeRotation,
+    /// Length of one tile edge in pixels
+    pub tile_size: f64,
+    /// Fraction of each tile used as a border between stickers
+    pub sticker_gap: f64,
+    /// Empty space around the drawing in pixels
+    pub margin: f64,
+}
+
+impl Default for IsometricView {
+    /// The standard competitor's view: no rotation, 24-pixel tiles and thin borders.
+    fn default() -> Self {
+        Self { view: CubeRotation::ID, tile_size: 24.0, sticker_gap: 0.06, margin: 4.0 }
+    }
+}
+
+/// A single filled polygon of the drawing, in screen coordinates.
+struct Shape {
+    points: Vec<[f64; 2]>,
+    colour: [u8; 3],
+    tile: Option<TilePos>,
+}
+
+impl IsometricView {
+    /// Returns the `(width, height)` of the drawing for an N×N×N cube.
+    pub fn size<const N: usize>(&self) -> (f64, f64) {
+        let n = N as f64;
+        (
+            2.0 * n * self.tile_size * COS_30 + 2.0 * self.margin,
+            2.0 * n * self.tile_size + 2.0 * self.margin,
+        )
+    }
+
+    /// Renders the cube as an SVG document.
+    ///
+    /// Each face is drawn as a dark rhombus with one `<polygon>` per sticker on top.
+    /// Sticker polygons carry `data-face`, `data-row` and `data-col` attributes giving
+    /// their [`TilePos`] on the viewed (rotated) cube, which makes the output easy to
+    /// post-process or annotate.
+    pub fn svg<const N: usize>(&self, state: &RubiksState<N>) -> String {
+        let (width, height) = self.size::<N>();
+        let mut out = String::new();
+        let _ = writeln!(
+            out,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{w}" height="{h}" viewBox="0 0 {w} {h}">"#,
+            w = fmt_num(width, DECIMALS),
+            h = fmt_num(height, DECIMALS)
+        );
+        for shape in self.shapes(state) {
+            let points = shape
+                .points
+                .iter()
+                .map(|p| format!("{},{}", fmt_num(p[0], DECIMALS), fmt_num(p[1], DECIMALS)))
+                .collect::<Vec<_>>()
+                .join(" ");
+            match shape.tile {
+                Some(TilePos { face, row, col }) => {
+                    let _ = writeln!(
+                        out,
+                        r#"  <polygon class="sticker" data-face="{face:?}" data-row="{row}" data-col="{col}" points="{points}" fill="{}"/>"#,
+                        hex(shape.colour)
+                    );
+                }
+                None => {
+                    let _ = writeln!(out, r#"  <polygon class="body" points="{points}" fill="{}"/>"#, hex(shape.colour));
+                }
+            }
+        }
+        out.push_str("</svg>\n");
+        out
+    }
+
+    /// Renders the cube into a raster image on a white background.
+    ///
+    /// The image size is the drawing size from [`IsometricView::size`] rounded up to
+    /// whole pixels. Encode the result with [`RgbImage::to_ppm`] or [`RgbImage::to_png`].
+    pub fn raster<const N: usize>(&self, state: &RubiksState<N>) -> RgbImage {
+        let (width, height) = self.size::<N>();
+        let mut image = RgbImage::filled(width.ceil() as usize, height.ceil() as usize, BACKGROUND);
+        for shape in self.shapes(state) {
+            image.fill_convex(&shape.points, shape.colour);
+        }
+        image
+    }
+
+    /// Returns the screen position of the centre of a visible tile.
+    ///
+    /// Tile positions refer to the viewed cube, i.e. after [`IsometricView::view`] has
+    /// been applied. Returns `None` for tiles on the Down, Left and Back faces, which are
+    /// hidden from this viewpoint.
+    pub fn tile_centre<const N: usize>(&self, tile: TilePos) -> Option<[f64; 2]> {
+        if !VISIBLE_FACES.contains(&tile.face) {
+            return None;
+        }
+        let corners = tile_corners::<N>(tile, 0.0);
+        let centre = corners.iter().fold([0.0; 3], |acc, p| [acc[0] + p[0] / 4.0, acc[1] + p[1] / 4.0, acc[2] + p[2] / 4.0]);
+        Some(self.project::<N>(centre))
+    }
+
+    /// Builds the list of polygons to draw: face backgrounds first, then stickers.
+    fn shapes<const N: usize>(&self, state: &RubiksState<N>) -> Vec<Shape> {
+        let viewed = state * &self.view;
+        let mut shapes = Vec::with_capacity(3 + 3 * N * N);
+        for face in VISIBLE_FACES {
+            let whole = face_corners::<N>(face);
+            shapes.push(Shape {
+                points: whole.iter().map(|&p| self.project::<N>(p)).collect(),
+                colour: BODY,
+                tile: None,
+            });
+        }
+        for face in VISIBLE_FACES {
+            for row in 0..N {
+                for col in 0..N {
+                    let tile = TilePos { face, row, col };
+                    shapes.push(Shape {
+                        points: tile_corners::<N>(tile, self.sticker_gap).iter().map(|&p| self.project::<N>(p)).collect(),
+                        colour: rgb(viewed[tile]),
+                        tile: Some(tile),
+                    });
+                }
+            }
+        }
+        shapes
+    }
+
+    /// Projects a point of the `[0, N]³` cube frame to screen coordinates.
+    fn project<const N: usize>(&self, [x, y, z]: [f64; 3]) -> [f64; 2] {
+        let n = N as f64;
+        [
+            ((x - z) * COS_30 + n * COS_30) * self.tile_size + self.margin,
+            ((x + z) * 0.5 - y + n) * self.tile_size + self.margin,
+        ]
+    }
+}
+
+const COS_30: f64 = 0.866_025_403_784_438_6;
+
+/// Decimals written for SVG coordinates.
+const DECIMALS: usize = 3;
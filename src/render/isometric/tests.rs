@@ -0,0 +1,98 @@
+use super::*;
+use crate::core::cube::rotations::{X, Y};
+use crate::core::cube::schemes::{ColourScheme, Western};
+use crate::core::rubiks::moves::BasicMove;
+
+fn pixel_at<const N: usize>(view: &IsometricView, image: &RgbImage, tile: TilePos) -> [u8; 3] {
+    let [x, y] = view.tile_centre::<N>(tile).expect("tile should be visible");
+    image.pixel(x as usize, y as usize)
+}
+
+#[test]
+fn test_svg_has_one_polygon_per_visible_sticker() {
+    let cube = RubiksState::<3>::solved_in(Western);
+    let svg = IsometricView::default().svg(&cube);
+    assert_eq!(svg.matches(r#"class="sticker""#).count(), 27);
+    assert_eq!(svg.matches(r#"class="body""#).count(), 3);
+    assert!(svg.contains(r#"data-face="Up" data-row="0" data-col="0""#));
+    assert!(svg.trim_end().ends_with("</svg>"));
+}
+
+#[test]
+fn test_raster_shows_up_front_right() {
+    let view = IsometricView::default();
+    let cube = RubiksState::<3>::solved_in(Western);
+    let image = view.raster(&cube);
+    for face in VISIBLE_FACES {
+        for (row, col) in [(0, 0), (1, 1), (2, 2), (0, 2)] {
+            let tile = TilePos { face, row, col };
+            assert_eq!(pixel_at::<3>(&view, &image, tile), rgb(Western.from_face(face)),
+                "tile {:?} has the wrong colour", tile);
+        }
+    }
+}
+
+#[test]
+fn test_view_rotation_changes_visible_faces() {
+    let cube = RubiksState::<2>::solved_in(Western);
+
+    // Y brings the right face round to the front
+    let view = IsometricView { view: Y, ..IsometricView::default() };
+    let image = view.raster(&cube);
+    let front = TilePos { face: Face::Front, row: 0, col: 0 };
+    assert_eq!(pixel_at::<2>(&view, &image, front), rgb(Western.right()));
+
+    // X brings the front face up
+    let view = IsometricView { view: X, ..IsometricView::default() };
+    let image = view.raster(&cube);
+    let up = TilePos { face: Face::Up, row: 1, col: 1 };
+    assert_eq!(pixel_at::<2>(&view, &image, up), rgb(Western.front()));
+}
+
+#[test]
+fn test_sticker_positions_follow_tile_coordinates() {
+    // After U, the front face's top row shows the right face's colour,
+    // while the rest of the front face is untouched
+    let view = IsometricView::default();
+    let cube = RubiksState::<3>::solved_in(Western) * &BasicMove::<3>::U;
+    let image = view.raster(&cube);
+    for col in 0..3 {
+        let top = TilePos { face: Face::Front, row: 0, col };
+        let below = TilePos { face: Face::Front, row: 1, col };
+        assert_eq!(pixel_at::<3>(&view, &image, top), rgb(Western.right()));
+        assert_eq!(pixel_at::<3>(&view, &image, below), rgb(Western.front()));
+    }
+
+    // Up face (row, col) = (2, 2) is the corner next to the front and right faces
+    let corner = view.tile_centre::<3>(TilePos { face: Face::Up, row: 2, col: 2 }).unwrap();
+    let front_corner = view.tile_centre::<3>(TilePos { face: Face::Front, row: 0, col: 2 }).unwrap();
+    let right_corner = view.tile_centre::<3>(TilePos { face: Face::Right, row: 0, col: 0 }).unwrap();
+    assert!(corner[1] < front_corner[1] && corner[1] < right_corner[1]);
+    assert!(front_corner[0] < right_corner[0]);
+}
+
+#[test]
+fn test_works_for_any_dimension() {
+    let view = IsometricView::default();
+    let (w1, h1) = view.size::<1>();
+    let (w7, h7) = view.size::<7>();
+    assert!(w7 > w1 && h7 > h1);
+
+    let svg = view.svg(&RubiksState::<7>::solved_in(Western));
+    assert_eq!(svg.matches(r#"class="sticker""#).count(), 3 * 49);
+
+    let image = view.raster(&RubiksState::<1>::solved_in(Western));
+    assert_eq!((image.width, image.height), (w1.ceil() as usize, h1.ceil() as usize));
+
+    // A degenerate cube still produces a valid (empty) picture
+    let svg = view.svg(&RubiksState::<0>::solved_in(Western));
+    assert_eq!(svg.matches("<polygon").count(), 3);
+}
+
+#[test]
+fn test_hidden_faces_have_no_centre() {
+    let view = IsometricView::default();
+    assert!(view.tile_centre::<3>(TilePos { face: Face::Down, row: 1, col: 1 }).is_none());
+    assert!(view.tile_centre::<3>(TilePos { face: Face::Back, row: 1, col: 1 }).is_none());
+    assert!(view.tile_centre::<3>(TilePos { face: Face::Left, row: 1, col: 1 }).is_none());
+}
@@ -0,0 +1,498 @@
+//! 3D mesh export of cube states.
+//!
+//! A [`MeshExport`] turns a [`RubiksState<N>`] into a [`CubeMesh`]: one bevelled box per
+//! visible cubie and one coloured quad per sticker, floating just above the cubie it
+//! belongs to. The mesh can then be written as Wavefront OBJ (with a companion MTL
+//! material library) or as a self-contained glTF 2.0 JSON document, both of which
+//! Blender and most other DCC tools import without plugins.
+//!
+//! # Geometry
+//!
+//! The cube is centred on the origin with `x` to the right, `y` up and `z` towards the
+//! Front face, which matches the Y-up convention of glTF. One unit is one cubie, so an
+//! N×N×N cube spans `[-N/2, N/2]` on every axis. Stickers are laid out according to
+//! each face's principal corner, exactly as in the
+//! [`isometric`](super::isometric) renderer.
+//!
+//! # Naming
+//!
+//! Every part of the mesh becomes its own object (OBJ) or node (glTF):
+//!
+//! - Cubies are named `cubie_<x>_<y>_<z>` with integer coordinates from `0` to `N-1`
+//! - Stickers are named `sticker_<Face>_<row>_<col>` after their [`TilePos`]; glTF nodes
+//!   additionally carry the position as `extras` (`face`, `row`, `col`)
+//!
+//! Materials are named `body` for the plastic and after the [`Colour`] for stickers
+//! (`White`, `Yellow`, ...).
+//!
+//! # Example
+//!
+//! ```
+//! use rubiks_cube_representation::{RubiksState, Western};
+//! use rubiks_cube_representation::render::mesh::MeshExport;
+//!
+//! let cube = RubiksState::<3>::solved_in(Western);
+//! let mesh = MeshExport::default().mesh(&cube);
+//!
+//! let obj = mesh.to_obj("cube.mtl");
+//! let mtl = mesh.to_mtl();
+//! assert!(obj.contains("o sticker_Up_0_0"));
+//! assert!(mtl.contains("newmtl White"));
+//!
+//! let gltf = mesh.to_gltf();
+//! assert!(gltf.contains(r#""version":"2.0""#));
+//! ```
+
+use std::fmt::Write;
+
+use crate::core::rubiks::tiles::TilePos;
+use crate::core::rubiks::RubiksState;
+use crate::core::{Colour, COLOURS};
+use crate::core::cube::geometry::FACES;
+use crate::Face;
+
+use super::{fmt_num, rgb, tile_corners, BODY};
+
+#[cfg(test)]
+mod tests;
+
+/// Decimals written for coordinates and colour channels.
+const DECIMALS: usize = 6;
+
+/// Settings for building a 3D mesh of a cube.
+///
+/// All lengths are measured in cubies.
+///
+/// # Fields
+///
+/// - **bevel**: Width of the chamfer cut from every edge of a cubie, from `0.0` (sharp
+///   boxes) to `0.5`
+/// - **sticker_gap**: Margin between the edge of a cubie face and its sticker, from
+///   `0.0` (stickers cover the whole face) to just below `0.5`
+/// - **sticker_lift**: Distance stickers float above the cubie surface, to avoid
+///   z-fighting with the body
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct MeshExport {
+    /// Width of the chamfer on cubie edges
+    pub bevel: f64,
+    /// Margin between a cubie face and its sticker
+    pub sticker_gap: f64,
+    /// Height of stickers above the cubie surface
+    pub sticker_lift: f64,
+}
+
+impl Default for MeshExport {
+    /// Lightly bevelled cubies with thin sticker borders.
+    fn default() -> Self {
+        Self { bevel: 0.08, sticker_gap: 0.06, sticker_lift: 0.002 }
+    }
+}
+
+/// Material of a mesh part.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum MeshMaterial {
+    /// The dark plastic of the cubies
+    Body,
+    /// A sticker of the given colour
+    Sticker(Colour),
+}
+
+impl MeshMaterial {
+    /// Name used for the material in MTL and glTF output.
+    pub fn name(&self) -> String {
+        match self {
+            MeshMaterial::Body => "body".to_string(),
+            MeshMaterial::Sticker(colour) => format!("{colour:?}"),
+        }
+    }
+
+    /// Display colour as an sRGB triple.
+    pub fn rgb(&self) -> [u8; 3] {
+        match self {
+            MeshMaterial::Body => BODY,
+            MeshMaterial::Sticker(colour) => rgb(*colour),
+        }
+    }
+}
+
+/// What a mesh part represents.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum PartKind {
+    /// The body of the cubie at integer coordinates `[x, y, z]`, each from `0` to `N-1`
+    Cubie([usize; 3]),
+    /// The sticker at the given tile position
+    Sticker(TilePos),
+}
+
+/// A single named object of a [`CubeMesh`].
+///
+/// Polygons are convex, wound anticlockwise when seen from outside, and index into
+/// this part's own `positions`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MeshPart {
+    /// What this part represents
+    pub kind: PartKind,
+    /// Material the part is drawn with
+    pub material: MeshMaterial,
+    /// Vertex positions
+    pub positions: Vec<[f64; 3]>,
+    /// Faces as lists of indices into `positions`
+    pub polygons: Vec<Vec<u32>>,
+}
+
+impl MeshPart {
+    /// Object name, `cubie_<x>_<y>_<z>` or `sticker_<Face>_<row>_<col>`.
+    pub fn name(&self) -> String {
+        match self.kind {
+            PartKind::Cubie([x, y, z]) => format!("cubie_{x}_{y}_{z}"),
+            PartKind::Sticker(TilePos { face, row, col }) => format!("sticker_{face:?}_{row}_{col}"),
+        }
+    }
+
+    /// Faces split into triangles by fanning from their first vertex.
+    pub fn triangles(&self) -> Vec<[u32; 3]> {
+        self.polygons
+            .iter()
+            .flat_map(|polygon| (1..polygon.len().saturating_sub(1)).map(move |i| [polygon[0], polygon[i], polygon[i + 1]]))
+            .collect()
+    }
+}
+
+/// A cube state as a collection of 3D parts, ready to be written to a file.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CubeMesh {
+    /// Cubie bodies first, then stickers
+    pub parts: Vec<MeshPart>,
+}
+
+impl MeshExport {
+    /// Builds the mesh of a cube state.
+    ///
+    /// Only cubies on the surface are included, since interior ones can never be seen.
+    /// Each sticker takes its colour from the state at its [`TilePos`].
+    pub fn mesh<const N: usize>(&self, state: &RubiksState<N>) -> CubeMesh {
+        let half = N as f64 / 2.0;
+        let mut parts = Vec::new();
+
+        for x in 0..N {
+            for y in 0..N {
+                for z in 0..N {
+                    let on_surface = [x, y, z].iter().any(|&c| c == 0 || c == N - 1);
+                    if on_surface {
+                        let centre = [x as f64 + 0.5 - half, y as f64 + 0.5 - half, z as f64 + 0.5 - half];
+                        parts.push(self.cubie(centre, [x, y, z]));
+                    }
+                }
+            }
+        }
+
+        for face in FACES {
+            let normal = face_normal(face);
+            for row in 0..N {
+                for col in 0..N {
+                    let tile = TilePos { face, row, col };
+                    let positions = tile_corners::<N>(tile, self.sticker_gap)
+                        .iter()
+                        .map(|p| std::array::from_fn(|i| p[i] - half + normal[i] * self.sticker_lift))
+                        .collect::<Vec<[f64; 3]>>();
+                    let mut polygon = vec![0, 1, 2, 3];
+                    orient(&positions, &mut polygon, normal);
+                    parts.push(MeshPart {
+                        kind: PartKind::Sticker(tile),
+                        material: MeshMaterial::Sticker(state[tile]),
+                        positions,
+                        polygons: vec![polygon],
+                    });
+                }
+            }
+        }
+
+        CubeMesh { parts }
+    }
+
+    /// Builds a unit chamfered box around `centre`.
+    ///
+    /// The box has three vertices near each corner, one on each of the faces meeting
+    /// there, giving 6 face quads, 12 edge quads and 8 corner triangles.
+    fn cubie(&self, centre: [f64; 3], coords: [usize; 3]) -> MeshPart {
+        let bevel = self.bevel.clamp(0.0, 0.5);
+        let signs = [-1.0, 1.0];
+        let index = |s: [usize; 3], axis: usize| (((s[0] * 2 + s[1]) * 2 + s[2]) * 3 + axis) as u32;
+
+        let mut positions = Vec::with_capacity(24);
+        for sx in 0..2 {
+            for sy in 0..2 {
+                for sz in 0..2 {
+                    let s = [sx, sy, sz];
+                    for axis in 0..3 {
+                        positions.push(std::array::from_fn(|i| {
+                            let extent = if i == axis { 0.5 } else { 0.5 - bevel };
+                            centre[i] + signs[s[i]] * extent
+                        }));
+                    }
+                }
+            }
+        }
+
+        let mut polygons = Vec::with_capacity(26);
+        for axis in 0..3 {
+            let (a, b) = ((axis + 1) % 3, (axis + 2) % 3);
+            for side in 0..2 {
+                let corner = |ua: usize, ub: usize| {
+                    let mut s = [0; 3];
+                    s[axis] = side;
+                    s[a] = ua;
+                    s[b] = ub;
+                    s
+                };
+                polygons.push(vec![
+                    index(corner(0, 0), axis),
+                    index(corner(1, 0), axis),
+                    index(corner(1, 1), axis),
+                    index(corner(0, 1), axis),
+                ]);
+            }
+            // Edges running parallel to `axis`, between the faces normal to `a` and `b`
+            for sa in 0..2 {
+                for sb in 0..2 {
+                    let end = |t: usize| {
+                        let mut s = [0; 3];
+                        s[axis] = t;
+                        s[a] = sa;
+                        s[b] = sb;
+                        s
+                    };
+                    polygons.push(vec![index(end(0), a), index(end(1), a), index(end(1), b), index(end(0), b)]);
+                }
+            }
+        }
+        for corner in 0..8 {
+            let s = [corner >> 2 & 1, corner >> 1 & 1, corner & 1];
+            polygons.push(vec![index(s, 0), index(s, 1), index(s, 2)]);
+        }
+
+        for polygon in &mut polygons {
+            let centroid = polygon_centroid(&positions, polygon);
+            let outward = std::array::from_fn(|i| centroid[i] - centre[i]);
+            orient(&positions, polygon, outward);
+        }
+
+        MeshPart { kind: PartKind::Cubie(coords), material: MeshMaterial::Body, positions, polygons }
+    }
+}
+
+impl CubeMesh {
+    /// Materials used by the mesh: the body followed by the six sticker colours.
+    pub fn materials() -> [MeshMaterial; 7] {
+        let mut materials = [MeshMaterial::Body; 7];
+        for (slot, colour) in materials[1..].iter_mut().zip(COLOURS) {
+            *slot = MeshMaterial::Sticker(colour);
+        }
+        materials
+    }
+
+    /// Writes the mesh as a Wavefront OBJ file.
+    ///
+    /// `mtl_file` is the name of the material library referenced by the `mtllib`
+    /// statement; save the output of [`CubeMesh::to_mtl`] under that name next to
+    /// the OBJ file.
+    pub fn to_obj(&self, mtl_file: &str) -> String {
+        let mut out = String::new();
+        let _ = writeln!(out, "# Cube mesh exported by rubiks-cube-representation");
+        let _ = writeln!(out, "mtllib {mtl_file}");
+        let mut offset = 1;
+        for part in &self.parts {
+            let _ = writeln!(out, "o {}", part.name());
+            for [x, y, z] in &part.positions {
+                let _ = writeln!(out, "v {} {} {}", fmt_num(*x, DECIMALS), fmt_num(*y, DECIMALS), fmt_num(*z, DECIMALS));
+            }
+            let _ = writeln!(out, "usemtl {}", part.material.name());
+            for polygon in &part.polygons {
+                let indices = polygon.iter().map(|i| (i + offset).to_string()).collect::<Vec<_>>().join(" ");
+                let _ = writeln!(out, "f {indices}");
+            }
+            offset += part.positions.len() as u32;
+        }
+        out
+    }
+
+    /// Writes the material library for [`CubeMesh::to_obj`].
+    pub fn to_mtl(&self) -> String {
+        let mut out = String::new();
+        for material in Self::materials() {
+            let [r, g, b] = material.rgb().map(|c| fmt_num(c as f64 / 255.0, DECIMALS));
+            let _ = writeln!(out, "newmtl {}", material.name());
+            let _ = writeln!(out, "Kd {r} {g} {b}");
+            let _ = writeln!(out, "Ks 0.1 0.1 0.1");
+            let _ = writeln!(out, "Ns 32");
+            let _ = writeln!(out, "illum 2");
+            out.push('\n');
+        }
+        out
+    }
+
+    /// Writes the mesh as a glTF 2.0 JSON document.
+    ///
+    /// The geometry is embedded as a base64 `data:` URI, so the result is a single
+    /// self-contained `.gltf` file. Each part becomes a node with its own mesh; sticker
+    /// nodes carry their tile position in `extras`. Base colours are converted from the
+    /// sRGB palette to the linear values glTF expects.
+    pub fn to_gltf(&self) -> String {
+        let materials = Self::materials();
+        let vertex_count: usize = self.parts.iter().map(|p| p.positions.len()).sum();
+        let positions_len = vertex_count * 12;
+
+        let mut buffer = Vec::with_capacity(positions_len);
+        for part in &self.parts {
+            for position in &part.positions {
+                for coord in position {
+                    buffer.extend_from_slice(&(*coord as f32).to_le_bytes());
+                }
+            }
+        }
+        for part in &self.parts {
+            for triangle in part.triangles() {
+                for index in triangle {
+                    buffer.extend_from_slice(&index.to_le_bytes());
+                }
+            }
+        }
+
+        let mut nodes = Vec::new();
+        let mut meshes = Vec::new();
+        let mut accessors = Vec::new();
+        let (mut vertex_offset, mut index_offset) = (0, 0);
+        for (i, part) in self.parts.iter().enumerate() {
+            let (min, max) = bounds(&part.positions);
+            accessors.push(format!(
+                r#"{{"bufferView":0,"byteOffset":{},"componentType":5126,"count":{},"type":"VEC3","min":[{}],"max":[{}]}}"#,
+                vertex_offset * 12,
+                part.positions.len(),
+                min.map(|c| fmt_num(c as f32 as f64, DECIMALS)).join(","),
+                max.map(|c| fmt_num(c as f32 as f64, DECIMALS)).join(","),
+            ));
+            let index_count = part.triangles().len() * 3;
+            accessors.push(format!(
+                r#"{{"bufferView":1,"byteOffset":{},"componentType":5125,"count":{},"type":"SCALAR"}}"#,
+                index_offset * 4,
+                index_count
+            ));
+            let material = materials.iter().position(|m| *m == part.material).unwrap_or(0);
+            meshes.push(format!(
+                r#"{{"name":"{}","primitives":[{{"attributes":{{"POSITION":{}}},"indices":{},"material":{}}}]}}"#,
+                part.name(),
+                2 * i,
+                2 * i + 1,
+                material
+            ));
+            let extras = match part.kind {
+                PartKind::Sticker(TilePos { face, row, col }) => {
+                    format!(r#","extras":{{"face":"{face:?}","row":{row},"col":{col}}}"#)
+                }
+                PartKind::Cubie([x, y, z]) => format!(r#","extras":{{"cubie":[{x},{y},{z}]}}"#),
+            };
+            nodes.push(format!(r#"{{"name":"{}","mesh":{}{}}}"#, part.name(), i, extras));
+            vertex_offset += part.positions.len();
+            index_offset += index_count;
+        }
+
+        let material_json = materials
+            .iter()
+            .map(|material| {
+                let [r, g, b] = material.rgb().map(|c| fmt_num(srgb_to_linear(c), DECIMALS));
+                format!(
+                    r#"{{"name":"{}","pbrMetallicRoughness":{{"baseColorFactor":[{r},{g},{b},1],"metallicFactor":0,"roughnessFactor":0.5}}}}"#,
+                    material.name()
+                )
+            })
+            .collect::<Vec<_>>();
+        let node_indices = (0..nodes.len()).map(|i| i.to_string()).collect::<Vec<_>>();
+
+        let mut out = String::new();
+        out.push('{');
+        out.push_str(r#""asset":{"version":"2.0","generator":"rubiks-cube-representation"},"#);
+        let _ = write!(out, r#""scene":0,"scenes":[{{"nodes":[{}]}}],"#, node_indices.join(","));
+        let _ = write!(out, r#""nodes":[{}],"#, nodes.join(","));
+        let _ = write!(out, r#""meshes":[{}],"#, meshes.join(","));
+        let _ = write!(out, r#""materials":[{}],"#, material_json.join(","));
+        let _ = write!(out, r#""accessors":[{}],"#, accessors.join(","));
+        let _ = write!(
+            out,
+            r#""bufferViews":[{{"buffer":0,"byteOffset":0,"byteLength":{},"target":34962}},{{"buffer":0,"byteOffset":{},"byteLength":{},"target":34963}}],"#,
+            positions_len,
+            positions_len,
+            buffer.len() - positions_len
+        );
+        let _ = write!(
+            out,
+            r#""buffers":[{{"byteLength":{},"uri":"data:application/octet-stream;base64,{}"}}]"#,
+            buffer.len(),
+            base64(&buffer)
+        );
+        out.push_str("}\n");
+        out
+    }
+}
+
+/// Unit vector pointing out of a face.
+fn face_normal(face: Face) -> [f64; 3] {
+    face.normal().map(f64::from)
+}
+
+fn polygon_centroid(positions: &[[f64; 3]], polygon: &[u32]) -> [f64; 3] {
+    let n = polygon.len() as f64;
+    polygon.iter().fold([0.0; 3], |acc, &i| std::array::from_fn(|k| acc[k] + positions[i as usize][k] / n))
+}
+
+/// Reverses `polygon` if its winding does not face along `outward`.
+fn orient(positions: &[[f64; 3]], polygon: &mut [u32], outward: [f64; 3]) {
+    // Newell's method, which stays correct when some edges have zero length
+    let mut normal = [0.0; 3];
+    for (i, &a) in polygon.iter().enumerate() {
+        let p = positions[a as usize];
+        let q = positions[polygon[(i + 1) % polygon.len()] as usize];
+        normal[0] += (p[1] - q[1]) * (p[2] + q[2]);
+        normal[1] += (p[2] - q[2]) * (p[0] + q[0]);
+        normal[2] += (p[0] - q[0]) * (p[1] + q[1]);
+    }
+    let dot: f64 = (0..3).map(|i| normal[i] * outward[i]).sum();
+    if dot < 0.0 {
+        polygon.reverse();
+    }
+}
+
+fn bounds(positions: &[[f64; 3]]) -> ([f64; 3], [f64; 3]) {
+    let mut min = [f64::INFINITY; 3];
+    let mut max = [f64::NEG_INFINITY; 3];
+    for p in positions {
+        for i in 0..3 {
+            min[i] = min[i].min(p[i]);
+            max[i] = max[i].max(p[i]);
+        }
+    }
+    (min, max)
+}
+
+/// Converts an 8-bit sRGB channel to a linear value in `[0, 1]`.
+fn srgb_to_linear(channel: u8) -> f64 {
+    let c = channel as f64 / 255.0;
+    if c <= 0.04045 { c / 12.92 } else { ((c + 0.055) / 1.055).powf(2.4) }
+}
+
+/// Standard base64 encoding with padding.
+fn base64(data: &[u8]) -> String {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+    let mut out = String::with_capacity(data.len().div_ceil(3) * 4);
+    for chunk in data.chunks(3) {
+        let bytes = [chunk[0], *chunk.get(1).unwrap_or(&0), *chunk.get(2).unwrap_or(&0)];
+        let n = (bytes[0] as u32) << 16 | (bytes[1] as u32) << 8 | bytes[2] as u32;
+        for i in 0..4 {
+            if i <= chunk.len() {
+                out.push(ALPHABET[(n >> (18 - 6 * i) & 63) as usize] as char);
+            } else {
+                out.push('=');
+            }
+        }
+    }
+    out
+}
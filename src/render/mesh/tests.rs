@@ -0,0 +1,165 @@
+use super::*;
+use crate::core::cube::schemes::{ColourScheme, Western};
+use crate::core::rubiks::moves::BasicMove;
+
+fn sticker(mesh: &CubeMesh, tile: TilePos) -> &MeshPart {
+    mesh.parts.iter().find(|p| p.kind == PartKind::Sticker(tile)).expect("sticker should exist")
+}
+
+fn decode_base64(text: &str) -> Vec<u8> {
+    let value = |c: u8| match c {
+        b'A'..=b'Z' => c - b'A',
+        b'a'..=b'z' => c - b'a' + 26,
+        b'0'..=b'9' => c - b'0' + 52,
+        b'+' => 62,
+        _ => 63,
+    };
+    let mut out = Vec::new();
+    for chunk in text.as_bytes().chunks(4) {
+        let digits = chunk.iter().take_while(|&&c| c != b'=').map(|&c| value(c) as u32).collect::<Vec<_>>();
+        let n = digits.iter().enumerate().fold(0, |acc, (i, d)| acc | d << (18 - 6 * i));
+        for i in 0..digits.len() - 1 {
+            out.push((n >> (16 - 8 * i)) as u8);
+        }
+    }
+    out
+}
+
+#[test]
+fn test_part_counts() {
+    let mesh = MeshExport::default().mesh(&RubiksState::<3>::solved_in(Western));
+    let cubies = mesh.parts.iter().filter(|p| matches!(p.kind, PartKind::Cubie(_))).count();
+    let stickers = mesh.parts.iter().filter(|p| matches!(p.kind, PartKind::Sticker(_))).count();
+    assert_eq!(cubies, 26);
+    assert_eq!(stickers, 54);
+
+    let mesh = MeshExport::default().mesh(&RubiksState::<1>::solved_in(Western));
+    assert_eq!(mesh.parts.len(), 1 + 6);
+    let mesh = MeshExport::default().mesh(&RubiksState::<4>::solved_in(Western));
+    assert_eq!(mesh.parts.len(), (64 - 8) + 96);
+}
+
+#[test]
+fn test_cubie_is_closed_chamfered_box() {
+    let mesh = MeshExport::default().mesh(&RubiksState::<2>::solved_in(Western));
+    for part in mesh.parts.iter().filter(|p| matches!(p.kind, PartKind::Cubie(_))) {
+        assert_eq!(part.positions.len(), 24);
+        assert_eq!(part.polygons.len(), 26);
+        // A closed surface uses every directed edge exactly once
+        let mut edges = std::collections::HashMap::new();
+        for polygon in &part.polygons {
+            for (i, &a) in polygon.iter().enumerate() {
+                *edges.entry((a, polygon[(i + 1) % polygon.len()])).or_insert(0) += 1;
+            }
+        }
+        for (&(a, b), &count) in &edges {
+            assert_eq!(count, 1, "edge {a}->{b} used {count} times in {}", part.name());
+            assert_eq!(edges.get(&(b, a)), Some(&1), "edge {a}->{b} has no twin in {}", part.name());
+        }
+    }
+}
+
+#[test]
+fn test_sticker_placement() {
+    let export = MeshExport { sticker_gap: 0.0, sticker_lift: 0.0, ..MeshExport::default() };
+    let mesh = export.mesh(&RubiksState::<3>::solved_in(Western));
+
+    // Up row 0 col 0 sits at the ULB corner: left (-x), back (-z) and on top (+y)
+    let part = sticker(&mesh, TilePos { face: Face::Up, row: 0, col: 0 });
+    let centre = polygon_centroid(&part.positions, &part.polygons[0]);
+    assert_eq!(centre, [-1.0, 1.5, -1.0]);
+
+    // Front row 2 col 2 sits at the DFR corner
+    let part = sticker(&mesh, TilePos { face: Face::Front, row: 2, col: 2 });
+    let centre = polygon_centroid(&part.positions, &part.polygons[0]);
+    assert_eq!(centre, [1.0, -1.0, 1.5]);
+}
+
+#[test]
+fn test_stickers_face_outwards() {
+    let mesh = MeshExport::default().mesh(&RubiksState::<3>::solved_in(Western));
+    for face in FACES {
+        let part = sticker(&mesh, TilePos { face, row: 1, col: 2 });
+        let mut reversed = part.polygons[0].clone();
+        orient(&part.positions, &mut reversed, face_normal(face));
+        assert_eq!(reversed, part.polygons[0], "{face:?} sticker is wound inwards");
+        assert!(part.positions.iter().all(|p| p.iter().any(|c| c.abs() > 1.5)));
+    }
+}
+
+#[test]
+fn test_sticker_colours_follow_state() {
+    let cube = RubiksState::<3>::solved_in(Western) * &BasicMove::<3>::R;
+    let mesh = MeshExport::default().mesh(&cube);
+    for face in FACES {
+        for row in 0..3 {
+            for col in 0..3 {
+                let tile = TilePos { face, row, col };
+                assert_eq!(sticker(&mesh, tile).material, MeshMaterial::Sticker(cube[tile]));
+            }
+        }
+    }
+    let solved = MeshExport::default().mesh(&RubiksState::<3>::solved_in(Western));
+    let tile = TilePos { face: Face::Up, row: 0, col: 2 };
+    assert_eq!(sticker(&solved, tile).material, MeshMaterial::Sticker(Western.up()));
+}
+
+#[test]
+fn test_obj_and_mtl() {
+    let mesh = MeshExport::default().mesh(&RubiksState::<2>::solved_in(Western));
+    let obj = mesh.to_obj("cube.mtl");
+    assert!(obj.contains("mtllib cube.mtl\n"));
+    assert_eq!(obj.matches("\no ").count(), 8 + 24);
+    assert_eq!(obj.lines().filter(|l| l.starts_with("v ")).count(), 8 * 24 + 24 * 4);
+    assert!(obj.contains("o sticker_Front_1_0\n"));
+    assert!(obj.contains("o cubie_1_0_1\n"));
+
+    // Face indices are 1-based and never exceed the vertex count
+    let vertices = obj.lines().filter(|l| l.starts_with("v ")).count();
+    for line in obj.lines().filter(|l| l.starts_with("f ")) {
+        for index in line[2..].split(' ') {
+            let index: usize = index.parse().unwrap();
+            assert!(index >= 1 && index <= vertices);
+        }
+    }
+
+    let mtl = mesh.to_mtl();
+    for material in CubeMesh::materials() {
+        assert!(mtl.contains(&format!("newmtl {}\n", material.name())));
+        assert!(obj.contains(&format!("usemtl {}\n", material.name())));
+    }
+    assert!(mtl.contains("newmtl White\nKd 1 1 1\n"));
+}
+
+#[test]
+fn test_gltf_buffer_layout() {
+    let mesh = MeshExport::default().mesh(&RubiksState::<2>::solved_in(Western));
+    let gltf = mesh.to_gltf();
+    assert!(gltf.starts_with(r#"{"asset":{"version":"2.0""#));
+    assert!(gltf.contains(r#"{"name":"sticker_Up_0_1","mesh":"#));
+    assert!(gltf.contains(r#""extras":{"face":"Up","row":0,"col":1}"#));
+
+    let prefix = "data:application/octet-stream;base64,";
+    let start = gltf.find(prefix).unwrap() + prefix.len();
+    let end = start + gltf[start..].find('"').unwrap();
+    let bytes = decode_base64(&gltf[start..end]);
+    assert!(gltf.contains(&format!(r#""buffers":[{{"byteLength":{},"#, bytes.len())));
+
+    let vertices: usize = mesh.parts.iter().map(|p| p.positions.len()).sum();
+    let indices: usize = mesh.parts.iter().map(|p| p.triangles().len() * 3).sum();
+    assert_eq!(bytes.len(), vertices * 12 + indices * 4);
+
+    // First vertex of the first cubie
+    let x = f32::from_le_bytes(bytes[0..4].try_into().unwrap());
+    assert_eq!(x as f64, mesh.parts[0].positions[0][0] as f32 as f64);
+}
+
+#[test]
+fn test_base64() {
+    assert_eq!(base64(b""), "");
+    assert_eq!(base64(b"f"), "Zg==");
+    assert_eq!(base64(b"fo"), "Zm8=");
+    assert_eq!(base64(b"foo"), "Zm9v");
+    assert_eq!(base64(b"foobar"), "Zm9vYmFy");
+    assert_eq!(decode_base64(&base64(b"cube!")), b"cube!");
+}
@@ -0,0 +1,284 @@
+//! Image output for cube states.
+//!
+//! This module turns [`RubiksState<N>`](crate::core::rubiks::RubiksState) values into
+//! pictures. It is deliberately dependency-free: vector output is written as plain SVG
+//! text and raster output is held in a simple [`RgbImage`] buffer that can be encoded
+//! as binary PPM or (uncompressed) PNG without any image library.
+//!
+//! # Module Organization
+//!
+//! - [`isometric`]: Three-face isometric view of a cube as seen by a competitor
+//! - [`mesh`]: 3D geometry export as OBJ/MTL or glTF
+//! - [`RgbImage`]: Minimal RGB raster with PPM and PNG encoders
+//! - [`rgb`]: The display palette mapping each [`Colour`] to an sRGB triple
+//!
+//! # Coordinate Conventions
+//!
+//! Renderers place the cube in a right-handed 3D frame with `x` pointing right, `y`
+//! pointing up and `z` pointing towards the viewer, so the Up, Front and Right faces
+//! are the ones facing the camera. Screen coordinates follow image conventions with
+//! the origin in the top-left corner and `y` increasing downwards.
+
+use crate::core::rubiks::tiles::TilePos;
+use crate::core::Colour;
+use crate::Face;
+
+pub mod isometric;
+pub mod mesh;
+
+#[cfg(test)]
+mod tests;
+
+/// Returns the display colour used for a sticker as an `[r, g, b]` triple.
+///
+/// The palette follows the usual shades of a stickered speed cube rather than the
+/// pure primaries, so that white and yellow or red and orange remain distinguishable
+/// in small renders.
+///
+/// # Examples
+///
+/// ```
+/// use rubiks_cube_representation::core::Colour;
+/// use rubiks_cube_representation::render::rgb;
+///
+/// assert_eq!(rgb(Colour::White), [255, 255, 255]);
+/// ```
+pub fn rgb(colour: Colour) -> [u8; 3] {
+    match colour {
+        Colour::White => [255, 255, 255],
+        Colour::Yellow => [255, 213, 0],
+        Colour::Red => [196, 30, 58],
+        Colour::Orange => [255, 88, 0],
+        Colour::Blue => [0, 81, 186],
+        Colour::Green => [0, 158, 96],
+    }
+}
+
+/// Colour of the cube body, shown in the gaps between stickers, as an sRGB triple.
+pub(crate) const BODY: [u8; 3] = [24, 24, 24];
+
+/// Formats an `[r, g, b]` triple as an SVG/CSS hex colour (`#rrggbb`).
+pub(crate) fn hex(rgb: [u8; 3]) -> String {
+    format!("#{:02x}{:02x}{:02x}", rgb[0], rgb[1], rgb[2])
+}
+
+/// Formats a coordinate with at most `decimals` decimals and no trailing zeros.
+pub(crate) fn fmt_num(value: f64, decimals: usize) -> String {
+    let text = format!("{value:.decimals$}");
+    let text = text.trim_end_matches('0').trim_end_matches('.');
+    if text == "-0" { "0".to_string() } else { text.to_string() }
+}
+
+/// Maps in-face coordinates `(u, v)`, measured in tiles from the face's principal
+/// corner along its rows and columns respectively, to a point of the `[0, N]³` frame.
+pub(crate) fn face_point<const N: usize>(face: Face, u: f64, v: f64) -> [f64; 3] {
+    face.point::<N>(u, v).map(|c| c + N as f64 / 2.0)
+}
+
+/// Corners of a whole face, in drawing order.
+pub(crate) fn face_corners<const N: usize>(face: Face) -> [[f64; 3]; 4] {
+    let n = N as f64;
+    [
+        face_point::<N>(face, 0.0, 0.0),
+        face_point::<N>(face, 0.0, n),
+        face_point::<N>(face, n, n),
+        face_point::<N>(face, n, 0.0),
+    ]
+}
+
+/// Corners of a tile, shrunk towards its centre by `gap` tiles on every side.
+pub(crate) fn tile_corners<const N: usize>(tile: TilePos, gap: f64) -> [[f64; 3]; 4] {
+    let TilePos { face, row, col } = tile;
+    let (top, left) = (row as f64 + gap, col as f64 + gap);
+    let (bottom, right) = (row as f64 + 1.0 - gap, col as f64 + 1.0 - gap);
+    [
+        face_point::<N>(face, top, left),
+        face_point::<N>(face, top, right),
+        face_point::<N>(face, bottom, right),
+        face_point::<N>(face, bottom, left),
+    ]
+}
+
+/// A simple 8-bit RGB raster image.
+///
+/// Pixels are stored row-major starting from the top-left corner, so the pixel at
+/// `(x, y)` lives at `pixels[y * width + x]`.
+///
+/// # Encoding
+///
+/// - [`RgbImage::to_ppm`]: Binary PPM (`P6`), readable by most image tools
+/// - [`RgbImage::to_png`]: PNG using stored (uncompressed) deflate blocks
+///
+/// Neither encoder compresses the data; the goal is portable output without any
+/// external dependencies, not small files.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RgbImage {
+    /// Width of the image in pixels
+    pub width: usize,
+    /// Height of the image in pixels
+    pub height: usize,
+    /// Row-major pixel data, `width * height` entries long
+    pub pixels: Vec<[u8; 3]>,
+}
+
+impl RgbImage {
+    /// Creates an image of the given size filled with a single colour.
+    pub fn filled(width: usize, height: usize, background: [u8; 3]) -> Self {
+        Self { width, height, pixels: vec![background; width * height] }
+    }
+
+    /// Returns the colour of the pixel at column `x`, row `y`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `x >= width` or `y >= height`.
+    pub fn pixel(&self, x: usize, y: usize) -> [u8; 3] {
+        assert!(x < self.width && y < self.height, "pixel ({x}, {y}) outside {}x{} image", self.width, self.height);
+        self.pixels[y * self.width + x]
+    }
+
+    /// Fills a convex polygon given in pixel coordinates.
+    ///
+    /// A pixel is painted when its centre lies inside the polygon (or on its boundary).
+    /// The vertices may be given in either winding order.
+    pub(crate) fn fill_convex(&mut self, polygon: &[[f64; 2]], colour: [u8; 3]) {
+        if polygon.len() < 3 || self.width == 0 || self.height == 0 {
+            return;
+        }
+        let min_x = polygon.iter().map(|p| p[0]).fold(f64::INFINITY, f64::min).floor().max(0.0) as usize;
+        let max_x = polygon.iter().map(|p| p[0]).fold(f64::NEG_INFINITY, f64::max).ceil().min(self.width as f64) as usize;
+        let min_y = polygon.iter().map(|p| p[1]).fold(f64::INFINITY, f64::min).floor().max(0.0) as usize;
+        let max_y = polygon.iter().map(|p| p[1]).fold(f64::NEG_INFINITY, f64::max).ceil().min(self.height as f64) as usize;
+        for y in min_y..max_y {
+            for x in min_x..max_x {
+                let centre = [x as f64 + 0.5, y as f64 + 0.5];
+                if inside_convex(polygon, centre) {
+                    self.pixels[y * self.width + x] = colour;
+                }
+            }
+        }
+    }
+
+    /// Encodes the image as a binary PPM (`P6`) file.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rubiks_cube_representation::render::RgbImage;
+    ///
+    /// let image = RgbImage::filled(2, 1, [255, 0, 0]);
+    /// let ppm = image.to_ppm();
+    /// assert!(ppm.starts_with(b"P6\n2 1\n255\n"));
+    /// assert_eq!(ppm.len(), b"P6\n2 1\n255\n".len() + 6);
+    /// ```
+    pub fn to_ppm(&self) -> Vec<u8> {
+        let mut out = format!("P6\n{} {}\n255\n", self.width, self.height).into_bytes();
+        out.reserve(self.pixels.len() * 3);
+        for pixel in &self.pixels {
+            out.extend_from_slice(pixel);
+        }
+        out
+    }
+
+    /// Encodes the image as a PNG file.
+    ///
+    /// The image data is wrapped in stored (uncompressed) deflate blocks, which every
+    /// PNG decoder must accept. Files are therefore roughly `3 * width * height` bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rubiks_cube_representation::render::RgbImage;
+    ///
+    /// let png = RgbImage::filled(4, 4, [0, 0, 0]).to_png();
+    /// assert_eq!(&png[..8], b"\x89PNG\r\n\x1a\n");
+    /// ```
+    pub fn to_png(&self) -> Vec<u8> {
+        let mut raw = Vec::with_capacity(self.height * (self.width * 3 + 1));
+        for row in self.pixels.chunks(self.width.max(1)).take(self.height) {
+            raw.push(0); // filter type: none
+            for pixel in row {
+                raw.extend_from_slice(pixel);
+            }
+        }
+
+        let mut ihdr = Vec::with_capacity(13);
+        ihdr.extend_from_slice(&(self.width as u32).to_be_bytes());
+        ihdr.extend_from_slice(&(self.height as u32).to_be_bytes());
+        ihdr.extend_from_slice(&[8, 2, 0, 0, 0]); // 8-bit depth, RGB, deflate, no filter, no interlace
+
+        let mut out = b"\x89PNG\r\n\x1a\n".to_vec();
+        png_chunk(&mut out, b"IHDR", &ihdr);
+        png_chunk(&mut out, b"IDAT", &zlib_stored(&raw));
+        png_chunk(&mut out, b"IEND", &[]);
+        out
+    }
+}
+
+/// Returns whether `point` lies inside (or on) the convex polygon.
+fn inside_convex(polygon: &[[f64; 2]], point: [f64; 2]) -> bool {
+    let mut sign = 0.0;
+    for (i, a) in polygon.iter().enumerate() {
+        let b = polygon[(i + 1) % polygon.len()];
+        let cross = (b[0] - a[0]) * (point[1] - a[1]) - (b[1] - a[1]) * (point[0] - a[0]);
+        if cross != 0.0 {
+            if sign == 0.0 {
+                sign = cross;
+            } else if sign * cross < 0.0 {
+                return false;
+            }
+        }
+    }
+    true
+}
+
+/// Appends a PNG chunk (length, type, data, CRC) to `out`.
+fn png_chunk(out: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    out.extend_from_slice(&(data.len() as u32).to_be_bytes());
+    let start = out.len();
+    out.extend_from_slice(kind);
+    out.extend_from_slice(data);
+    let crc = crc32(&out[start..]);
+    out.extend_from_slice(&crc.to_be_bytes());
+}
+
+/// Wraps `data` in a zlib stream made of stored deflate blocks.
+fn zlib_stored(data: &[u8]) -> Vec<u8> {
+    const MAX_BLOCK: usize = 65_535;
+    let mut out = vec![0x78, 0x01];
+    let mut blocks = data.chunks(MAX_BLOCK).peekable();
+    if blocks.peek().is_none() {
+        out.extend_from_slice(&[1, 0, 0, 0xff, 0xff]);
+    }
+    while let Some(block) = blocks.next() {
+        let last = blocks.peek().is_none();
+        out.push(u8::from(last));
+        let len = block.len() as u16;
+        out.extend_from_slice(&len.to_le_bytes());
+        out.extend_from_slice(&(!len).to_le_bytes());
+        out.extend_from_slice(block);
+    }
+    out.extend_from_slice(&adler32(data).to_be_bytes());
+    out
+}
+
+fn crc32(data: &[u8]) -> u32 {
+    let mut crc = 0xffff_ffffu32;
+    for &byte in data {
+        crc ^= byte as u32;
+        for _ in 0..8 {
+            let mask = (crc & 1).wrapping_neg();
+            crc = (crc >> 1) ^ (0xedb8_8320 & mask);
+        }
+    }
+    !crc
+}
+
+fn adler32(data: &[u8]) -> u32 {
+    let (mut a, mut b) = (1u32, 0u32);
+    for &byte in data {
+        a = (a + byte as u32) % 65_521;
+        b = (b + a) % 65_521;
+    }
+    (b << 16) | a
+}
@@ -0,0 +1,63 @@
+use super::*;
+
+#[test]
+fn test_ppm_layout() {
+    let mut image = RgbImage::filled(2, 2, [0, 0, 0]);
+    image.pixels[3] = [1, 2, 3];
+    let ppm = image.to_ppm();
+    let header = b"P6\n2 2\n255\n";
+    assert_eq!(&ppm[..header.len()], header);
+    assert_eq!(&ppm[ppm.len() - 3..], &[1, 2, 3]);
+}
+
+#[test]
+fn test_png_structure() {
+    let image = RgbImage::filled(3, 2, [10, 20, 30]);
+    let png = image.to_png();
+    assert_eq!(&png[..8], b"\x89PNG\r\n\x1a\n");
+
+    // IHDR is the first chunk and records the dimensions
+    assert_eq!(&png[12..16], b"IHDR");
+    assert_eq!(u32::from_be_bytes(png[16..20].try_into().unwrap()), 3);
+    assert_eq!(u32::from_be_bytes(png[20..24].try_into().unwrap()), 2);
+
+    // The file ends with an empty IEND chunk
+    assert_eq!(&png[png.len() - 12..], b"\x00\x00\x00\x00IEND\xae\x42\x60\x82");
+}
+
+#[test]
+fn test_png_spans_multiple_deflate_blocks() {
+    // 200×200 RGB rows exceed the 65535 byte limit of a single stored block
+    let image = RgbImage::filled(200, 200, [255, 255, 255]);
+    let png = image.to_png();
+    let idat_len = u32::from_be_bytes(png[33..37].try_into().unwrap()) as usize;
+    assert_eq!(&png[37..41], b"IDAT");
+    let raw_len: usize = 200 * (200 * 3 + 1);
+    let blocks = raw_len.div_ceil(65_535);
+    assert_eq!(idat_len, 2 + raw_len + 5 * blocks + 4);
+}
+
+#[test]
+fn test_crc_and_adler_known_values() {
+    assert_eq!(crc32(b"IEND"), 0xae42_6082);
+    assert_eq!(adler32(b"Wikipedia"), 0x11e6_0398);
+}
+
+#[test]
+fn test_fill_convex_square() {
+    let mut image = RgbImage::filled(4, 4, [0, 0, 0]);
+    image.fill_convex(&[[1.0, 1.0], [3.0, 1.0], [3.0, 3.0], [1.0, 3.0]], [9, 9, 9]);
+    assert_eq!(image.pixel(0, 0), [0, 0, 0]);
+    assert_eq!(image.pixel(1, 1), [9, 9, 9]);
+    assert_eq!(image.pixel(2, 2), [9, 9, 9]);
+    assert_eq!(image.pixel(3, 3), [0, 0, 0]);
+}
+
+#[test]
+fn test_fmt_num_precision() {
+    assert_eq!(fmt_num(1.5, 3), "1.5");
+    assert_eq!(fmt_num(2.0, 6), "2");
+    assert_eq!(fmt_num(0.123_456_7, 3), "0.123");
+    assert_eq!(fmt_num(0.123_456_7, 6), "0.123457");
+    assert_eq!(fmt_num(-0.000_1, 3), "0");
+}